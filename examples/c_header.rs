@@ -0,0 +1,27 @@
+use std::{env, fs};
+
+use syg::model::Database;
+
+/// writes a C header of the `#[repr(C)]` types and `extern "C"` functions of a crate and lists what was left out
+///
+/// `cargo run --example c_header -- <dir containing the crates> <crate name> <output .h> [dependency crates...]`
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let [base_path, name, output, dependencies @ ..] = args.as_slice() else {
+        eprintln!("usage: c_header <dir containing the crates> <crate name> <output .h> [dependency crates...]");
+        return;
+    };
+
+    let mut db = Database::default();
+    for it in dependencies {
+        db.add_crate(base_path, it);
+    }
+    db.add_crate(base_path, name);
+    db.compile();
+
+    let header = db.c_header(&name.replace('-', "_"));
+    fs::write(output, &header.code).unwrap();
+    for (item, reason) in header.skipped.iter() {
+        eprintln!("skipped {}: {}", item, reason);
+    }
+}
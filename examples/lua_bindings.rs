@@ -0,0 +1,40 @@
+use std::{env, fs};
+
+use syg::{model::Database, GlobalIdent, LuaStubFormat};
+
+/// writes `mlua` bindings of the symbols (the whole crate by default) and lists the skipped items.
+/// `--luals=<file>` and `--luau=<file>` also write annotation stubs of the bound API
+///
+/// `cargo run --example lua_bindings -- <dir containing the crate> <crate name> <output .rs> [symbols...] [--luals=<file>] [--luau=<file>]`
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let positional = args.iter().filter(|it| !it.starts_with("--")).collect::<Vec<_>>();
+    let [base_path, name, output, symbols @ ..] = positional.as_slice() else {
+        eprintln!("usage: lua_bindings <dir containing the crate> <crate name> <output .rs> [symbols...] [--luals=<file>] [--luau=<file>]");
+        return;
+    };
+
+    let mut db = Database::default();
+    db.add_crate(base_path, name);
+    db.compile();
+
+    let mut symbols = symbols.iter().map(|it| GlobalIdent::from_qualified_name(it)).collect::<Vec<_>>();
+    if symbols.is_empty() {
+        symbols.push(GlobalIdent::from_qualified_name(&name.replace('-', "_")));
+    }
+    let bindings = db.lua_bindings(&symbols);
+    fs::write(output, &bindings.code).unwrap();
+    for (item, reason) in bindings.skipped.iter() {
+        eprintln!("skipped {}: {}", item, reason);
+    }
+    for arg in args.iter() {
+        let (format, path) = if let Some(path) = arg.strip_prefix("--luals=") {
+            (LuaStubFormat::LuaLs, path)
+        } else if let Some(path) = arg.strip_prefix("--luau=") {
+            (LuaStubFormat::Luau, path)
+        } else {
+            continue;
+        };
+        fs::write(path, db.lua_stubs(&symbols, format).code).unwrap();
+    }
+}
@@ -0,0 +1,25 @@
+use std::env;
+
+use syg::model::Database;
+
+/// prints modules of the crate with their items grouped by kind
+///
+/// `cargo run --example module_tree -- <dir containing the crate> <crate name> [--pub]`
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let public_only = args.iter().any(|it| it == "--pub");
+    let positional = args.iter().filter(|it| !it.starts_with("--")).collect::<Vec<_>>();
+    let [base_path, name] = positional.as_slice() else {
+        eprintln!("usage: module_tree <dir containing the crate> <crate name> [--pub]");
+        return;
+    };
+
+    let mut db = Database::default();
+    db.add_crate(base_path, name);
+    db.compile();
+
+    match db.module_tree(&name.replace('-', "_"), public_only) {
+        Some(tree) => println!("{}", tree),
+        None => eprintln!("crate {} is not found", name),
+    }
+}
@@ -0,0 +1,31 @@
+use std::{env, fs};
+
+use syg::{model::Database, GlobalIdent};
+
+/// writes a `.d.ts` of the symbols (the whole crate by default) and lists what was left out
+///
+/// `cargo run --example ts_declarations -- <dir containing the crate> <crate name> <output .d.ts> [symbols...]`
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let [base_path, name, output, symbols @ ..] = args.as_slice() else {
+        eprintln!("usage: ts_declarations <dir containing the crate> <crate name> <output .d.ts> [symbols...]");
+        return;
+    };
+
+    let mut db = Database::default();
+    db.add_crate(base_path, name);
+    db.compile();
+
+    let mut symbols = symbols.iter().map(|it| GlobalIdent::from_qualified_name(it)).collect::<Vec<_>>();
+    if symbols.is_empty() {
+        symbols.push(GlobalIdent::from_qualified_name(&name.replace('-', "_")));
+    }
+    let declarations = db.ts_declarations(&symbols);
+    fs::write(output, &declarations.code).unwrap();
+    for (item, reason) in declarations.skipped.iter() {
+        eprintln!("skipped {}: {}", item, reason);
+    }
+    for (item, ty) in declarations.unknown.iter() {
+        eprintln!("{} in {} is written as unknown", ty, item);
+    }
+}
@@ -0,0 +1,640 @@
+//! Signature-only model of `alloc`, bundled with syg so that resolution works without the real sources.
+//! Allocator parameters are omitted. Replaced by the real crate when `alloc` is added explicitly.
+
+#![no_std]
+
+pub mod boxed {
+    use core::clone::Clone;
+    use core::default::Default;
+    use core::fmt::{Debug, Display, Formatter, Result};
+    use core::ops::{Deref, DerefMut};
+
+    pub struct Box<T: ?Sized>(core::ptr::NonNull<T>);
+
+    unsafe impl<T: ?Sized + Send> Send for Box<T> {}
+    unsafe impl<T: ?Sized + Sync> Sync for Box<T> {}
+
+    impl<T> Box<T> {
+        pub fn new(x: T) -> Box<T> {}
+        pub fn pin(x: T) -> core::pin::Pin<Box<T>> {}
+        pub fn into_inner(boxed: Self) -> T {}
+    }
+
+    impl<T: ?Sized> Box<T> {
+        pub unsafe fn from_raw(raw: *mut T) -> Box<T> {}
+        pub fn into_raw(b: Box<T>) -> *mut T {}
+        pub fn leak<'a>(b: Box<T>) -> &'a mut T where T: 'a {}
+    }
+
+    impl<T: ?Sized> Deref for Box<T> {
+        type Target = T;
+        fn deref(&self) -> &T {}
+    }
+    impl<T: ?Sized> DerefMut for Box<T> {
+        fn deref_mut(&mut self) -> &mut T {}
+    }
+    impl<T: Clone> Clone for Box<T> {
+        fn clone(&self) -> Self {}
+    }
+    impl<T: Default> Default for Box<T> {
+        fn default() -> Self {}
+    }
+    impl<T: ?Sized + Debug> Debug for Box<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {}
+    }
+    impl<T: ?Sized + Display> Display for Box<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {}
+    }
+    impl<T: ?Sized + core::cmp::PartialEq> core::cmp::PartialEq for Box<T> {
+        fn eq(&self, other: &Self) -> bool {}
+    }
+    impl<T> core::convert::From<T> for Box<T> {
+        fn from(t: T) -> Self {}
+    }
+    impl<I: core::iter::Iterator + ?Sized> core::iter::Iterator for Box<I> {
+        type Item = I::Item;
+        fn next(&mut self) -> core::option::Option<I::Item> {}
+    }
+    impl<Args, F: core::ops::FnOnce<Args> + ?Sized> core::ops::FnOnce<Args> for Box<F> {
+        type Output = F::Output;
+        fn call_once(self, args: Args) -> Self::Output {}
+    }
+}
+
+pub mod vec {
+    use core::clone::Clone;
+    use core::default::Default;
+    use core::fmt::{Debug, Formatter, Result};
+    use core::iter::{Extend, FromIterator, IntoIterator, Iterator};
+    use core::ops::{Deref, DerefMut, Index, IndexMut};
+    use core::option::Option;
+
+    pub struct Vec<T> {
+        buf: core::ptr::NonNull<T>,
+        len: usize,
+    }
+
+    pub struct IntoIter<T> {
+        buf: core::ptr::NonNull<T>,
+        end: *const T,
+    }
+
+    unsafe impl<T: Send> Send for Vec<T> {}
+    unsafe impl<T: Sync> Sync for Vec<T> {}
+    unsafe impl<T: Send> Send for IntoIter<T> {}
+    unsafe impl<T: Sync> Sync for IntoIter<T> {}
+
+    pub struct Drain<'a, T: 'a> {
+        iter: core::slice::Iter<'a, T>,
+    }
+
+    impl<T> Vec<T> {
+        pub const fn new() -> Vec<T> {}
+        pub fn with_capacity(capacity: usize) -> Vec<T> {}
+        pub fn capacity(&self) -> usize {}
+        pub fn reserve(&mut self, additional: usize) {}
+        pub fn push(&mut self, value: T) {}
+        pub fn pop(&mut self) -> Option<T> {}
+        pub fn insert(&mut self, index: usize, element: T) {}
+        pub fn remove(&mut self, index: usize) -> T {}
+        pub fn swap_remove(&mut self, index: usize) -> T {}
+        pub fn truncate(&mut self, len: usize) {}
+        pub fn clear(&mut self) {}
+        pub fn len(&self) -> usize {}
+        pub fn is_empty(&self) -> bool {}
+        pub fn as_slice(&self) -> &[T] {}
+        pub fn as_mut_slice(&mut self) -> &mut [T] {}
+        pub fn retain<F: core::ops::FnMut(&T) -> bool>(&mut self, f: F) {}
+        pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {}
+        pub fn append(&mut self, other: &mut Vec<T>) {}
+        pub fn into_boxed_slice(self) -> crate::boxed::Box<[T]> {}
+    }
+
+    impl<T: Clone> Vec<T> {
+        pub fn extend_from_slice(&mut self, other: &[T]) {}
+        pub fn resize(&mut self, new_len: usize, value: T) {}
+    }
+
+    impl<T> Deref for Vec<T> {
+        type Target = [T];
+        fn deref(&self) -> &[T] {}
+    }
+    impl<T> DerefMut for Vec<T> {
+        fn deref_mut(&mut self) -> &mut [T] {}
+    }
+    impl<T> Index<usize> for Vec<T> {
+        type Output = T;
+        fn index(&self, index: usize) -> &T {}
+    }
+    impl<T> IndexMut<usize> for Vec<T> {
+        fn index_mut(&mut self, index: usize) -> &mut T {}
+    }
+    impl<T: Clone> Clone for Vec<T> {
+        fn clone(&self) -> Self {}
+    }
+    impl<T> Default for Vec<T> {
+        fn default() -> Vec<T> {}
+    }
+    impl<T: Debug> Debug for Vec<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {}
+    }
+    impl<T: core::cmp::PartialEq> core::cmp::PartialEq for Vec<T> {
+        fn eq(&self, other: &Vec<T>) -> bool {}
+    }
+    impl<T: core::cmp::Eq> core::cmp::Eq for Vec<T> {}
+    impl<T: core::hash::Hash> core::hash::Hash for Vec<T> {
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {}
+    }
+    impl<T> FromIterator<T> for Vec<T> {
+        fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Vec<T> {}
+    }
+    impl<T> Extend<T> for Vec<T> {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {}
+    }
+    impl<T> IntoIterator for Vec<T> {
+        type Item = T;
+        type IntoIter = IntoIter<T>;
+        fn into_iter(self) -> IntoIter<T> {}
+    }
+    impl<'a, T> IntoIterator for &'a Vec<T> {
+        type Item = &'a T;
+        type IntoIter = core::slice::Iter<'a, T>;
+        fn into_iter(self) -> core::slice::Iter<'a, T> {}
+    }
+    impl<'a, T> IntoIterator for &'a mut Vec<T> {
+        type Item = &'a mut T;
+        type IntoIter = core::slice::IterMut<'a, T>;
+        fn into_iter(self) -> core::slice::IterMut<'a, T> {}
+    }
+    impl<T: Clone> core::convert::From<&[T]> for Vec<T> {
+        fn from(s: &[T]) -> Vec<T> {}
+    }
+    impl<T, const N: usize> core::convert::From<[T; N]> for Vec<T> {
+        fn from(s: [T; N]) -> Vec<T> {}
+    }
+    impl<T> core::convert::AsRef<[T]> for Vec<T> {
+        fn as_ref(&self) -> &[T] {}
+    }
+    impl<T> Iterator for IntoIter<T> {
+        type Item = T;
+        fn next(&mut self) -> Option<T> {}
+    }
+    impl<T> Iterator for Drain<'_, T> {
+        type Item = T;
+        fn next(&mut self) -> Option<T> {}
+    }
+}
+
+pub mod string {
+    use core::clone::Clone;
+    use core::default::Default;
+    use core::fmt::{Debug, Display, Formatter, Result};
+    use core::ops::{Add, Deref, DerefMut};
+    use core::option::Option;
+    use crate::vec::Vec;
+
+    pub struct String {
+        vec: Vec<u8>,
+    }
+
+    pub struct FromUtf8Error {
+        bytes: Vec<u8>,
+        error: core::str::Utf8Error,
+    }
+
+    pub trait ToString {
+        fn to_string(&self) -> String;
+    }
+
+    impl<T: Display + ?Sized> ToString for T {
+        fn to_string(&self) -> String {}
+    }
+
+    impl String {
+        pub const fn new() -> String {}
+        pub fn with_capacity(capacity: usize) -> String {}
+        pub fn from_utf8(vec: Vec<u8>) -> core::result::Result<String, FromUtf8Error> {}
+        pub fn as_str(&self) -> &str {}
+        pub fn as_mut_str(&mut self) -> &mut str {}
+        pub fn push_str(&mut self, string: &str) {}
+        pub fn push(&mut self, ch: char) {}
+        pub fn pop(&mut self) -> Option<char> {}
+        pub fn len(&self) -> usize {}
+        pub fn is_empty(&self) -> bool {}
+        pub fn clear(&mut self) {}
+        pub fn into_bytes(self) -> Vec<u8> {}
+        pub fn into_boxed_str(self) -> crate::boxed::Box<str> {}
+    }
+
+    impl Deref for String {
+        type Target = str;
+        fn deref(&self) -> &str {}
+    }
+    impl DerefMut for String {
+        fn deref_mut(&mut self) -> &mut str {}
+    }
+    impl Clone for String {
+        fn clone(&self) -> Self {}
+    }
+    impl Default for String {
+        fn default() -> String {}
+    }
+    impl Debug for String {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {}
+    }
+    impl Display for String {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {}
+    }
+    impl core::cmp::PartialEq for String {
+        fn eq(&self, other: &String) -> bool {}
+    }
+    impl core::cmp::PartialEq<str> for String {
+        fn eq(&self, other: &str) -> bool {}
+    }
+    impl<'a> core::cmp::PartialEq<&'a str> for String {
+        fn eq(&self, other: &&'a str) -> bool {}
+    }
+    impl core::cmp::Eq for String {}
+    impl core::cmp::PartialOrd for String {
+        fn partial_cmp(&self, other: &String) -> Option<core::cmp::Ordering> {}
+    }
+    impl core::cmp::Ord for String {
+        fn cmp(&self, other: &String) -> core::cmp::Ordering {}
+    }
+    impl core::hash::Hash for String {
+        fn hash<H: core::hash::Hasher>(&self, hasher: &mut H) {}
+    }
+    impl core::convert::From<&str> for String {
+        fn from(s: &str) -> String {}
+    }
+    impl core::convert::From<char> for String {
+        fn from(c: char) -> String {}
+    }
+    impl core::convert::AsRef<str> for String {
+        fn as_ref(&self) -> &str {}
+    }
+    impl core::convert::AsRef<[u8]> for String {
+        fn as_ref(&self) -> &[u8] {}
+    }
+    impl core::borrow::Borrow<str> for String {
+        fn borrow(&self) -> &str {}
+    }
+    impl core::str::FromStr for String {
+        type Err = core::convert::Infallible;
+        fn from_str(s: &str) -> core::result::Result<String, Self::Err> {}
+    }
+    impl Add<&str> for String {
+        type Output = String;
+        fn add(self, other: &str) -> String {}
+    }
+    impl core::ops::AddAssign<&str> for String {
+        fn add_assign(&mut self, other: &str) {}
+    }
+    impl core::iter::FromIterator<char> for String {
+        fn from_iter<I: core::iter::IntoIterator<Item = char>>(iter: I) -> String {}
+    }
+    impl<'a> core::iter::FromIterator<&'a str> for String {
+        fn from_iter<I: core::iter::IntoIterator<Item = &'a str>>(iter: I) -> String {}
+    }
+    impl core::fmt::Write for String {
+        fn write_str(&mut self, s: &str) -> Result {}
+    }
+
+    impl str {
+        pub fn to_owned(&self) -> String {}
+        pub fn to_lowercase(&self) -> String {}
+        pub fn to_uppercase(&self) -> String {}
+        pub fn replace(&self, from: &str, to: &str) -> String {}
+        pub fn repeat(&self, n: usize) -> String {}
+    }
+}
+
+pub mod borrow {
+    pub use core::borrow::{Borrow, BorrowMut};
+
+    pub trait ToOwned {
+        type Owned: Borrow<Self>;
+        fn to_owned(&self) -> Self::Owned;
+    }
+
+    pub enum Cow<'a, B: ?Sized + 'a>
+    where
+        B: ToOwned,
+    {
+        Borrowed(&'a B),
+        Owned(<B as ToOwned>::Owned),
+    }
+
+    impl<T: core::clone::Clone> ToOwned for T {
+        type Owned = T;
+        fn to_owned(&self) -> T {}
+    }
+    impl ToOwned for str {
+        type Owned = crate::string::String;
+        fn to_owned(&self) -> crate::string::String {}
+    }
+    impl<T: core::clone::Clone> ToOwned for [T] {
+        type Owned = crate::vec::Vec<T>;
+        fn to_owned(&self) -> crate::vec::Vec<T> {}
+    }
+
+    impl<B: ?Sized + ToOwned> Cow<'_, B> {
+        pub fn into_owned(self) -> <B as ToOwned>::Owned {}
+        pub fn to_mut(&mut self) -> &mut <B as ToOwned>::Owned {}
+    }
+    impl<B: ?Sized + ToOwned> core::ops::Deref for Cow<'_, B> {
+        type Target = B;
+        fn deref(&self) -> &B {}
+    }
+    impl<B: ?Sized + ToOwned> core::clone::Clone for Cow<'_, B> {
+        fn clone(&self) -> Self {}
+    }
+}
+
+pub mod rc {
+    use core::cell::Cell;
+    use core::option::Option;
+
+    struct RcInner<T: ?Sized> {
+        strong: Cell<usize>,
+        weak: Cell<usize>,
+        value: T,
+    }
+
+    pub struct Rc<T: ?Sized> {
+        ptr: core::ptr::NonNull<RcInner<T>>,
+    }
+
+    pub struct Weak<T: ?Sized> {
+        ptr: core::ptr::NonNull<RcInner<T>>,
+    }
+
+    impl<T: ?Sized> !Send for Rc<T> {}
+    impl<T: ?Sized> !Sync for Rc<T> {}
+    impl<T: ?Sized> !Send for Weak<T> {}
+    impl<T: ?Sized> !Sync for Weak<T> {}
+
+    impl<T> Rc<T> {
+        pub fn new(value: T) -> Rc<T> {}
+        pub fn try_unwrap(this: Self) -> core::result::Result<T, Self> {}
+    }
+
+    impl<T: ?Sized> Rc<T> {
+        pub fn downgrade(this: &Self) -> Weak<T> {}
+        pub fn strong_count(this: &Self) -> usize {}
+        pub fn weak_count(this: &Self) -> usize {}
+        pub fn ptr_eq(this: &Self, other: &Self) -> bool {}
+        pub fn get_mut(this: &mut Self) -> Option<&mut T> {}
+    }
+
+    impl<T: ?Sized> Weak<T> {
+        pub fn upgrade(&self) -> Option<Rc<T>> {}
+    }
+
+    impl<T: ?Sized> core::ops::Deref for Rc<T> {
+        type Target = T;
+        fn deref(&self) -> &T {}
+    }
+    impl<T: ?Sized> core::clone::Clone for Rc<T> {
+        fn clone(&self) -> Rc<T> {}
+    }
+    impl<T: ?Sized> core::clone::Clone for Weak<T> {
+        fn clone(&self) -> Weak<T> {}
+    }
+    impl<T: core::default::Default> core::default::Default for Rc<T> {
+        fn default() -> Rc<T> {}
+    }
+    impl<T: ?Sized + core::fmt::Debug> core::fmt::Debug for Rc<T> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {}
+    }
+    impl<T: ?Sized + core::cmp::PartialEq> core::cmp::PartialEq for Rc<T> {
+        fn eq(&self, other: &Rc<T>) -> bool {}
+    }
+    impl<T> core::convert::From<T> for Rc<T> {
+        fn from(t: T) -> Self {}
+    }
+}
+
+pub mod sync {
+    use core::option::Option;
+
+    struct ArcInner<T: ?Sized> {
+        strong: core::sync::atomic::AtomicUsize,
+        weak: core::sync::atomic::AtomicUsize,
+        data: T,
+    }
+
+    pub struct Arc<T: ?Sized> {
+        ptr: core::ptr::NonNull<ArcInner<T>>,
+    }
+
+    pub struct Weak<T: ?Sized> {
+        ptr: core::ptr::NonNull<ArcInner<T>>,
+    }
+
+    unsafe impl<T: ?Sized + Sync + Send> Send for Arc<T> {}
+    unsafe impl<T: ?Sized + Sync + Send> Sync for Arc<T> {}
+    unsafe impl<T: ?Sized + Sync + Send> Send for Weak<T> {}
+    unsafe impl<T: ?Sized + Sync + Send> Sync for Weak<T> {}
+
+    impl<T> Arc<T> {
+        pub fn new(data: T) -> Arc<T> {}
+        pub fn try_unwrap(this: Self) -> core::result::Result<T, Self> {}
+    }
+
+    impl<T: ?Sized> Arc<T> {
+        pub fn downgrade(this: &Self) -> Weak<T> {}
+        pub fn strong_count(this: &Self) -> usize {}
+        pub fn ptr_eq(this: &Self, other: &Self) -> bool {}
+        pub fn get_mut(this: &mut Self) -> Option<&mut T> {}
+    }
+
+    impl<T: ?Sized> Weak<T> {
+        pub fn upgrade(&self) -> Option<Arc<T>> {}
+    }
+
+    impl<T: ?Sized> core::ops::Deref for Arc<T> {
+        type Target = T;
+        fn deref(&self) -> &T {}
+    }
+    impl<T: ?Sized> core::clone::Clone for Arc<T> {
+        fn clone(&self) -> Arc<T> {}
+    }
+    impl<T: ?Sized> core::clone::Clone for Weak<T> {
+        fn clone(&self) -> Weak<T> {}
+    }
+    impl<T: core::default::Default> core::default::Default for Arc<T> {
+        fn default() -> Arc<T> {}
+    }
+    impl<T: ?Sized + core::fmt::Debug> core::fmt::Debug for Arc<T> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {}
+    }
+    impl<T: ?Sized + core::cmp::PartialEq> core::cmp::PartialEq for Arc<T> {
+        fn eq(&self, other: &Arc<T>) -> bool {}
+    }
+    impl<T> core::convert::From<T> for Arc<T> {
+        fn from(t: T) -> Self {}
+    }
+}
+
+pub mod collections {
+    use core::option::Option;
+
+    pub mod btree_map {
+        use core::option::Option;
+
+        pub struct BTreeMap<K, V> {
+            root: Option<core::ptr::NonNull<(K, V)>>,
+            length: usize,
+        }
+
+        unsafe impl<K: Send, V: Send> Send for BTreeMap<K, V> {}
+        unsafe impl<K: Sync, V: Sync> Sync for BTreeMap<K, V> {}
+
+        pub struct Iter<'a, K: 'a, V: 'a> {
+            range: core::marker::PhantomData<&'a (K, V)>,
+        }
+
+        pub enum Entry<'a, K: 'a, V: 'a> {
+            Vacant(VacantEntry<'a, K, V>),
+            Occupied(OccupiedEntry<'a, K, V>),
+        }
+
+        pub struct VacantEntry<'a, K, V> {
+            key: K,
+            _marker: core::marker::PhantomData<&'a mut (K, V)>,
+        }
+
+        pub struct OccupiedEntry<'a, K, V> {
+            _marker: core::marker::PhantomData<&'a mut (K, V)>,
+        }
+
+        impl<K, V> BTreeMap<K, V> {
+            pub const fn new() -> BTreeMap<K, V> {}
+            pub fn len(&self) -> usize {}
+            pub fn is_empty(&self) -> bool {}
+            pub fn clear(&mut self) {}
+            pub fn iter(&self) -> Iter<'_, K, V> {}
+        }
+
+        impl<K: core::cmp::Ord, V> BTreeMap<K, V> {
+            pub fn get<Q: ?Sized + core::cmp::Ord>(&self, key: &Q) -> Option<&V> where K: core::borrow::Borrow<Q> {}
+            pub fn get_mut<Q: ?Sized + core::cmp::Ord>(&mut self, key: &Q) -> Option<&mut V> where K: core::borrow::Borrow<Q> {}
+            pub fn contains_key<Q: ?Sized + core::cmp::Ord>(&self, key: &Q) -> bool where K: core::borrow::Borrow<Q> {}
+            pub fn insert(&mut self, key: K, value: V) -> Option<V> {}
+            pub fn remove<Q: ?Sized + core::cmp::Ord>(&mut self, key: &Q) -> Option<V> where K: core::borrow::Borrow<Q> {}
+            pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {}
+        }
+
+        impl<'a, K: core::cmp::Ord, V> Entry<'a, K, V> {
+            pub fn or_insert(self, default: V) -> &'a mut V {}
+            pub fn or_insert_with<F: core::ops::FnOnce() -> V>(self, default: F) -> &'a mut V {}
+            pub fn or_default(self) -> &'a mut V where V: core::default::Default {}
+        }
+
+        impl<'a, K: 'a, V: 'a> core::iter::Iterator for Iter<'a, K, V> {
+            type Item = (&'a K, &'a V);
+            fn next(&mut self) -> Option<(&'a K, &'a V)> {}
+        }
+        impl<'a, K, V> core::iter::IntoIterator for &'a BTreeMap<K, V> {
+            type Item = (&'a K, &'a V);
+            type IntoIter = Iter<'a, K, V>;
+            fn into_iter(self) -> Iter<'a, K, V> {}
+        }
+        impl<K: core::clone::Clone, V: core::clone::Clone> core::clone::Clone for BTreeMap<K, V> {
+            fn clone(&self) -> BTreeMap<K, V> {}
+        }
+        impl<K, V> core::default::Default for BTreeMap<K, V> {
+            fn default() -> BTreeMap<K, V> {}
+        }
+        impl<K: core::fmt::Debug, V: core::fmt::Debug> core::fmt::Debug for BTreeMap<K, V> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {}
+        }
+        impl<K: core::cmp::PartialEq, V: core::cmp::PartialEq> core::cmp::PartialEq for BTreeMap<K, V> {
+            fn eq(&self, other: &BTreeMap<K, V>) -> bool {}
+        }
+        impl<K: core::cmp::Ord, V> core::iter::FromIterator<(K, V)> for BTreeMap<K, V> {
+            fn from_iter<T: core::iter::IntoIterator<Item = (K, V)>>(iter: T) -> BTreeMap<K, V> {}
+        }
+        impl<K: core::cmp::Ord, V> core::iter::Extend<(K, V)> for BTreeMap<K, V> {
+            fn extend<T: core::iter::IntoIterator<Item = (K, V)>>(&mut self, iter: T) {}
+        }
+    }
+
+    pub mod btree_set {
+        use core::option::Option;
+
+        pub struct BTreeSet<T> {
+            map: crate::collections::btree_map::BTreeMap<T, ()>,
+        }
+
+        impl<T> BTreeSet<T> {
+            pub const fn new() -> BTreeSet<T> {}
+            pub fn len(&self) -> usize {}
+            pub fn is_empty(&self) -> bool {}
+        }
+
+        impl<T: core::cmp::Ord> BTreeSet<T> {
+            pub fn insert(&mut self, value: T) -> bool {}
+            pub fn contains<Q: ?Sized + core::cmp::Ord>(&self, value: &Q) -> bool where T: core::borrow::Borrow<Q> {}
+            pub fn remove<Q: ?Sized + core::cmp::Ord>(&mut self, value: &Q) -> bool where T: core::borrow::Borrow<Q> {}
+            pub fn first(&self) -> Option<&T> {}
+        }
+
+        impl<T: core::clone::Clone> core::clone::Clone for BTreeSet<T> {
+            fn clone(&self) -> BTreeSet<T> {}
+        }
+        impl<T> core::default::Default for BTreeSet<T> {
+            fn default() -> BTreeSet<T> {}
+        }
+        impl<T: core::fmt::Debug> core::fmt::Debug for BTreeSet<T> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {}
+        }
+        impl<T: core::cmp::Ord> core::iter::FromIterator<T> for BTreeSet<T> {
+            fn from_iter<I: core::iter::IntoIterator<Item = T>>(iter: I) -> BTreeSet<T> {}
+        }
+    }
+
+    pub mod vec_deque {
+        use core::option::Option;
+
+        pub struct VecDeque<T> {
+            head: usize,
+            len: usize,
+            buf: crate::vec::Vec<T>,
+        }
+
+        impl<T> VecDeque<T> {
+            pub const fn new() -> VecDeque<T> {}
+            pub fn with_capacity(capacity: usize) -> VecDeque<T> {}
+            pub fn len(&self) -> usize {}
+            pub fn is_empty(&self) -> bool {}
+            pub fn push_back(&mut self, value: T) {}
+            pub fn push_front(&mut self, value: T) {}
+            pub fn pop_back(&mut self) -> Option<T> {}
+            pub fn pop_front(&mut self) -> Option<T> {}
+            pub fn front(&self) -> Option<&T> {}
+            pub fn back(&self) -> Option<&T> {}
+        }
+
+        impl<T: core::clone::Clone> core::clone::Clone for VecDeque<T> {
+            fn clone(&self) -> VecDeque<T> {}
+        }
+        impl<T> core::default::Default for VecDeque<T> {
+            fn default() -> VecDeque<T> {}
+        }
+        impl<T: core::fmt::Debug> core::fmt::Debug for VecDeque<T> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {}
+        }
+    }
+
+    pub use self::btree_map::BTreeMap;
+    pub use self::btree_set::BTreeSet;
+    pub use self::vec_deque::VecDeque;
+}
+
+pub mod fmt {
+    pub use core::fmt::*;
+
+    pub fn format(args: Arguments<'_>) -> crate::string::String {}
+}
@@ -0,0 +1,1755 @@
+//! Signature-only model of `core`, bundled with syg so that resolution works without the real sources.
+//! Bodies are empty and unstable details are omitted. Replaced by the real crate when `core` is added explicitly.
+
+#![no_core]
+
+#[prelude_import]
+use crate::prelude::rust_2021::*;
+
+pub mod marker {
+    pub trait Sized {}
+    pub unsafe auto trait Send {}
+    pub unsafe auto trait Sync {}
+    pub auto trait Unpin {}
+    pub trait Copy: Clone {}
+    pub struct PhantomData<T: ?Sized>;
+    pub struct PhantomPinned;
+
+    use crate::clone::Clone;
+
+    impl<T: ?Sized> Copy for PhantomData<T> {}
+    impl<T: ?Sized> Clone for PhantomData<T> {}
+    impl<T: ?Sized> crate::default::Default for PhantomData<T> {}
+    impl !Unpin for PhantomPinned {}
+
+    impl<T: ?Sized> !Send for *const T {}
+    impl<T: ?Sized> !Send for *mut T {}
+    impl<T: ?Sized> !Sync for *const T {}
+    impl<T: ?Sized> !Sync for *mut T {}
+    unsafe impl<T: Sync + ?Sized> Send for &T {}
+    unsafe impl<T: Send + ?Sized> Send for &mut T {}
+    impl<T: ?Sized> Unpin for &T {}
+    impl<T: ?Sized> Unpin for &mut T {}
+    impl<T: ?Sized> Unpin for *const T {}
+    impl<T: ?Sized> Unpin for *mut T {}
+}
+
+pub mod clone {
+    pub trait Clone: Sized {
+        fn clone(&self) -> Self;
+        fn clone_from(&mut self, source: &Self) {}
+    }
+
+    impl<T: ?Sized> Clone for &T {
+        fn clone(&self) -> Self {}
+    }
+}
+
+pub mod default {
+    pub trait Default: Sized {
+        fn default() -> Self;
+    }
+}
+
+pub mod cmp {
+    use crate::option::Option;
+
+    pub trait PartialEq<Rhs: ?Sized = Self> {
+        fn eq(&self, other: &Rhs) -> bool;
+        fn ne(&self, other: &Rhs) -> bool {}
+    }
+
+    pub trait Eq: PartialEq<Self> {}
+
+    pub enum Ordering {
+        Less = -1,
+        Equal = 0,
+        Greater = 1,
+    }
+
+    pub trait PartialOrd<Rhs: ?Sized = Self>: PartialEq<Rhs> {
+        fn partial_cmp(&self, other: &Rhs) -> Option<Ordering>;
+        fn lt(&self, other: &Rhs) -> bool {}
+        fn le(&self, other: &Rhs) -> bool {}
+        fn gt(&self, other: &Rhs) -> bool {}
+        fn ge(&self, other: &Rhs) -> bool {}
+    }
+
+    pub trait Ord: Eq + PartialOrd<Self> {
+        fn cmp(&self, other: &Self) -> Ordering;
+        fn max(self, other: Self) -> Self where Self: Sized {}
+        fn min(self, other: Self) -> Self where Self: Sized {}
+        fn clamp(self, min: Self, max: Self) -> Self where Self: Sized {}
+    }
+
+    pub struct Reverse<T>(pub T);
+
+    pub fn min<T: Ord>(v1: T, v2: T) -> T {}
+    pub fn max<T: Ord>(v1: T, v2: T) -> T {}
+
+    impl crate::clone::Clone for Ordering {
+        fn clone(&self) -> Ordering {}
+    }
+    impl crate::marker::Copy for Ordering {}
+    impl PartialEq for Ordering {
+        fn eq(&self, other: &Ordering) -> bool {}
+    }
+    impl Eq for Ordering {}
+    impl crate::fmt::Debug for Ordering {
+        fn fmt(&self, f: &mut crate::fmt::Formatter<'_>) -> crate::fmt::Result {}
+    }
+}
+
+pub mod convert {
+    use crate::result::Result;
+
+    pub trait From<T>: Sized {
+        fn from(value: T) -> Self;
+    }
+
+    pub trait Into<T>: Sized {
+        fn into(self) -> T;
+    }
+
+    pub trait TryFrom<T>: Sized {
+        type Error;
+        fn try_from(value: T) -> Result<Self, Self::Error>;
+    }
+
+    pub trait TryInto<T>: Sized {
+        type Error;
+        fn try_into(self) -> Result<T, Self::Error>;
+    }
+
+    pub trait AsRef<T: ?Sized> {
+        fn as_ref(&self) -> &T;
+    }
+
+    pub trait AsMut<T: ?Sized> {
+        fn as_mut(&mut self) -> &mut T;
+    }
+
+    pub enum Infallible {}
+
+    pub fn identity<T>(x: T) -> T {}
+
+    impl<T, U> Into<U> for T
+    where
+        U: From<T>,
+    {
+        fn into(self) -> U {}
+    }
+
+    impl<T> From<T> for T {
+        fn from(t: T) -> T {}
+    }
+
+    impl<T, U> TryFrom<U> for T
+    where
+        U: Into<T>,
+    {
+        type Error = Infallible;
+        fn try_from(value: U) -> Result<Self, Self::Error> {}
+    }
+
+    impl<T, U> TryInto<U> for T
+    where
+        U: TryFrom<T>,
+    {
+        type Error = U::Error;
+        fn try_into(self) -> Result<U, U::Error> {}
+    }
+}
+
+pub mod ops {
+    use crate::option::Option;
+
+    pub trait Add<Rhs = Self> {
+        type Output;
+        fn add(self, rhs: Rhs) -> Self::Output;
+    }
+    pub trait Sub<Rhs = Self> {
+        type Output;
+        fn sub(self, rhs: Rhs) -> Self::Output;
+    }
+    pub trait Mul<Rhs = Self> {
+        type Output;
+        fn mul(self, rhs: Rhs) -> Self::Output;
+    }
+    pub trait Div<Rhs = Self> {
+        type Output;
+        fn div(self, rhs: Rhs) -> Self::Output;
+    }
+    pub trait Rem<Rhs = Self> {
+        type Output;
+        fn rem(self, rhs: Rhs) -> Self::Output;
+    }
+    pub trait Neg {
+        type Output;
+        fn neg(self) -> Self::Output;
+    }
+    pub trait Not {
+        type Output;
+        fn not(self) -> Self::Output;
+    }
+    pub trait BitAnd<Rhs = Self> {
+        type Output;
+        fn bitand(self, rhs: Rhs) -> Self::Output;
+    }
+    pub trait BitOr<Rhs = Self> {
+        type Output;
+        fn bitor(self, rhs: Rhs) -> Self::Output;
+    }
+    pub trait BitXor<Rhs = Self> {
+        type Output;
+        fn bitxor(self, rhs: Rhs) -> Self::Output;
+    }
+    pub trait Shl<Rhs = Self> {
+        type Output;
+        fn shl(self, rhs: Rhs) -> Self::Output;
+    }
+    pub trait Shr<Rhs = Self> {
+        type Output;
+        fn shr(self, rhs: Rhs) -> Self::Output;
+    }
+    pub trait AddAssign<Rhs = Self> {
+        fn add_assign(&mut self, rhs: Rhs);
+    }
+    pub trait SubAssign<Rhs = Self> {
+        fn sub_assign(&mut self, rhs: Rhs);
+    }
+    pub trait MulAssign<Rhs = Self> {
+        fn mul_assign(&mut self, rhs: Rhs);
+    }
+    pub trait DivAssign<Rhs = Self> {
+        fn div_assign(&mut self, rhs: Rhs);
+    }
+    pub trait RemAssign<Rhs = Self> {
+        fn rem_assign(&mut self, rhs: Rhs);
+    }
+
+    pub trait Index<Idx: ?Sized> {
+        type Output: ?Sized;
+        fn index(&self, index: Idx) -> &Self::Output;
+    }
+    pub trait IndexMut<Idx: ?Sized>: Index<Idx> {
+        fn index_mut(&mut self, index: Idx) -> &mut Self::Output;
+    }
+
+    pub trait Deref {
+        type Target: ?Sized;
+        fn deref(&self) -> &Self::Target;
+    }
+    pub trait DerefMut: Deref {
+        fn deref_mut(&mut self) -> &mut Self::Target;
+    }
+
+    pub trait Drop {
+        fn drop(&mut self);
+    }
+
+    pub trait FnOnce<Args> {
+        type Output;
+        fn call_once(self, args: Args) -> Self::Output;
+    }
+    pub trait FnMut<Args>: FnOnce<Args> {
+        fn call_mut(&mut self, args: Args) -> Self::Output;
+    }
+    pub trait Fn<Args>: FnMut<Args> {
+        fn call(&self, args: Args) -> Self::Output;
+    }
+
+    pub struct Range<Idx> {
+        pub start: Idx,
+        pub end: Idx,
+    }
+    pub struct RangeInclusive<Idx> {
+        start: Idx,
+        end: Idx,
+    }
+    pub struct RangeFrom<Idx> {
+        pub start: Idx,
+    }
+    pub struct RangeTo<Idx> {
+        pub end: Idx,
+    }
+    pub struct RangeToInclusive<Idx> {
+        pub end: Idx,
+    }
+    pub struct RangeFull;
+
+    pub enum Bound<T> {
+        Included(T),
+        Excluded(T),
+        Unbounded,
+    }
+
+    pub trait RangeBounds<T: ?Sized> {
+        fn start_bound(&self) -> Bound<&T>;
+        fn end_bound(&self) -> Bound<&T>;
+        fn contains<U>(&self, item: &U) -> bool where T: PartialOrd<U>, U: ?Sized + PartialOrd<T> {}
+    }
+
+    impl<T> RangeBounds<T> for Range<T> {
+        fn start_bound(&self) -> Bound<&T> {}
+        fn end_bound(&self) -> Bound<&T> {}
+    }
+    impl<T> RangeBounds<T> for RangeInclusive<T> {
+        fn start_bound(&self) -> Bound<&T> {}
+        fn end_bound(&self) -> Bound<&T> {}
+    }
+    impl<T> RangeBounds<T> for RangeFrom<T> {
+        fn start_bound(&self) -> Bound<&T> {}
+        fn end_bound(&self) -> Bound<&T> {}
+    }
+    impl<T> RangeBounds<T> for RangeTo<T> {
+        fn start_bound(&self) -> Bound<&T> {}
+        fn end_bound(&self) -> Bound<&T> {}
+    }
+    impl<T: ?Sized> RangeBounds<T> for RangeFull {
+        fn start_bound(&self) -> Bound<&T> {}
+        fn end_bound(&self) -> Bound<&T> {}
+    }
+
+    pub enum ControlFlow<B, C = ()> {
+        Continue(C),
+        Break(B),
+    }
+
+    impl<Idx> RangeInclusive<Idx> {
+        pub const fn new(start: Idx, end: Idx) -> Self {}
+        pub const fn start(&self) -> &Idx {}
+        pub const fn end(&self) -> &Idx {}
+    }
+
+    impl<T: ?Sized> Deref for &T {
+        type Target = T;
+        fn deref(&self) -> &T {}
+    }
+    impl<T: ?Sized> Deref for &mut T {
+        type Target = T;
+        fn deref(&self) -> &T {}
+    }
+    impl<T: ?Sized> DerefMut for &mut T {
+        fn deref_mut(&mut self) -> &mut T {}
+    }
+}
+
+pub mod option {
+    use crate::ops::{Deref, FnOnce};
+    use crate::result::Result;
+
+    pub enum Option<T> {
+        None,
+        Some(T),
+    }
+
+    pub use self::Option::None;
+    pub use self::Option::Some;
+
+    impl<T> Option<T> {
+        pub const fn is_some(&self) -> bool {}
+        pub const fn is_none(&self) -> bool {}
+        pub fn is_some_and(self, f: impl FnOnce(T) -> bool) -> bool {}
+        pub const fn as_ref(&self) -> Option<&T> {}
+        pub fn as_mut(&mut self) -> Option<&mut T> {}
+        pub fn expect(self, msg: &str) -> T {}
+        pub fn unwrap(self) -> T {}
+        pub fn unwrap_or(self, default: T) -> T {}
+        pub fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {}
+        pub fn unwrap_or_default(self) -> T where T: crate::default::Default {}
+        pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Option<U> {}
+        pub fn map_or<U, F: FnOnce(T) -> U>(self, default: U, f: F) -> U {}
+        pub fn ok_or<E>(self, err: E) -> Result<T, E> {}
+        pub fn ok_or_else<E, F: FnOnce() -> E>(self, err: F) -> Result<T, E> {}
+        pub fn as_deref(&self) -> Option<&T::Target> where T: Deref {}
+        pub fn and_then<U, F: FnOnce(T) -> Option<U>>(self, f: F) -> Option<U> {}
+        pub fn filter<P: FnOnce(&T) -> bool>(self, predicate: P) -> Self {}
+        pub fn or(self, optb: Option<T>) -> Option<T> {}
+        pub fn or_else<F: FnOnce() -> Option<T>>(self, f: F) -> Option<T> {}
+        pub fn take(&mut self) -> Option<T> {}
+        pub fn replace(&mut self, value: T) -> Option<T> {}
+        pub fn insert(&mut self, value: T) -> &mut T {}
+        pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, f: F) -> &mut T {}
+        pub fn zip<U>(self, other: Option<U>) -> Option<(T, U)> {}
+        pub fn iter(&self) -> Iter<'_, T> {}
+    }
+
+    impl<T> Option<&T> {
+        pub fn cloned(self) -> Option<T> where T: crate::clone::Clone {}
+        pub fn copied(self) -> Option<T> where T: crate::marker::Copy {}
+    }
+
+    pub struct Iter<'a, A: 'a> {
+        inner: Option<&'a A>,
+    }
+
+    impl<'a, A> crate::iter::Iterator for Iter<'a, A> {
+        type Item = &'a A;
+        fn next(&mut self) -> Option<&'a A> {}
+    }
+
+    impl<T: crate::clone::Clone> crate::clone::Clone for Option<T> {
+        fn clone(&self) -> Self {}
+    }
+    impl<T: crate::marker::Copy> crate::marker::Copy for Option<T> {}
+    impl<T> crate::default::Default for Option<T> {
+        fn default() -> Option<T> {}
+    }
+    impl<T: crate::cmp::PartialEq> crate::cmp::PartialEq for Option<T> {
+        fn eq(&self, other: &Self) -> bool {}
+    }
+    impl<T: crate::cmp::Eq> crate::cmp::Eq for Option<T> {}
+    impl<T: crate::hash::Hash> crate::hash::Hash for Option<T> {
+        fn hash<H: crate::hash::Hasher>(&self, state: &mut H) {}
+    }
+    impl<T: crate::fmt::Debug> crate::fmt::Debug for Option<T> {
+        fn fmt(&self, f: &mut crate::fmt::Formatter<'_>) -> crate::fmt::Result {}
+    }
+    impl<T> crate::convert::From<T> for Option<T> {
+        fn from(val: T) -> Option<T> {}
+    }
+    impl<T> crate::iter::IntoIterator for Option<T> {
+        type Item = T;
+        type IntoIter = IntoIter<T>;
+        fn into_iter(self) -> IntoIter<T> {}
+    }
+
+    pub struct IntoIter<A> {
+        inner: Option<A>,
+    }
+
+    impl<A> crate::iter::Iterator for IntoIter<A> {
+        type Item = A;
+        fn next(&mut self) -> Option<A> {}
+    }
+}
+
+pub mod result {
+    use crate::ops::FnOnce;
+    use crate::option::Option;
+
+    pub enum Result<T, E> {
+        Ok(T),
+        Err(E),
+    }
+
+    pub use self::Result::Err;
+    pub use self::Result::Ok;
+
+    impl<T, E> Result<T, E> {
+        pub const fn is_ok(&self) -> bool {}
+        pub const fn is_err(&self) -> bool {}
+        pub fn ok(self) -> Option<T> {}
+        pub fn err(self) -> Option<E> {}
+        pub const fn as_ref(&self) -> Result<&T, &E> {}
+        pub fn as_mut(&mut self) -> Result<&mut T, &mut E> {}
+        pub fn map<U, F: FnOnce(T) -> U>(self, op: F) -> Result<U, E> {}
+        pub fn map_err<F, O: FnOnce(E) -> F>(self, op: O) -> Result<T, F> {}
+        pub fn and_then<U, F: FnOnce(T) -> Result<U, E>>(self, op: F) -> Result<U, E> {}
+        pub fn or_else<F, O: FnOnce(E) -> Result<T, F>>(self, op: O) -> Result<T, F> {}
+        pub fn expect(self, msg: &str) -> T where E: crate::fmt::Debug {}
+        pub fn unwrap(self) -> T where E: crate::fmt::Debug {}
+        pub fn unwrap_or(self, default: T) -> T {}
+        pub fn unwrap_or_else<F: FnOnce(E) -> T>(self, op: F) -> T {}
+        pub fn unwrap_or_default(self) -> T where T: crate::default::Default {}
+        pub fn expect_err(self, msg: &str) -> E where T: crate::fmt::Debug {}
+        pub fn unwrap_err(self) -> E where T: crate::fmt::Debug {}
+    }
+
+    impl<T: crate::clone::Clone, E: crate::clone::Clone> crate::clone::Clone for Result<T, E> {
+        fn clone(&self) -> Self {}
+    }
+    impl<T: crate::marker::Copy, E: crate::marker::Copy> crate::marker::Copy for Result<T, E> {}
+    impl<T: crate::cmp::PartialEq, E: crate::cmp::PartialEq> crate::cmp::PartialEq for Result<T, E> {
+        fn eq(&self, other: &Self) -> bool {}
+    }
+    impl<T: crate::cmp::Eq, E: crate::cmp::Eq> crate::cmp::Eq for Result<T, E> {}
+    impl<T: crate::fmt::Debug, E: crate::fmt::Debug> crate::fmt::Debug for Result<T, E> {
+        fn fmt(&self, f: &mut crate::fmt::Formatter<'_>) -> crate::fmt::Result {}
+    }
+}
+
+pub mod iter {
+    use crate::cmp::Ord;
+    use crate::ops::FnMut;
+    use crate::option::Option;
+
+    pub trait Iterator {
+        type Item;
+        fn next(&mut self) -> Option<Self::Item>;
+        fn size_hint(&self) -> (usize, Option<usize>) {}
+        fn count(self) -> usize where Self: Sized {}
+        fn last(self) -> Option<Self::Item> where Self: Sized {}
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {}
+        fn map<B, F>(self, f: F) -> Map<Self, F> where Self: Sized, F: FnMut(Self::Item) -> B {}
+        fn filter<P>(self, predicate: P) -> Filter<Self, P> where Self: Sized, P: FnMut(&Self::Item) -> bool {}
+        fn filter_map<B, F>(self, f: F) -> FilterMap<Self, F> where Self: Sized, F: FnMut(Self::Item) -> Option<B> {}
+        fn enumerate(self) -> Enumerate<Self> where Self: Sized {}
+        fn zip<U>(self, other: U) -> Zip<Self, U::IntoIter> where Self: Sized, U: IntoIterator {}
+        fn chain<U>(self, other: U) -> Chain<Self, U::IntoIter> where Self: Sized, U: IntoIterator<Item = Self::Item> {}
+        fn take(self, n: usize) -> Take<Self> where Self: Sized {}
+        fn skip(self, n: usize) -> Skip<Self> where Self: Sized {}
+        fn peekable(self) -> Peekable<Self> where Self: Sized {}
+        fn rev(self) -> Rev<Self> where Self: Sized + DoubleEndedIterator {}
+        fn for_each<F>(self, f: F) where Self: Sized, F: FnMut(Self::Item) {}
+        fn collect<B: FromIterator<Self::Item>>(self) -> B where Self: Sized {}
+        fn fold<B, F>(self, init: B, f: F) -> B where Self: Sized, F: FnMut(B, Self::Item) -> B {}
+        fn any<F>(&mut self, f: F) -> bool where Self: Sized, F: FnMut(Self::Item) -> bool {}
+        fn all<F>(&mut self, f: F) -> bool where Self: Sized, F: FnMut(Self::Item) -> bool {}
+        fn find<P>(&mut self, predicate: P) -> Option<Self::Item> where Self: Sized, P: FnMut(&Self::Item) -> bool {}
+        fn position<P>(&mut self, predicate: P) -> Option<usize> where Self: Sized, P: FnMut(Self::Item) -> bool {}
+        fn max(self) -> Option<Self::Item> where Self: Sized, Self::Item: Ord {}
+        fn min(self) -> Option<Self::Item> where Self: Sized, Self::Item: Ord {}
+        fn sum<S>(self) -> S where Self: Sized, S: Sum<Self::Item> {}
+        fn cloned<'a, T: 'a + crate::clone::Clone>(self) -> Cloned<Self> where Self: Sized + Iterator<Item = &'a T> {}
+        fn copied<'a, T: 'a + crate::marker::Copy>(self) -> Copied<Self> where Self: Sized + Iterator<Item = &'a T> {}
+    }
+
+    pub trait IntoIterator {
+        type Item;
+        type IntoIter: Iterator<Item = Self::Item>;
+        fn into_iter(self) -> Self::IntoIter;
+    }
+
+    pub trait FromIterator<A>: Sized {
+        fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self;
+    }
+
+    pub trait DoubleEndedIterator: Iterator {
+        fn next_back(&mut self) -> Option<Self::Item>;
+    }
+
+    pub trait ExactSizeIterator: Iterator {
+        fn len(&self) -> usize {}
+    }
+
+    pub trait Extend<A> {
+        fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T);
+    }
+
+    pub trait Sum<A = Self>: Sized {
+        fn sum<I: Iterator<Item = A>>(iter: I) -> Self;
+    }
+
+    pub trait Product<A = Self>: Sized {
+        fn product<I: Iterator<Item = A>>(iter: I) -> Self;
+    }
+
+    impl<I: Iterator> IntoIterator for I {
+        type Item = I::Item;
+        type IntoIter = I;
+        fn into_iter(self) -> I {}
+    }
+
+    pub struct Map<I, F> {
+        iter: I,
+        f: F,
+    }
+    pub struct Filter<I, P> {
+        iter: I,
+        predicate: P,
+    }
+    pub struct FilterMap<I, F> {
+        iter: I,
+        f: F,
+    }
+    pub struct Enumerate<I> {
+        iter: I,
+        count: usize,
+    }
+    pub struct Zip<A, B> {
+        a: A,
+        b: B,
+    }
+    pub struct Chain<A, B> {
+        a: Option<A>,
+        b: Option<B>,
+    }
+    pub struct Take<I> {
+        iter: I,
+        n: usize,
+    }
+    pub struct Skip<I> {
+        iter: I,
+        n: usize,
+    }
+    pub struct Peekable<I: Iterator> {
+        iter: I,
+        peeked: Option<Option<I::Item>>,
+    }
+    pub struct Rev<T> {
+        iter: T,
+    }
+    pub struct Cloned<I> {
+        it: I,
+    }
+    pub struct Copied<I> {
+        it: I,
+    }
+    pub struct Empty<T>(crate::marker::PhantomData<T>);
+    pub struct Once<T> {
+        inner: Option<T>,
+    }
+
+    pub fn empty<T>() -> Empty<T> {}
+    pub fn once<T>(value: T) -> Once<T> {}
+
+    impl<B, I: Iterator, F> Iterator for Map<I, F>
+    where
+        F: FnMut(I::Item) -> B,
+    {
+        type Item = B;
+        fn next(&mut self) -> Option<B> {}
+    }
+    impl<I: Iterator, P> Iterator for Filter<I, P>
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        type Item = I::Item;
+        fn next(&mut self) -> Option<I::Item> {}
+    }
+    impl<B, I: Iterator, F> Iterator for FilterMap<I, F>
+    where
+        F: FnMut(I::Item) -> Option<B>,
+    {
+        type Item = B;
+        fn next(&mut self) -> Option<B> {}
+    }
+    impl<I: Iterator> Iterator for Enumerate<I> {
+        type Item = (usize, I::Item);
+        fn next(&mut self) -> Option<(usize, I::Item)> {}
+    }
+    impl<A: Iterator, B: Iterator> Iterator for Zip<A, B> {
+        type Item = (A::Item, B::Item);
+        fn next(&mut self) -> Option<(A::Item, B::Item)> {}
+    }
+    impl<A: Iterator, B: Iterator<Item = A::Item>> Iterator for Chain<A, B> {
+        type Item = A::Item;
+        fn next(&mut self) -> Option<A::Item> {}
+    }
+    impl<I: Iterator> Iterator for Take<I> {
+        type Item = I::Item;
+        fn next(&mut self) -> Option<I::Item> {}
+    }
+    impl<I: Iterator> Iterator for Skip<I> {
+        type Item = I::Item;
+        fn next(&mut self) -> Option<I::Item> {}
+    }
+    impl<I: Iterator> Iterator for Peekable<I> {
+        type Item = I::Item;
+        fn next(&mut self) -> Option<I::Item> {}
+    }
+    impl<I: Iterator> Peekable<I> {
+        pub fn peek(&mut self) -> Option<&I::Item> {}
+    }
+    impl<I: DoubleEndedIterator> Iterator for Rev<I> {
+        type Item = I::Item;
+        fn next(&mut self) -> Option<I::Item> {}
+    }
+    impl<'a, I: Iterator<Item = &'a T>, T: 'a + crate::clone::Clone> Iterator for Cloned<I> {
+        type Item = T;
+        fn next(&mut self) -> Option<T> {}
+    }
+    impl<'a, I: Iterator<Item = &'a T>, T: 'a + crate::marker::Copy> Iterator for Copied<I> {
+        type Item = T;
+        fn next(&mut self) -> Option<T> {}
+    }
+    impl<T> Iterator for Empty<T> {
+        type Item = T;
+        fn next(&mut self) -> Option<T> {}
+    }
+    impl<T> Iterator for Once<T> {
+        type Item = T;
+        fn next(&mut self) -> Option<T> {}
+    }
+    impl<A: crate::clone::Clone> Iterator for crate::ops::Range<A> {
+        type Item = A;
+        fn next(&mut self) -> Option<A> {}
+    }
+    impl<A: crate::clone::Clone> Iterator for crate::ops::RangeInclusive<A> {
+        type Item = A;
+        fn next(&mut self) -> Option<A> {}
+    }
+}
+
+pub mod fmt {
+    pub type Result = crate::result::Result<(), Error>;
+
+    pub struct Error;
+
+    pub struct Formatter<'a> {
+        buf: &'a mut (dyn Write + 'a),
+    }
+
+    pub struct Arguments<'a> {
+        pieces: &'a [&'static str],
+    }
+
+    pub trait Debug {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result;
+    }
+
+    pub trait Display {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result;
+    }
+
+    pub trait Write {
+        fn write_str(&mut self, s: &str) -> Result;
+        fn write_char(&mut self, c: char) -> Result {}
+        fn write_fmt(&mut self, args: Arguments<'_>) -> Result {}
+    }
+
+    impl<'a> Formatter<'a> {
+        pub fn write_str(&mut self, data: &str) -> Result {}
+        pub fn write_fmt(&mut self, fmt: Arguments<'_>) -> Result {}
+        pub fn alternate(&self) -> bool {}
+    }
+
+    impl<T: ?Sized + Debug> Debug for &T {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {}
+    }
+    impl<T: ?Sized + Display> Display for &T {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {}
+    }
+    impl<T: Debug> Debug for [T] {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {}
+    }
+    impl Debug for Error {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {}
+    }
+    impl Display for Error {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {}
+    }
+}
+
+pub mod hash {
+    pub trait Hash {
+        fn hash<H: Hasher>(&self, state: &mut H);
+    }
+
+    pub trait Hasher {
+        fn finish(&self) -> u64;
+        fn write(&mut self, bytes: &[u8]);
+    }
+
+    pub trait BuildHasher {
+        type Hasher: Hasher;
+        fn build_hasher(&self) -> Self::Hasher;
+    }
+
+    impl<T: ?Sized + Hash> Hash for &T {
+        fn hash<H: Hasher>(&self, state: &mut H) {}
+    }
+    impl<T: Hash> Hash for [T] {
+        fn hash<H: Hasher>(&self, state: &mut H) {}
+    }
+}
+
+pub mod any {
+    use crate::option::Option;
+
+    pub trait Any: 'static {
+        fn type_id(&self) -> TypeId;
+    }
+
+    pub struct TypeId {
+        t: u128,
+    }
+
+    impl TypeId {
+        pub fn of<T: ?Sized + 'static>() -> TypeId {}
+    }
+
+    impl<T: 'static + ?Sized> Any for T {
+        fn type_id(&self) -> TypeId {}
+    }
+
+    impl dyn Any {
+        pub fn is<T: Any>(&self) -> bool {}
+        pub fn downcast_ref<T: Any>(&self) -> Option<&T> {}
+        pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {}
+    }
+
+    pub fn type_name<T: ?Sized>() -> &'static str {}
+
+    impl crate::clone::Clone for TypeId {
+        fn clone(&self) -> TypeId {}
+    }
+    impl crate::marker::Copy for TypeId {}
+    impl crate::cmp::PartialEq for TypeId {
+        fn eq(&self, other: &TypeId) -> bool {}
+    }
+    impl crate::cmp::Eq for TypeId {}
+    impl crate::hash::Hash for TypeId {
+        fn hash<H: crate::hash::Hasher>(&self, state: &mut H) {}
+    }
+    impl crate::fmt::Debug for TypeId {
+        fn fmt(&self, f: &mut crate::fmt::Formatter<'_>) -> crate::fmt::Result {}
+    }
+}
+
+pub mod cell {
+    use crate::option::Option;
+
+    pub struct UnsafeCell<T: ?Sized> {
+        value: T,
+    }
+
+    pub struct Cell<T: ?Sized> {
+        value: UnsafeCell<T>,
+    }
+
+    pub struct RefCell<T: ?Sized> {
+        borrow: Cell<isize>,
+        value: UnsafeCell<T>,
+    }
+
+    pub struct Ref<'b, T: ?Sized + 'b> {
+        value: &'b T,
+    }
+
+    pub struct RefMut<'b, T: ?Sized + 'b> {
+        value: &'b mut T,
+    }
+
+    pub struct OnceCell<T> {
+        inner: UnsafeCell<Option<T>>,
+    }
+
+    impl<T: ?Sized> !Sync for UnsafeCell<T> {}
+    unsafe impl<T: ?Sized + Send> Send for Cell<T> {}
+    impl<T: ?Sized> !Sync for Cell<T> {}
+    unsafe impl<T: ?Sized + Send> Send for RefCell<T> {}
+    impl<T: ?Sized> !Sync for RefCell<T> {}
+    impl<T> !Sync for OnceCell<T> {}
+
+    impl<T> Cell<T> {
+        pub const fn new(value: T) -> Cell<T> {}
+        pub fn set(&self, val: T) {}
+        pub fn replace(&self, val: T) -> T {}
+        pub fn into_inner(self) -> T {}
+    }
+
+    impl<T: crate::marker::Copy> Cell<T> {
+        pub fn get(&self) -> T {}
+    }
+
+    impl<T> RefCell<T> {
+        pub const fn new(value: T) -> RefCell<T> {}
+        pub fn into_inner(self) -> T {}
+        pub fn replace(&self, t: T) -> T {}
+    }
+
+    impl<T: ?Sized> RefCell<T> {
+        pub fn borrow(&self) -> Ref<'_, T> {}
+        pub fn borrow_mut(&self) -> RefMut<'_, T> {}
+    }
+
+    impl<T: ?Sized> crate::ops::Deref for Ref<'_, T> {
+        type Target = T;
+        fn deref(&self) -> &T {}
+    }
+    impl<T: ?Sized> crate::ops::Deref for RefMut<'_, T> {
+        type Target = T;
+        fn deref(&self) -> &T {}
+    }
+    impl<T: ?Sized> crate::ops::DerefMut for RefMut<'_, T> {
+        fn deref_mut(&mut self) -> &mut T {}
+    }
+
+    impl<T> OnceCell<T> {
+        pub const fn new() -> OnceCell<T> {}
+        pub fn get(&self) -> Option<&T> {}
+    }
+
+    impl<T: crate::marker::Copy> crate::clone::Clone for Cell<T> {
+        fn clone(&self) -> Cell<T> {}
+    }
+    impl<T: crate::clone::Clone> crate::clone::Clone for RefCell<T> {
+        fn clone(&self) -> RefCell<T> {}
+    }
+    impl<T: crate::default::Default> crate::default::Default for Cell<T> {
+        fn default() -> Cell<T> {}
+    }
+    impl<T: crate::default::Default> crate::default::Default for RefCell<T> {
+        fn default() -> RefCell<T> {}
+    }
+}
+
+pub mod ptr {
+    pub struct NonNull<T: ?Sized> {
+        pointer: *const T,
+    }
+
+    impl<T: ?Sized> !Send for NonNull<T> {}
+    impl<T: ?Sized> !Sync for NonNull<T> {}
+
+    impl<T: ?Sized> NonNull<T> {
+        pub const fn as_ptr(self) -> *mut T {}
+        pub unsafe fn as_ref<'a>(&self) -> &'a T {}
+        pub unsafe fn as_mut<'a>(&mut self) -> &'a mut T {}
+    }
+
+    pub fn null<T>() -> *const T {}
+    pub fn null_mut<T>() -> *mut T {}
+}
+
+pub mod pin {
+    pub struct Pin<Ptr> {
+        pointer: Ptr,
+    }
+
+    impl<Ptr: crate::ops::Deref> Pin<Ptr> {
+        pub fn new(pointer: Ptr) -> Pin<Ptr> where Ptr::Target: Unpin {}
+        pub fn as_ref(&self) -> Pin<&Ptr::Target> {}
+        pub fn into_inner(pin: Pin<Ptr>) -> Ptr where Ptr::Target: Unpin {}
+    }
+
+    impl<Ptr: crate::ops::Deref> crate::ops::Deref for Pin<Ptr> {
+        type Target = Ptr::Target;
+        fn deref(&self) -> &Ptr::Target {}
+    }
+}
+
+pub mod task {
+    pub struct Context<'a> {
+        waker: &'a Waker,
+    }
+
+    impl<'a> Context<'a> {
+        pub fn from_waker(waker: &'a Waker) -> Context<'a> {}
+        pub fn waker(&self) -> &'a Waker {}
+    }
+
+    pub struct Waker {
+        data: *const (),
+    }
+
+    impl Waker {
+        pub fn wake(self) {}
+        pub fn wake_by_ref(&self) {}
+    }
+
+    pub enum Poll<T> {
+        Ready(T),
+        Pending,
+    }
+
+    impl<T> Poll<T> {
+        pub fn is_ready(&self) -> bool {}
+        pub fn is_pending(&self) -> bool {}
+        pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Poll<U> {}
+    }
+}
+
+pub mod future {
+    use crate::pin::Pin;
+    use crate::task::{Context, Poll};
+
+    pub trait Future {
+        type Output;
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>;
+    }
+
+    pub trait IntoFuture {
+        type Output;
+        type IntoFuture: Future<Output = Self::Output>;
+        fn into_future(self) -> Self::IntoFuture;
+    }
+
+    impl<F: Future> IntoFuture for F {
+        type Output = F::Output;
+        type IntoFuture = F;
+        fn into_future(self) -> Self::IntoFuture {}
+    }
+}
+
+pub mod sync {
+    pub mod atomic {
+        pub enum Ordering {
+            Relaxed,
+            Release,
+            Acquire,
+            AcqRel,
+            SeqCst,
+        }
+
+        pub struct AtomicBool {
+            v: crate::cell::UnsafeCell<u8>,
+        }
+        pub struct AtomicUsize {
+            v: crate::cell::UnsafeCell<usize>,
+        }
+        pub struct AtomicIsize {
+            v: crate::cell::UnsafeCell<isize>,
+        }
+        pub struct AtomicU32 {
+            v: crate::cell::UnsafeCell<u32>,
+        }
+        pub struct AtomicU64 {
+            v: crate::cell::UnsafeCell<u64>,
+        }
+        pub struct AtomicI32 {
+            v: crate::cell::UnsafeCell<i32>,
+        }
+        pub struct AtomicI64 {
+            v: crate::cell::UnsafeCell<i64>,
+        }
+
+        unsafe impl Sync for AtomicBool {}
+        unsafe impl Sync for AtomicUsize {}
+        unsafe impl Sync for AtomicIsize {}
+        unsafe impl Sync for AtomicU32 {}
+        unsafe impl Sync for AtomicU64 {}
+        unsafe impl Sync for AtomicI32 {}
+        unsafe impl Sync for AtomicI64 {}
+
+        impl AtomicBool {
+            pub const fn new(v: bool) -> AtomicBool {}
+            pub fn load(&self, order: Ordering) -> bool {}
+            pub fn store(&self, val: bool, order: Ordering) {}
+        }
+        impl AtomicUsize {
+            pub const fn new(v: usize) -> AtomicUsize {}
+            pub fn load(&self, order: Ordering) -> usize {}
+            pub fn store(&self, val: usize, order: Ordering) {}
+            pub fn fetch_add(&self, val: usize, order: Ordering) -> usize {}
+            pub fn fetch_sub(&self, val: usize, order: Ordering) -> usize {}
+        }
+    }
+}
+
+pub mod mem {
+    pub fn drop<T>(_x: T) {}
+    pub fn forget<T>(t: T) {}
+    pub fn replace<T>(dest: &mut T, src: T) -> T {}
+    pub fn swap<T>(x: &mut T, y: &mut T) {}
+    pub fn take<T: crate::default::Default>(dest: &mut T) -> T {}
+    pub const fn size_of<T>() -> usize {}
+    pub const fn align_of<T>() -> usize {}
+
+    pub struct ManuallyDrop<T: ?Sized> {
+        value: T,
+    }
+
+    pub union MaybeUninit<T> {
+        uninit: (),
+        value: ManuallyDrop<T>,
+    }
+}
+
+pub mod num {
+    pub struct NonZeroU8(u8);
+    pub struct NonZeroU16(u16);
+    pub struct NonZeroU32(u32);
+    pub struct NonZeroU64(u64);
+    pub struct NonZeroUsize(usize);
+    pub struct NonZeroI32(i32);
+    pub struct NonZeroI64(i64);
+
+    pub struct Wrapping<T>(pub T);
+
+    pub struct ParseIntError {
+        kind: IntErrorKind,
+    }
+
+    pub struct ParseFloatError {
+        kind: u8,
+    }
+
+    pub enum IntErrorKind {
+        Empty,
+        InvalidDigit,
+        PosOverflow,
+        NegOverflow,
+        Zero,
+    }
+}
+
+pub mod time {
+    pub struct Duration {
+        secs: u64,
+        nanos: u32,
+    }
+
+    impl Duration {
+        pub const ZERO: Duration = Duration { secs: 0, nanos: 0 };
+        pub const fn new(secs: u64, nanos: u32) -> Duration {}
+        pub const fn from_secs(secs: u64) -> Duration {}
+        pub const fn from_millis(millis: u64) -> Duration {}
+        pub fn from_secs_f32(secs: f32) -> Duration {}
+        pub fn from_secs_f64(secs: f64) -> Duration {}
+        pub const fn as_secs(&self) -> u64 {}
+        pub const fn as_millis(&self) -> u128 {}
+        pub fn as_secs_f32(&self) -> f32 {}
+        pub fn as_secs_f64(&self) -> f64 {}
+    }
+
+    impl crate::clone::Clone for Duration {
+        fn clone(&self) -> Duration {}
+    }
+    impl crate::marker::Copy for Duration {}
+    impl crate::default::Default for Duration {
+        fn default() -> Duration {}
+    }
+    impl crate::cmp::PartialEq for Duration {
+        fn eq(&self, other: &Duration) -> bool {}
+    }
+    impl crate::cmp::Eq for Duration {}
+    impl crate::hash::Hash for Duration {
+        fn hash<H: crate::hash::Hasher>(&self, state: &mut H) {}
+    }
+    impl crate::fmt::Debug for Duration {
+        fn fmt(&self, f: &mut crate::fmt::Formatter<'_>) -> crate::fmt::Result {}
+    }
+    impl crate::ops::Add for Duration {
+        type Output = Duration;
+        fn add(self, rhs: Duration) -> Duration {}
+    }
+    impl crate::ops::Sub for Duration {
+        type Output = Duration;
+        fn sub(self, rhs: Duration) -> Duration {}
+    }
+}
+
+pub mod borrow {
+    pub trait Borrow<Borrowed: ?Sized> {
+        fn borrow(&self) -> &Borrowed;
+    }
+
+    pub trait BorrowMut<Borrowed: ?Sized>: Borrow<Borrowed> {
+        fn borrow_mut(&mut self) -> &mut Borrowed;
+    }
+
+    impl<T: ?Sized> Borrow<T> for T {
+        fn borrow(&self) -> &T {}
+    }
+}
+
+pub mod ffi {
+    #[cfg(not(all(
+        any(target_arch = "aarch64", target_arch = "arm", target_arch = "powerpc", target_arch = "riscv64"),
+        not(any(target_os = "macos", target_os = "ios", target_os = "windows"))
+    )))]
+    pub type c_char = i8;
+    #[cfg(all(
+        any(target_arch = "aarch64", target_arch = "arm", target_arch = "powerpc", target_arch = "riscv64"),
+        not(any(target_os = "macos", target_os = "ios", target_os = "windows"))
+    ))]
+    pub type c_char = u8;
+    pub type c_schar = i8;
+    pub type c_uchar = u8;
+    pub type c_short = i16;
+    pub type c_ushort = u16;
+    pub type c_int = i32;
+    pub type c_uint = u32;
+    #[cfg(all(target_pointer_width = "64", not(target_os = "windows")))]
+    pub type c_long = i64;
+    #[cfg(all(target_pointer_width = "64", not(target_os = "windows")))]
+    pub type c_ulong = u64;
+    #[cfg(any(target_pointer_width = "32", target_os = "windows"))]
+    pub type c_long = i32;
+    #[cfg(any(target_pointer_width = "32", target_os = "windows"))]
+    pub type c_ulong = u32;
+    pub type c_longlong = i64;
+    pub type c_ulonglong = u64;
+    pub type c_float = f32;
+    pub type c_double = f64;
+    pub type c_size_t = usize;
+    pub type c_ssize_t = isize;
+    pub type c_ptrdiff_t = isize;
+
+    pub enum c_void {}
+
+    pub struct CStr {
+        inner: [c_char],
+    }
+
+    impl CStr {
+        pub unsafe fn from_ptr<'a>(ptr: *const c_char) -> &'a CStr {}
+        pub fn as_ptr(&self) -> *const c_char {}
+        pub fn to_bytes(&self) -> &[u8] {}
+        pub fn to_str(&self) -> crate::result::Result<&str, crate::str::Utf8Error> {}
+    }
+
+    pub struct VaList<'a, 'f: 'a> {
+        inner: &'a mut &'f c_void,
+    }
+}
+
+pub mod error {
+    use crate::fmt::{Debug, Display};
+    use crate::option::Option;
+
+    pub trait Error: Debug + Display {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {}
+        fn description(&self) -> &str {}
+    }
+}
+
+pub mod slice {
+    use crate::option::Option;
+
+    pub struct Iter<'a, T: 'a> {
+        ptr: *const T,
+        end: *const T,
+        _marker: crate::marker::PhantomData<&'a T>,
+    }
+
+    pub struct IterMut<'a, T: 'a> {
+        ptr: *mut T,
+        end: *mut T,
+        _marker: crate::marker::PhantomData<&'a mut T>,
+    }
+
+    unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+    unsafe impl<T: Sync> Send for Iter<'_, T> {}
+    unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+    unsafe impl<T: Send> Send for IterMut<'_, T> {}
+
+    impl<'a, T> crate::iter::Iterator for Iter<'a, T> {
+        type Item = &'a T;
+        fn next(&mut self) -> Option<&'a T> {}
+    }
+
+    impl<'a, T> crate::iter::Iterator for IterMut<'a, T> {
+        type Item = &'a mut T;
+        fn next(&mut self) -> Option<&'a mut T> {}
+    }
+
+    impl<T> [T] {
+        pub const fn len(&self) -> usize {}
+        pub const fn is_empty(&self) -> bool {}
+        pub fn first(&self) -> Option<&T> {}
+        pub fn last(&self) -> Option<&T> {}
+        pub fn get(&self, index: usize) -> Option<&T> {}
+        pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {}
+        pub fn iter(&self) -> Iter<'_, T> {}
+        pub fn iter_mut(&mut self) -> IterMut<'_, T> {}
+        pub fn contains(&self, x: &T) -> bool where T: crate::cmp::PartialEq {}
+        pub fn swap(&mut self, a: usize, b: usize) {}
+        pub fn reverse(&mut self) {}
+    }
+
+    impl<'a, T> crate::iter::IntoIterator for &'a [T] {
+        type Item = &'a T;
+        type IntoIter = Iter<'a, T>;
+        fn into_iter(self) -> Iter<'a, T> {}
+    }
+
+    impl<'a, T> crate::iter::IntoIterator for &'a mut [T] {
+        type Item = &'a mut T;
+        type IntoIter = IterMut<'a, T>;
+        fn into_iter(self) -> IterMut<'a, T> {}
+    }
+}
+
+pub mod str {
+    use crate::option::Option;
+    use crate::result::Result;
+
+    pub trait FromStr: Sized {
+        type Err;
+        fn from_str(s: &str) -> Result<Self, Self::Err>;
+    }
+
+    pub struct Chars<'a> {
+        iter: crate::slice::Iter<'a, u8>,
+    }
+
+    pub struct Utf8Error {
+        valid_up_to: usize,
+    }
+
+    impl<'a> crate::iter::Iterator for Chars<'a> {
+        type Item = char;
+        fn next(&mut self) -> Option<char> {}
+    }
+
+    impl str {
+        pub const fn len(&self) -> usize {}
+        pub const fn is_empty(&self) -> bool {}
+        pub fn chars(&self) -> Chars<'_> {}
+        pub const fn as_bytes(&self) -> &[u8] {}
+        pub fn trim(&self) -> &str {}
+        pub fn starts_with(&self, pat: &str) -> bool {}
+        pub fn ends_with(&self, pat: &str) -> bool {}
+        pub fn contains(&self, pat: &str) -> bool {}
+        pub fn find(&self, pat: &str) -> Option<usize> {}
+        pub fn parse<F: FromStr>(&self) -> Result<F, F::Err> {}
+    }
+
+    pub fn from_utf8(v: &[u8]) -> Result<&str, Utf8Error> {}
+}
+
+pub mod char {
+    impl char {
+        pub const fn is_ascii(&self) -> bool {}
+        pub fn is_alphabetic(self) -> bool {}
+        pub fn is_numeric(self) -> bool {}
+        pub fn is_whitespace(self) -> bool {}
+        pub const fn len_utf8(self) -> usize {}
+    }
+}
+
+pub mod array {
+    impl<T, const N: usize> [T; N] {
+        pub fn map<F, U>(self, f: F) -> [U; N] where F: crate::ops::FnMut(T) -> U {}
+        pub const fn as_slice(&self) -> &[T] {}
+        pub fn as_mut_slice(&mut self) -> &mut [T] {}
+    }
+
+    impl<T: crate::clone::Clone, const N: usize> crate::clone::Clone for [T; N] {
+        fn clone(&self) -> Self {}
+    }
+    impl<T: crate::marker::Copy, const N: usize> crate::marker::Copy for [T; N] {}
+    impl<T: crate::default::Default, const N: usize> crate::default::Default for [T; N] {
+        fn default() -> [T; N] {}
+    }
+    impl<T: crate::cmp::PartialEq, const N: usize> crate::cmp::PartialEq for [T; N] {
+        fn eq(&self, other: &[T; N]) -> bool {}
+    }
+    impl<T: crate::cmp::Eq, const N: usize> crate::cmp::Eq for [T; N] {}
+    impl<T: crate::hash::Hash, const N: usize> crate::hash::Hash for [T; N] {
+        fn hash<H: crate::hash::Hasher>(&self, state: &mut H) {}
+    }
+    impl<T: crate::fmt::Debug, const N: usize> crate::fmt::Debug for [T; N] {
+        fn fmt(&self, f: &mut crate::fmt::Formatter<'_>) -> crate::fmt::Result {}
+    }
+    impl<T, const N: usize> crate::ops::Index<usize> for [T; N] {
+        type Output = T;
+        fn index(&self, index: usize) -> &T {}
+    }
+    impl<T, const N: usize> crate::ops::IndexMut<usize> for [T; N] {
+        fn index_mut(&mut self, index: usize) -> &mut T {}
+    }
+}
+
+pub mod tuple {
+    impl crate::clone::Clone for () {
+        fn clone(&self) -> () {}
+    }
+    impl crate::marker::Copy for () {}
+    impl crate::default::Default for () {
+        fn default() -> () {}
+    }
+    impl crate::cmp::PartialEq for () {
+        fn eq(&self, other: &()) -> bool {}
+    }
+    impl crate::cmp::Eq for () {}
+    impl crate::fmt::Debug for () {
+        fn fmt(&self, f: &mut crate::fmt::Formatter<'_>) -> crate::fmt::Result {}
+    }
+    impl<A: crate::clone::Clone, B: crate::clone::Clone> crate::clone::Clone for (A, B) {
+        fn clone(&self) -> (A, B) {}
+    }
+    impl<A: crate::marker::Copy, B: crate::marker::Copy> crate::marker::Copy for (A, B) {}
+    impl<A: crate::cmp::PartialEq, B: crate::cmp::PartialEq> crate::cmp::PartialEq for (A, B) {
+        fn eq(&self, other: &(A, B)) -> bool {}
+    }
+    impl<A: crate::fmt::Debug, B: crate::fmt::Debug> crate::fmt::Debug for (A, B) {
+        fn fmt(&self, f: &mut crate::fmt::Formatter<'_>) -> crate::fmt::Result {}
+    }
+    impl<A: crate::clone::Clone, B: crate::clone::Clone, C: crate::clone::Clone> crate::clone::Clone for (A, B, C) {
+        fn clone(&self) -> (A, B, C) {}
+    }
+    impl<A: crate::marker::Copy, B: crate::marker::Copy, C: crate::marker::Copy> crate::marker::Copy for (A, B, C) {}
+}
+
+/// trait impls of the primitive types
+mod impls {
+    use crate::clone::Clone;
+    use crate::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+    use crate::default::Default;
+    use crate::fmt::{Debug, Display, Formatter, Result};
+    use crate::hash::{Hash, Hasher};
+    use crate::marker::Copy;
+    use crate::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+    use crate::option::Option;
+    use crate::str::FromStr;
+
+    impl Clone for bool { fn clone(&self) -> bool {} }
+    impl Copy for bool {}
+    impl Default for bool { fn default() -> bool {} }
+    impl PartialEq for bool { fn eq(&self, other: &bool) -> bool {} }
+    impl PartialOrd for bool { fn partial_cmp(&self, other: &bool) -> Option<Ordering> {} }
+    impl Eq for bool {}
+    impl Ord for bool { fn cmp(&self, other: &bool) -> Ordering {} }
+    impl Hash for bool { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for bool { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for bool { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for bool { type Err = (); fn from_str(s: &str) -> crate::result::Result<bool, Self::Err> {} }
+
+    impl Clone for char { fn clone(&self) -> char {} }
+    impl Copy for char {}
+    impl Default for char { fn default() -> char {} }
+    impl PartialEq for char { fn eq(&self, other: &char) -> bool {} }
+    impl PartialOrd for char { fn partial_cmp(&self, other: &char) -> Option<Ordering> {} }
+    impl Eq for char {}
+    impl Ord for char { fn cmp(&self, other: &char) -> Ordering {} }
+    impl Hash for char { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for char { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for char { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for char { type Err = (); fn from_str(s: &str) -> crate::result::Result<char, Self::Err> {} }
+
+    impl Clone for i8 { fn clone(&self) -> i8 {} }
+    impl Copy for i8 {}
+    impl Default for i8 { fn default() -> i8 {} }
+    impl PartialEq for i8 { fn eq(&self, other: &i8) -> bool {} }
+    impl PartialOrd for i8 { fn partial_cmp(&self, other: &i8) -> Option<Ordering> {} }
+    impl Eq for i8 {}
+    impl Ord for i8 { fn cmp(&self, other: &i8) -> Ordering {} }
+    impl Hash for i8 { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for i8 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for i8 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for i8 { type Err = crate::num::ParseIntError; fn from_str(s: &str) -> crate::result::Result<i8, Self::Err> {} }
+    impl Add for i8 { type Output = i8; fn add(self, rhs: i8) -> i8 {} }
+    impl Sub for i8 { type Output = i8; fn sub(self, rhs: i8) -> i8 {} }
+    impl Mul for i8 { type Output = i8; fn mul(self, rhs: i8) -> i8 {} }
+    impl Div for i8 { type Output = i8; fn div(self, rhs: i8) -> i8 {} }
+    impl Rem for i8 { type Output = i8; fn rem(self, rhs: i8) -> i8 {} }
+    impl AddAssign for i8 { fn add_assign(&mut self, rhs: i8) {} }
+    impl SubAssign for i8 { fn sub_assign(&mut self, rhs: i8) {} }
+    impl MulAssign for i8 { fn mul_assign(&mut self, rhs: i8) {} }
+    impl DivAssign for i8 { fn div_assign(&mut self, rhs: i8) {} }
+    impl Neg for i8 { type Output = i8; fn neg(self) -> i8 {} }
+    impl crate::iter::Sum for i8 { fn sum<I: crate::iter::Iterator<Item = i8>>(iter: I) -> i8 {} }
+
+    impl Clone for i16 { fn clone(&self) -> i16 {} }
+    impl Copy for i16 {}
+    impl Default for i16 { fn default() -> i16 {} }
+    impl PartialEq for i16 { fn eq(&self, other: &i16) -> bool {} }
+    impl PartialOrd for i16 { fn partial_cmp(&self, other: &i16) -> Option<Ordering> {} }
+    impl Eq for i16 {}
+    impl Ord for i16 { fn cmp(&self, other: &i16) -> Ordering {} }
+    impl Hash for i16 { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for i16 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for i16 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for i16 { type Err = crate::num::ParseIntError; fn from_str(s: &str) -> crate::result::Result<i16, Self::Err> {} }
+    impl Add for i16 { type Output = i16; fn add(self, rhs: i16) -> i16 {} }
+    impl Sub for i16 { type Output = i16; fn sub(self, rhs: i16) -> i16 {} }
+    impl Mul for i16 { type Output = i16; fn mul(self, rhs: i16) -> i16 {} }
+    impl Div for i16 { type Output = i16; fn div(self, rhs: i16) -> i16 {} }
+    impl Rem for i16 { type Output = i16; fn rem(self, rhs: i16) -> i16 {} }
+    impl AddAssign for i16 { fn add_assign(&mut self, rhs: i16) {} }
+    impl SubAssign for i16 { fn sub_assign(&mut self, rhs: i16) {} }
+    impl MulAssign for i16 { fn mul_assign(&mut self, rhs: i16) {} }
+    impl DivAssign for i16 { fn div_assign(&mut self, rhs: i16) {} }
+    impl Neg for i16 { type Output = i16; fn neg(self) -> i16 {} }
+    impl crate::iter::Sum for i16 { fn sum<I: crate::iter::Iterator<Item = i16>>(iter: I) -> i16 {} }
+
+    impl Clone for i32 { fn clone(&self) -> i32 {} }
+    impl Copy for i32 {}
+    impl Default for i32 { fn default() -> i32 {} }
+    impl PartialEq for i32 { fn eq(&self, other: &i32) -> bool {} }
+    impl PartialOrd for i32 { fn partial_cmp(&self, other: &i32) -> Option<Ordering> {} }
+    impl Eq for i32 {}
+    impl Ord for i32 { fn cmp(&self, other: &i32) -> Ordering {} }
+    impl Hash for i32 { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for i32 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for i32 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for i32 { type Err = crate::num::ParseIntError; fn from_str(s: &str) -> crate::result::Result<i32, Self::Err> {} }
+    impl Add for i32 { type Output = i32; fn add(self, rhs: i32) -> i32 {} }
+    impl Sub for i32 { type Output = i32; fn sub(self, rhs: i32) -> i32 {} }
+    impl Mul for i32 { type Output = i32; fn mul(self, rhs: i32) -> i32 {} }
+    impl Div for i32 { type Output = i32; fn div(self, rhs: i32) -> i32 {} }
+    impl Rem for i32 { type Output = i32; fn rem(self, rhs: i32) -> i32 {} }
+    impl AddAssign for i32 { fn add_assign(&mut self, rhs: i32) {} }
+    impl SubAssign for i32 { fn sub_assign(&mut self, rhs: i32) {} }
+    impl MulAssign for i32 { fn mul_assign(&mut self, rhs: i32) {} }
+    impl DivAssign for i32 { fn div_assign(&mut self, rhs: i32) {} }
+    impl Neg for i32 { type Output = i32; fn neg(self) -> i32 {} }
+    impl crate::iter::Sum for i32 { fn sum<I: crate::iter::Iterator<Item = i32>>(iter: I) -> i32 {} }
+
+    impl Clone for i64 { fn clone(&self) -> i64 {} }
+    impl Copy for i64 {}
+    impl Default for i64 { fn default() -> i64 {} }
+    impl PartialEq for i64 { fn eq(&self, other: &i64) -> bool {} }
+    impl PartialOrd for i64 { fn partial_cmp(&self, other: &i64) -> Option<Ordering> {} }
+    impl Eq for i64 {}
+    impl Ord for i64 { fn cmp(&self, other: &i64) -> Ordering {} }
+    impl Hash for i64 { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for i64 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for i64 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for i64 { type Err = crate::num::ParseIntError; fn from_str(s: &str) -> crate::result::Result<i64, Self::Err> {} }
+    impl Add for i64 { type Output = i64; fn add(self, rhs: i64) -> i64 {} }
+    impl Sub for i64 { type Output = i64; fn sub(self, rhs: i64) -> i64 {} }
+    impl Mul for i64 { type Output = i64; fn mul(self, rhs: i64) -> i64 {} }
+    impl Div for i64 { type Output = i64; fn div(self, rhs: i64) -> i64 {} }
+    impl Rem for i64 { type Output = i64; fn rem(self, rhs: i64) -> i64 {} }
+    impl AddAssign for i64 { fn add_assign(&mut self, rhs: i64) {} }
+    impl SubAssign for i64 { fn sub_assign(&mut self, rhs: i64) {} }
+    impl MulAssign for i64 { fn mul_assign(&mut self, rhs: i64) {} }
+    impl DivAssign for i64 { fn div_assign(&mut self, rhs: i64) {} }
+    impl Neg for i64 { type Output = i64; fn neg(self) -> i64 {} }
+    impl crate::iter::Sum for i64 { fn sum<I: crate::iter::Iterator<Item = i64>>(iter: I) -> i64 {} }
+
+    impl Clone for i128 { fn clone(&self) -> i128 {} }
+    impl Copy for i128 {}
+    impl Default for i128 { fn default() -> i128 {} }
+    impl PartialEq for i128 { fn eq(&self, other: &i128) -> bool {} }
+    impl PartialOrd for i128 { fn partial_cmp(&self, other: &i128) -> Option<Ordering> {} }
+    impl Eq for i128 {}
+    impl Ord for i128 { fn cmp(&self, other: &i128) -> Ordering {} }
+    impl Hash for i128 { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for i128 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for i128 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for i128 { type Err = crate::num::ParseIntError; fn from_str(s: &str) -> crate::result::Result<i128, Self::Err> {} }
+    impl Add for i128 { type Output = i128; fn add(self, rhs: i128) -> i128 {} }
+    impl Sub for i128 { type Output = i128; fn sub(self, rhs: i128) -> i128 {} }
+    impl Mul for i128 { type Output = i128; fn mul(self, rhs: i128) -> i128 {} }
+    impl Div for i128 { type Output = i128; fn div(self, rhs: i128) -> i128 {} }
+    impl Rem for i128 { type Output = i128; fn rem(self, rhs: i128) -> i128 {} }
+    impl AddAssign for i128 { fn add_assign(&mut self, rhs: i128) {} }
+    impl SubAssign for i128 { fn sub_assign(&mut self, rhs: i128) {} }
+    impl MulAssign for i128 { fn mul_assign(&mut self, rhs: i128) {} }
+    impl DivAssign for i128 { fn div_assign(&mut self, rhs: i128) {} }
+    impl Neg for i128 { type Output = i128; fn neg(self) -> i128 {} }
+    impl crate::iter::Sum for i128 { fn sum<I: crate::iter::Iterator<Item = i128>>(iter: I) -> i128 {} }
+
+    impl Clone for isize { fn clone(&self) -> isize {} }
+    impl Copy for isize {}
+    impl Default for isize { fn default() -> isize {} }
+    impl PartialEq for isize { fn eq(&self, other: &isize) -> bool {} }
+    impl PartialOrd for isize { fn partial_cmp(&self, other: &isize) -> Option<Ordering> {} }
+    impl Eq for isize {}
+    impl Ord for isize { fn cmp(&self, other: &isize) -> Ordering {} }
+    impl Hash for isize { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for isize { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for isize { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for isize { type Err = crate::num::ParseIntError; fn from_str(s: &str) -> crate::result::Result<isize, Self::Err> {} }
+    impl Add for isize { type Output = isize; fn add(self, rhs: isize) -> isize {} }
+    impl Sub for isize { type Output = isize; fn sub(self, rhs: isize) -> isize {} }
+    impl Mul for isize { type Output = isize; fn mul(self, rhs: isize) -> isize {} }
+    impl Div for isize { type Output = isize; fn div(self, rhs: isize) -> isize {} }
+    impl Rem for isize { type Output = isize; fn rem(self, rhs: isize) -> isize {} }
+    impl AddAssign for isize { fn add_assign(&mut self, rhs: isize) {} }
+    impl SubAssign for isize { fn sub_assign(&mut self, rhs: isize) {} }
+    impl MulAssign for isize { fn mul_assign(&mut self, rhs: isize) {} }
+    impl DivAssign for isize { fn div_assign(&mut self, rhs: isize) {} }
+    impl Neg for isize { type Output = isize; fn neg(self) -> isize {} }
+    impl crate::iter::Sum for isize { fn sum<I: crate::iter::Iterator<Item = isize>>(iter: I) -> isize {} }
+
+    impl Clone for u8 { fn clone(&self) -> u8 {} }
+    impl Copy for u8 {}
+    impl Default for u8 { fn default() -> u8 {} }
+    impl PartialEq for u8 { fn eq(&self, other: &u8) -> bool {} }
+    impl PartialOrd for u8 { fn partial_cmp(&self, other: &u8) -> Option<Ordering> {} }
+    impl Eq for u8 {}
+    impl Ord for u8 { fn cmp(&self, other: &u8) -> Ordering {} }
+    impl Hash for u8 { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for u8 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for u8 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for u8 { type Err = crate::num::ParseIntError; fn from_str(s: &str) -> crate::result::Result<u8, Self::Err> {} }
+    impl Add for u8 { type Output = u8; fn add(self, rhs: u8) -> u8 {} }
+    impl Sub for u8 { type Output = u8; fn sub(self, rhs: u8) -> u8 {} }
+    impl Mul for u8 { type Output = u8; fn mul(self, rhs: u8) -> u8 {} }
+    impl Div for u8 { type Output = u8; fn div(self, rhs: u8) -> u8 {} }
+    impl Rem for u8 { type Output = u8; fn rem(self, rhs: u8) -> u8 {} }
+    impl AddAssign for u8 { fn add_assign(&mut self, rhs: u8) {} }
+    impl SubAssign for u8 { fn sub_assign(&mut self, rhs: u8) {} }
+    impl MulAssign for u8 { fn mul_assign(&mut self, rhs: u8) {} }
+    impl DivAssign for u8 { fn div_assign(&mut self, rhs: u8) {} }
+    impl crate::iter::Sum for u8 { fn sum<I: crate::iter::Iterator<Item = u8>>(iter: I) -> u8 {} }
+
+    impl Clone for u16 { fn clone(&self) -> u16 {} }
+    impl Copy for u16 {}
+    impl Default for u16 { fn default() -> u16 {} }
+    impl PartialEq for u16 { fn eq(&self, other: &u16) -> bool {} }
+    impl PartialOrd for u16 { fn partial_cmp(&self, other: &u16) -> Option<Ordering> {} }
+    impl Eq for u16 {}
+    impl Ord for u16 { fn cmp(&self, other: &u16) -> Ordering {} }
+    impl Hash for u16 { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for u16 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for u16 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for u16 { type Err = crate::num::ParseIntError; fn from_str(s: &str) -> crate::result::Result<u16, Self::Err> {} }
+    impl Add for u16 { type Output = u16; fn add(self, rhs: u16) -> u16 {} }
+    impl Sub for u16 { type Output = u16; fn sub(self, rhs: u16) -> u16 {} }
+    impl Mul for u16 { type Output = u16; fn mul(self, rhs: u16) -> u16 {} }
+    impl Div for u16 { type Output = u16; fn div(self, rhs: u16) -> u16 {} }
+    impl Rem for u16 { type Output = u16; fn rem(self, rhs: u16) -> u16 {} }
+    impl AddAssign for u16 { fn add_assign(&mut self, rhs: u16) {} }
+    impl SubAssign for u16 { fn sub_assign(&mut self, rhs: u16) {} }
+    impl MulAssign for u16 { fn mul_assign(&mut self, rhs: u16) {} }
+    impl DivAssign for u16 { fn div_assign(&mut self, rhs: u16) {} }
+    impl crate::iter::Sum for u16 { fn sum<I: crate::iter::Iterator<Item = u16>>(iter: I) -> u16 {} }
+
+    impl Clone for u32 { fn clone(&self) -> u32 {} }
+    impl Copy for u32 {}
+    impl Default for u32 { fn default() -> u32 {} }
+    impl PartialEq for u32 { fn eq(&self, other: &u32) -> bool {} }
+    impl PartialOrd for u32 { fn partial_cmp(&self, other: &u32) -> Option<Ordering> {} }
+    impl Eq for u32 {}
+    impl Ord for u32 { fn cmp(&self, other: &u32) -> Ordering {} }
+    impl Hash for u32 { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for u32 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for u32 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for u32 { type Err = crate::num::ParseIntError; fn from_str(s: &str) -> crate::result::Result<u32, Self::Err> {} }
+    impl Add for u32 { type Output = u32; fn add(self, rhs: u32) -> u32 {} }
+    impl Sub for u32 { type Output = u32; fn sub(self, rhs: u32) -> u32 {} }
+    impl Mul for u32 { type Output = u32; fn mul(self, rhs: u32) -> u32 {} }
+    impl Div for u32 { type Output = u32; fn div(self, rhs: u32) -> u32 {} }
+    impl Rem for u32 { type Output = u32; fn rem(self, rhs: u32) -> u32 {} }
+    impl AddAssign for u32 { fn add_assign(&mut self, rhs: u32) {} }
+    impl SubAssign for u32 { fn sub_assign(&mut self, rhs: u32) {} }
+    impl MulAssign for u32 { fn mul_assign(&mut self, rhs: u32) {} }
+    impl DivAssign for u32 { fn div_assign(&mut self, rhs: u32) {} }
+    impl crate::iter::Sum for u32 { fn sum<I: crate::iter::Iterator<Item = u32>>(iter: I) -> u32 {} }
+
+    impl Clone for u64 { fn clone(&self) -> u64 {} }
+    impl Copy for u64 {}
+    impl Default for u64 { fn default() -> u64 {} }
+    impl PartialEq for u64 { fn eq(&self, other: &u64) -> bool {} }
+    impl PartialOrd for u64 { fn partial_cmp(&self, other: &u64) -> Option<Ordering> {} }
+    impl Eq for u64 {}
+    impl Ord for u64 { fn cmp(&self, other: &u64) -> Ordering {} }
+    impl Hash for u64 { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for u64 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for u64 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for u64 { type Err = crate::num::ParseIntError; fn from_str(s: &str) -> crate::result::Result<u64, Self::Err> {} }
+    impl Add for u64 { type Output = u64; fn add(self, rhs: u64) -> u64 {} }
+    impl Sub for u64 { type Output = u64; fn sub(self, rhs: u64) -> u64 {} }
+    impl Mul for u64 { type Output = u64; fn mul(self, rhs: u64) -> u64 {} }
+    impl Div for u64 { type Output = u64; fn div(self, rhs: u64) -> u64 {} }
+    impl Rem for u64 { type Output = u64; fn rem(self, rhs: u64) -> u64 {} }
+    impl AddAssign for u64 { fn add_assign(&mut self, rhs: u64) {} }
+    impl SubAssign for u64 { fn sub_assign(&mut self, rhs: u64) {} }
+    impl MulAssign for u64 { fn mul_assign(&mut self, rhs: u64) {} }
+    impl DivAssign for u64 { fn div_assign(&mut self, rhs: u64) {} }
+    impl crate::iter::Sum for u64 { fn sum<I: crate::iter::Iterator<Item = u64>>(iter: I) -> u64 {} }
+
+    impl Clone for u128 { fn clone(&self) -> u128 {} }
+    impl Copy for u128 {}
+    impl Default for u128 { fn default() -> u128 {} }
+    impl PartialEq for u128 { fn eq(&self, other: &u128) -> bool {} }
+    impl PartialOrd for u128 { fn partial_cmp(&self, other: &u128) -> Option<Ordering> {} }
+    impl Eq for u128 {}
+    impl Ord for u128 { fn cmp(&self, other: &u128) -> Ordering {} }
+    impl Hash for u128 { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for u128 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for u128 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for u128 { type Err = crate::num::ParseIntError; fn from_str(s: &str) -> crate::result::Result<u128, Self::Err> {} }
+    impl Add for u128 { type Output = u128; fn add(self, rhs: u128) -> u128 {} }
+    impl Sub for u128 { type Output = u128; fn sub(self, rhs: u128) -> u128 {} }
+    impl Mul for u128 { type Output = u128; fn mul(self, rhs: u128) -> u128 {} }
+    impl Div for u128 { type Output = u128; fn div(self, rhs: u128) -> u128 {} }
+    impl Rem for u128 { type Output = u128; fn rem(self, rhs: u128) -> u128 {} }
+    impl AddAssign for u128 { fn add_assign(&mut self, rhs: u128) {} }
+    impl SubAssign for u128 { fn sub_assign(&mut self, rhs: u128) {} }
+    impl MulAssign for u128 { fn mul_assign(&mut self, rhs: u128) {} }
+    impl DivAssign for u128 { fn div_assign(&mut self, rhs: u128) {} }
+    impl crate::iter::Sum for u128 { fn sum<I: crate::iter::Iterator<Item = u128>>(iter: I) -> u128 {} }
+
+    impl Clone for usize { fn clone(&self) -> usize {} }
+    impl Copy for usize {}
+    impl Default for usize { fn default() -> usize {} }
+    impl PartialEq for usize { fn eq(&self, other: &usize) -> bool {} }
+    impl PartialOrd for usize { fn partial_cmp(&self, other: &usize) -> Option<Ordering> {} }
+    impl Eq for usize {}
+    impl Ord for usize { fn cmp(&self, other: &usize) -> Ordering {} }
+    impl Hash for usize { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for usize { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for usize { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for usize { type Err = crate::num::ParseIntError; fn from_str(s: &str) -> crate::result::Result<usize, Self::Err> {} }
+    impl Add for usize { type Output = usize; fn add(self, rhs: usize) -> usize {} }
+    impl Sub for usize { type Output = usize; fn sub(self, rhs: usize) -> usize {} }
+    impl Mul for usize { type Output = usize; fn mul(self, rhs: usize) -> usize {} }
+    impl Div for usize { type Output = usize; fn div(self, rhs: usize) -> usize {} }
+    impl Rem for usize { type Output = usize; fn rem(self, rhs: usize) -> usize {} }
+    impl AddAssign for usize { fn add_assign(&mut self, rhs: usize) {} }
+    impl SubAssign for usize { fn sub_assign(&mut self, rhs: usize) {} }
+    impl MulAssign for usize { fn mul_assign(&mut self, rhs: usize) {} }
+    impl DivAssign for usize { fn div_assign(&mut self, rhs: usize) {} }
+    impl crate::iter::Sum for usize { fn sum<I: crate::iter::Iterator<Item = usize>>(iter: I) -> usize {} }
+
+    impl Clone for f32 { fn clone(&self) -> f32 {} }
+    impl Copy for f32 {}
+    impl Default for f32 { fn default() -> f32 {} }
+    impl PartialEq for f32 { fn eq(&self, other: &f32) -> bool {} }
+    impl PartialOrd for f32 { fn partial_cmp(&self, other: &f32) -> Option<Ordering> {} }
+    impl Debug for f32 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for f32 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for f32 { type Err = crate::num::ParseFloatError; fn from_str(s: &str) -> crate::result::Result<f32, Self::Err> {} }
+    impl Add for f32 { type Output = f32; fn add(self, rhs: f32) -> f32 {} }
+    impl Sub for f32 { type Output = f32; fn sub(self, rhs: f32) -> f32 {} }
+    impl Mul for f32 { type Output = f32; fn mul(self, rhs: f32) -> f32 {} }
+    impl Div for f32 { type Output = f32; fn div(self, rhs: f32) -> f32 {} }
+    impl Rem for f32 { type Output = f32; fn rem(self, rhs: f32) -> f32 {} }
+    impl AddAssign for f32 { fn add_assign(&mut self, rhs: f32) {} }
+    impl SubAssign for f32 { fn sub_assign(&mut self, rhs: f32) {} }
+    impl MulAssign for f32 { fn mul_assign(&mut self, rhs: f32) {} }
+    impl DivAssign for f32 { fn div_assign(&mut self, rhs: f32) {} }
+    impl Neg for f32 { type Output = f32; fn neg(self) -> f32 {} }
+    impl crate::iter::Sum for f32 { fn sum<I: crate::iter::Iterator<Item = f32>>(iter: I) -> f32 {} }
+
+    impl Clone for f64 { fn clone(&self) -> f64 {} }
+    impl Copy for f64 {}
+    impl Default for f64 { fn default() -> f64 {} }
+    impl PartialEq for f64 { fn eq(&self, other: &f64) -> bool {} }
+    impl PartialOrd for f64 { fn partial_cmp(&self, other: &f64) -> Option<Ordering> {} }
+    impl Debug for f64 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for f64 { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl FromStr for f64 { type Err = crate::num::ParseFloatError; fn from_str(s: &str) -> crate::result::Result<f64, Self::Err> {} }
+    impl Add for f64 { type Output = f64; fn add(self, rhs: f64) -> f64 {} }
+    impl Sub for f64 { type Output = f64; fn sub(self, rhs: f64) -> f64 {} }
+    impl Mul for f64 { type Output = f64; fn mul(self, rhs: f64) -> f64 {} }
+    impl Div for f64 { type Output = f64; fn div(self, rhs: f64) -> f64 {} }
+    impl Rem for f64 { type Output = f64; fn rem(self, rhs: f64) -> f64 {} }
+    impl AddAssign for f64 { fn add_assign(&mut self, rhs: f64) {} }
+    impl SubAssign for f64 { fn sub_assign(&mut self, rhs: f64) {} }
+    impl MulAssign for f64 { fn mul_assign(&mut self, rhs: f64) {} }
+    impl DivAssign for f64 { fn div_assign(&mut self, rhs: f64) {} }
+    impl Neg for f64 { type Output = f64; fn neg(self) -> f64 {} }
+    impl crate::iter::Sum for f64 { fn sum<I: crate::iter::Iterator<Item = f64>>(iter: I) -> f64 {} }
+
+    impl PartialEq for str { fn eq(&self, other: &str) -> bool {} }
+    impl Eq for str {}
+    impl PartialOrd for str { fn partial_cmp(&self, other: &str) -> Option<Ordering> {} }
+    impl Ord for str { fn cmp(&self, other: &str) -> Ordering {} }
+    impl Hash for str { fn hash<H: Hasher>(&self, state: &mut H) {} }
+    impl Debug for str { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+    impl Display for str { fn fmt(&self, f: &mut Formatter<'_>) -> Result {} }
+
+    impl<T: ?Sized> Clone for *const T { fn clone(&self) -> *const T {} }
+    impl<T: ?Sized> Copy for *const T {}
+    impl<T: ?Sized> Clone for *mut T { fn clone(&self) -> *mut T {} }
+    impl<T: ?Sized> Copy for *mut T {}
+    impl<T: ?Sized> Copy for &T {}
+}
+
+pub mod prelude {
+    pub mod v1 {
+        pub use crate::clone::Clone;
+        pub use crate::cmp::{Eq, Ord, PartialEq, PartialOrd};
+        pub use crate::convert::{AsMut, AsRef, From, Into};
+        pub use crate::default::Default;
+        pub use crate::iter::{DoubleEndedIterator, ExactSizeIterator, Extend, IntoIterator, Iterator};
+        pub use crate::marker::{Copy, Send, Sized, Sync, Unpin};
+        pub use crate::mem::drop;
+        pub use crate::ops::{Drop, Fn, FnMut, FnOnce};
+        pub use crate::option::Option;
+        pub use crate::option::Option::{None, Some};
+        pub use crate::result::Result;
+        pub use crate::result::Result::{Err, Ok};
+    }
+
+    pub mod rust_2015 {
+        pub use crate::prelude::v1::*;
+    }
+
+    pub mod rust_2018 {
+        pub use crate::prelude::v1::*;
+    }
+
+    pub mod rust_2021 {
+        pub use crate::prelude::v1::*;
+        pub use crate::convert::{TryFrom, TryInto};
+        pub use crate::iter::FromIterator;
+    }
+
+    pub mod rust_2024 {
+        pub use crate::prelude::rust_2021::*;
+        pub use crate::future::{Future, IntoFuture};
+    }
+}
@@ -0,0 +1,796 @@
+//! Signature-only model of `std`, bundled with syg so that resolution works without the real sources.
+//! Re-exports `core` and `alloc` the same way the real crate does. Replaced by the real crate when `std` is added explicitly.
+
+pub mod any {
+    pub use core::any::*;
+}
+pub mod array {
+    pub use core::array::*;
+}
+pub mod cell {
+    pub use core::cell::*;
+}
+pub mod char {
+    pub use core::char::*;
+}
+pub mod clone {
+    pub use core::clone::*;
+}
+pub mod cmp {
+    pub use core::cmp::*;
+}
+pub mod convert {
+    pub use core::convert::*;
+}
+pub mod default {
+    pub use core::default::*;
+}
+pub mod hash {
+    pub use core::hash::*;
+
+    pub struct DefaultHasher {
+        state: u64,
+    }
+
+    pub struct RandomState {
+        k0: u64,
+        k1: u64,
+    }
+
+    impl DefaultHasher {
+        pub fn new() -> DefaultHasher {}
+    }
+
+    impl RandomState {
+        pub fn new() -> RandomState {}
+    }
+
+    impl Hasher for DefaultHasher {
+        fn finish(&self) -> u64 {}
+        fn write(&mut self, msg: &[u8]) {}
+    }
+
+    impl BuildHasher for RandomState {
+        type Hasher = DefaultHasher;
+        fn build_hasher(&self) -> DefaultHasher {}
+    }
+
+    impl core::default::Default for RandomState {
+        fn default() -> RandomState {}
+    }
+    impl core::clone::Clone for RandomState {
+        fn clone(&self) -> RandomState {}
+    }
+}
+pub mod iter {
+    pub use core::iter::*;
+}
+pub mod marker {
+    pub use core::marker::*;
+}
+pub mod mem {
+    pub use core::mem::*;
+}
+pub mod num {
+    pub use core::num::*;
+}
+pub mod ops {
+    pub use core::ops::*;
+}
+pub mod option {
+    pub use core::option::*;
+}
+pub mod pin {
+    pub use core::pin::*;
+}
+pub mod future {
+    pub use core::future::*;
+}
+pub mod task {
+    pub use core::task::*;
+}
+pub mod ptr {
+    pub use core::ptr::*;
+}
+pub mod result {
+    pub use core::result::*;
+}
+pub mod slice {
+    pub use core::slice::*;
+}
+pub mod str {
+    pub use core::str::*;
+}
+pub mod borrow {
+    pub use alloc::borrow::*;
+}
+pub mod boxed {
+    pub use alloc::boxed::*;
+}
+pub mod fmt {
+    pub use alloc::fmt::*;
+}
+pub mod rc {
+    pub use alloc::rc::*;
+}
+pub mod string {
+    pub use alloc::string::*;
+}
+pub mod vec {
+    pub use alloc::vec::*;
+}
+
+pub mod error {
+    pub use core::error::*;
+}
+
+pub mod collections {
+    pub use alloc::collections::*;
+
+    pub mod hash_map {
+        use core::option::Option;
+        use crate::hash::RandomState;
+
+        pub struct HashMap<K, V, S = RandomState> {
+            base: crate::vec::Vec<(K, V)>,
+            hash_builder: S,
+        }
+
+        pub struct Iter<'a, K: 'a, V: 'a> {
+            inner: core::marker::PhantomData<&'a (K, V)>,
+        }
+
+        pub struct Keys<'a, K: 'a, V: 'a> {
+            inner: Iter<'a, K, V>,
+        }
+
+        pub struct Values<'a, K: 'a, V: 'a> {
+            inner: Iter<'a, K, V>,
+        }
+
+        pub enum Entry<'a, K: 'a, V: 'a> {
+            Occupied(OccupiedEntry<'a, K, V>),
+            Vacant(VacantEntry<'a, K, V>),
+        }
+
+        pub struct OccupiedEntry<'a, K, V> {
+            _marker: core::marker::PhantomData<&'a mut (K, V)>,
+        }
+
+        pub struct VacantEntry<'a, K, V> {
+            key: K,
+            _marker: core::marker::PhantomData<&'a mut (K, V)>,
+        }
+
+        impl<K, V> HashMap<K, V, RandomState> {
+            pub fn new() -> HashMap<K, V, RandomState> {}
+            pub fn with_capacity(capacity: usize) -> HashMap<K, V, RandomState> {}
+        }
+
+        impl<K, V, S> HashMap<K, V, S> {
+            pub fn with_hasher(hash_builder: S) -> HashMap<K, V, S> {}
+            pub fn len(&self) -> usize {}
+            pub fn is_empty(&self) -> bool {}
+            pub fn clear(&mut self) {}
+            pub fn iter(&self) -> Iter<'_, K, V> {}
+            pub fn keys(&self) -> Keys<'_, K, V> {}
+            pub fn values(&self) -> Values<'_, K, V> {}
+        }
+
+        impl<K: core::cmp::Eq + core::hash::Hash, V, S: core::hash::BuildHasher> HashMap<K, V, S> {
+            pub fn get<Q: ?Sized + core::hash::Hash + core::cmp::Eq>(&self, k: &Q) -> Option<&V> where K: core::borrow::Borrow<Q> {}
+            pub fn get_mut<Q: ?Sized + core::hash::Hash + core::cmp::Eq>(&mut self, k: &Q) -> Option<&mut V> where K: core::borrow::Borrow<Q> {}
+            pub fn contains_key<Q: ?Sized + core::hash::Hash + core::cmp::Eq>(&self, k: &Q) -> bool where K: core::borrow::Borrow<Q> {}
+            pub fn insert(&mut self, k: K, v: V) -> Option<V> {}
+            pub fn remove<Q: ?Sized + core::hash::Hash + core::cmp::Eq>(&mut self, k: &Q) -> Option<V> where K: core::borrow::Borrow<Q> {}
+            pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {}
+        }
+
+        impl<'a, K, V> Entry<'a, K, V> {
+            pub fn or_insert(self, default: V) -> &'a mut V {}
+            pub fn or_insert_with<F: core::ops::FnOnce() -> V>(self, default: F) -> &'a mut V {}
+            pub fn or_default(self) -> &'a mut V where V: core::default::Default {}
+        }
+
+        impl<'a, K, V> core::iter::Iterator for Iter<'a, K, V> {
+            type Item = (&'a K, &'a V);
+            fn next(&mut self) -> Option<(&'a K, &'a V)> {}
+        }
+        impl<'a, K, V> core::iter::Iterator for Keys<'a, K, V> {
+            type Item = &'a K;
+            fn next(&mut self) -> Option<&'a K> {}
+        }
+        impl<'a, K, V> core::iter::Iterator for Values<'a, K, V> {
+            type Item = &'a V;
+            fn next(&mut self) -> Option<&'a V> {}
+        }
+        impl<'a, K, V, S> core::iter::IntoIterator for &'a HashMap<K, V, S> {
+            type Item = (&'a K, &'a V);
+            type IntoIter = Iter<'a, K, V>;
+            fn into_iter(self) -> Iter<'a, K, V> {}
+        }
+        impl<K: core::clone::Clone, V: core::clone::Clone, S: core::clone::Clone> core::clone::Clone for HashMap<K, V, S> {
+            fn clone(&self) -> Self {}
+        }
+        impl<K, V, S: core::default::Default> core::default::Default for HashMap<K, V, S> {
+            fn default() -> HashMap<K, V, S> {}
+        }
+        impl<K: core::fmt::Debug, V: core::fmt::Debug, S> core::fmt::Debug for HashMap<K, V, S> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {}
+        }
+        impl<K: core::cmp::Eq + core::hash::Hash, V: core::cmp::PartialEq, S: core::hash::BuildHasher> core::cmp::PartialEq for HashMap<K, V, S> {
+            fn eq(&self, other: &HashMap<K, V, S>) -> bool {}
+        }
+        impl<K: core::cmp::Eq + core::hash::Hash, V, S: core::hash::BuildHasher + core::default::Default> core::iter::FromIterator<(K, V)> for HashMap<K, V, S> {
+            fn from_iter<T: core::iter::IntoIterator<Item = (K, V)>>(iter: T) -> HashMap<K, V, S> {}
+        }
+        impl<K: core::cmp::Eq + core::hash::Hash, V, S: core::hash::BuildHasher> core::iter::Extend<(K, V)> for HashMap<K, V, S> {
+            fn extend<T: core::iter::IntoIterator<Item = (K, V)>>(&mut self, iter: T) {}
+        }
+        impl<K: core::cmp::Eq + core::hash::Hash, V, S: core::hash::BuildHasher> core::ops::Index<&K> for HashMap<K, V, S> {
+            type Output = V;
+            fn index(&self, key: &K) -> &V {}
+        }
+    }
+
+    pub mod hash_set {
+        use crate::hash::RandomState;
+
+        pub struct HashSet<T, S = RandomState> {
+            map: crate::collections::hash_map::HashMap<T, (), S>,
+        }
+
+        impl<T> HashSet<T, RandomState> {
+            pub fn new() -> HashSet<T, RandomState> {}
+            pub fn with_capacity(capacity: usize) -> HashSet<T, RandomState> {}
+        }
+
+        impl<T, S> HashSet<T, S> {
+            pub fn len(&self) -> usize {}
+            pub fn is_empty(&self) -> bool {}
+            pub fn clear(&mut self) {}
+        }
+
+        impl<T: core::cmp::Eq + core::hash::Hash, S: core::hash::BuildHasher> HashSet<T, S> {
+            pub fn insert(&mut self, value: T) -> bool {}
+            pub fn contains<Q: ?Sized + core::hash::Hash + core::cmp::Eq>(&self, value: &Q) -> bool where T: core::borrow::Borrow<Q> {}
+            pub fn remove<Q: ?Sized + core::hash::Hash + core::cmp::Eq>(&mut self, value: &Q) -> bool where T: core::borrow::Borrow<Q> {}
+        }
+
+        impl<T: core::clone::Clone, S: core::clone::Clone> core::clone::Clone for HashSet<T, S> {
+            fn clone(&self) -> Self {}
+        }
+        impl<T, S: core::default::Default> core::default::Default for HashSet<T, S> {
+            fn default() -> HashSet<T, S> {}
+        }
+        impl<T: core::fmt::Debug, S> core::fmt::Debug for HashSet<T, S> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {}
+        }
+        impl<T: core::cmp::Eq + core::hash::Hash, S: core::hash::BuildHasher + core::default::Default> core::iter::FromIterator<T> for HashSet<T, S> {
+            fn from_iter<I: core::iter::IntoIterator<Item = T>>(iter: I) -> HashSet<T, S> {}
+        }
+    }
+
+    pub use self::hash_map::HashMap;
+    pub use self::hash_set::HashSet;
+}
+
+pub mod sync {
+    pub use alloc::sync::*;
+    pub use core::sync::atomic;
+
+    pub struct Mutex<T: ?Sized> {
+        poison: bool,
+        data: core::cell::UnsafeCell<T>,
+    }
+
+    pub struct MutexGuard<'a, T: ?Sized + 'a> {
+        lock: &'a Mutex<T>,
+    }
+
+    pub struct RwLock<T: ?Sized> {
+        poison: bool,
+        data: core::cell::UnsafeCell<T>,
+    }
+
+    pub struct RwLockReadGuard<'a, T: ?Sized + 'a> {
+        data: core::ptr::NonNull<T>,
+        inner_lock: &'a RwLock<T>,
+    }
+
+    pub struct RwLockWriteGuard<'a, T: ?Sized + 'a> {
+        lock: &'a RwLock<T>,
+    }
+
+    pub struct PoisonError<T> {
+        data: T,
+    }
+
+    pub type LockResult<Guard> = core::result::Result<Guard, PoisonError<Guard>>;
+
+    pub struct Once {
+        state: u32,
+    }
+
+    pub struct OnceLock<T> {
+        once: Once,
+        value: core::cell::UnsafeCell<core::mem::MaybeUninit<T>>,
+    }
+
+    unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+    unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+    impl<T: ?Sized> !Send for MutexGuard<'_, T> {}
+    unsafe impl<T: ?Sized + Sync> Sync for MutexGuard<'_, T> {}
+    unsafe impl<T: ?Sized + Send> Send for RwLock<T> {}
+    unsafe impl<T: ?Sized + Send + Sync> Sync for RwLock<T> {}
+    impl<T: ?Sized> !Send for RwLockReadGuard<'_, T> {}
+    unsafe impl<T: ?Sized + Sync> Sync for RwLockReadGuard<'_, T> {}
+    impl<T: ?Sized> !Send for RwLockWriteGuard<'_, T> {}
+    unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
+    unsafe impl<T: Sync + Send> Sync for OnceLock<T> {}
+    unsafe impl<T: Send> Send for OnceLock<T> {}
+
+    impl<T> Mutex<T> {
+        pub const fn new(t: T) -> Mutex<T> {}
+        pub fn into_inner(self) -> LockResult<T> {}
+    }
+
+    impl<T: ?Sized> Mutex<T> {
+        pub fn lock(&self) -> LockResult<MutexGuard<'_, T>> {}
+        pub fn get_mut(&mut self) -> LockResult<&mut T> {}
+    }
+
+    impl<T> RwLock<T> {
+        pub const fn new(t: T) -> RwLock<T> {}
+    }
+
+    impl<T: ?Sized> RwLock<T> {
+        pub fn read(&self) -> LockResult<RwLockReadGuard<'_, T>> {}
+        pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {}
+    }
+
+    impl<T> OnceLock<T> {
+        pub const fn new() -> OnceLock<T> {}
+        pub fn get(&self) -> core::option::Option<&T> {}
+        pub fn get_or_init<F: core::ops::FnOnce() -> T>(&self, f: F) -> &T {}
+    }
+
+    impl<T: ?Sized> core::ops::Deref for MutexGuard<'_, T> {
+        type Target = T;
+        fn deref(&self) -> &T {}
+    }
+    impl<T: ?Sized> core::ops::DerefMut for MutexGuard<'_, T> {
+        fn deref_mut(&mut self) -> &mut T {}
+    }
+    impl<T: ?Sized> core::ops::Deref for RwLockReadGuard<'_, T> {
+        type Target = T;
+        fn deref(&self) -> &T {}
+    }
+    impl<T: ?Sized> core::ops::Deref for RwLockWriteGuard<'_, T> {
+        type Target = T;
+        fn deref(&self) -> &T {}
+    }
+    impl<T: ?Sized> core::ops::DerefMut for RwLockWriteGuard<'_, T> {
+        fn deref_mut(&mut self) -> &mut T {}
+    }
+    impl<T: ?Sized + core::default::Default> core::default::Default for Mutex<T> {
+        fn default() -> Mutex<T> {}
+    }
+    impl<T: ?Sized + core::fmt::Debug> core::fmt::Debug for Mutex<T> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {}
+    }
+    impl<T: ?Sized + core::default::Default> core::default::Default for RwLock<T> {
+        fn default() -> RwLock<T> {}
+    }
+
+    pub mod mpsc {
+        pub struct Sender<T> {
+            inner: crate::sync::Arc<T>,
+        }
+
+        pub struct SyncSender<T> {
+            inner: crate::sync::Arc<T>,
+        }
+
+        pub struct Receiver<T> {
+            inner: crate::sync::Arc<T>,
+        }
+
+        pub struct SendError<T>(pub T);
+
+        pub struct RecvError;
+
+        pub enum TryRecvError {
+            Empty,
+            Disconnected,
+        }
+
+        unsafe impl<T: Send> Send for Sender<T> {}
+        unsafe impl<T: Send> Sync for Sender<T> {}
+        unsafe impl<T: Send> Send for Receiver<T> {}
+        impl<T> !Sync for Receiver<T> {}
+
+        pub fn channel<T>() -> (Sender<T>, Receiver<T>) {}
+        pub fn sync_channel<T>(bound: usize) -> (SyncSender<T>, Receiver<T>) {}
+
+        impl<T> Sender<T> {
+            pub fn send(&self, t: T) -> core::result::Result<(), SendError<T>> {}
+        }
+
+        impl<T> SyncSender<T> {
+            pub fn send(&self, t: T) -> core::result::Result<(), SendError<T>> {}
+        }
+
+        impl<T> Receiver<T> {
+            pub fn recv(&self) -> core::result::Result<T, RecvError> {}
+            pub fn try_recv(&self) -> core::result::Result<T, TryRecvError> {}
+        }
+
+        impl<T> core::clone::Clone for Sender<T> {
+            fn clone(&self) -> Sender<T> {}
+        }
+        impl<T> core::clone::Clone for SyncSender<T> {
+            fn clone(&self) -> SyncSender<T> {}
+        }
+    }
+}
+
+pub mod io {
+    use crate::string::String;
+    use crate::vec::Vec;
+
+    pub struct Error {
+        repr: ErrorKind,
+    }
+
+    pub enum ErrorKind {
+        NotFound,
+        PermissionDenied,
+        AlreadyExists,
+        InvalidInput,
+        InvalidData,
+        UnexpectedEof,
+        Other,
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    pub trait Read {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize>;
+        fn read_to_end(&mut self, buf: &mut Vec<u8>) -> Result<usize> {}
+        fn read_to_string(&mut self, buf: &mut String) -> Result<usize> {}
+        fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {}
+    }
+
+    pub trait Write {
+        fn write(&mut self, buf: &[u8]) -> Result<usize>;
+        fn flush(&mut self) -> Result<()>;
+        fn write_all(&mut self, buf: &[u8]) -> Result<()> {}
+        fn write_fmt(&mut self, fmt: core::fmt::Arguments<'_>) -> Result<()> {}
+    }
+
+    pub trait BufRead: Read {
+        fn fill_buf(&mut self) -> Result<&[u8]>;
+        fn consume(&mut self, amt: usize);
+        fn read_line(&mut self, buf: &mut String) -> Result<usize> {}
+    }
+
+    pub trait Seek {
+        fn seek(&mut self, pos: SeekFrom) -> Result<u64>;
+    }
+
+    pub enum SeekFrom {
+        Start(u64),
+        End(i64),
+        Current(i64),
+    }
+
+    pub struct BufReader<R: ?Sized> {
+        buf: crate::boxed::Box<[u8]>,
+        inner: R,
+    }
+
+    pub struct BufWriter<W: ?Sized + Write> {
+        buf: Vec<u8>,
+        inner: W,
+    }
+
+    pub struct Stdin {
+        inner: &'static u8,
+    }
+
+    pub struct Stdout {
+        inner: &'static u8,
+    }
+
+    pub struct Stderr {
+        inner: &'static u8,
+    }
+
+    pub fn stdin() -> Stdin {}
+    pub fn stdout() -> Stdout {}
+    pub fn stderr() -> Stderr {}
+
+    impl Error {
+        pub fn new<E>(kind: ErrorKind, error: E) -> Error where E: core::convert::Into<crate::boxed::Box<dyn crate::error::Error + Send + Sync>> {}
+        pub fn kind(&self) -> ErrorKind {}
+    }
+
+    impl<R: Read> BufReader<R> {
+        pub fn new(inner: R) -> BufReader<R> {}
+    }
+
+    impl<W: Write> BufWriter<W> {
+        pub fn new(inner: W) -> BufWriter<W> {}
+    }
+
+    impl<R: ?Sized + Read> Read for BufReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {}
+    }
+    impl<R: ?Sized + Read> BufRead for BufReader<R> {
+        fn fill_buf(&mut self) -> Result<&[u8]> {}
+        fn consume(&mut self, amt: usize) {}
+    }
+    impl<W: ?Sized + Write> Write for BufWriter<W> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {}
+        fn flush(&mut self) -> Result<()> {}
+    }
+    impl Read for Stdin {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {}
+    }
+    impl Write for Stdout {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {}
+        fn flush(&mut self) -> Result<()> {}
+    }
+    impl Write for Stderr {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {}
+        fn flush(&mut self) -> Result<()> {}
+    }
+    impl Write for Vec<u8> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {}
+        fn flush(&mut self) -> Result<()> {}
+    }
+    impl core::fmt::Debug for Error {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {}
+    }
+    impl core::fmt::Display for Error {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {}
+    }
+    impl crate::error::Error for Error {}
+    impl core::convert::From<ErrorKind> for Error {
+        fn from(kind: ErrorKind) -> Error {}
+    }
+}
+
+pub mod ffi {
+    pub use core::ffi::*;
+
+    pub struct OsString {
+        inner: crate::vec::Vec<u8>,
+    }
+
+    pub struct OsStr {
+        inner: [u8],
+    }
+
+    pub struct CString {
+        inner: crate::boxed::Box<[u8]>,
+    }
+}
+
+pub mod path {
+    use core::option::Option;
+    use crate::ffi::{OsStr, OsString};
+
+    pub struct Path {
+        inner: OsStr,
+    }
+
+    pub struct PathBuf {
+        inner: OsString,
+    }
+
+    impl Path {
+        pub fn new<S: core::convert::AsRef<OsStr> + ?Sized>(s: &S) -> &Path {}
+        pub fn to_path_buf(&self) -> PathBuf {}
+        pub fn to_str(&self) -> Option<&str> {}
+        pub fn join<P: core::convert::AsRef<Path>>(&self, path: P) -> PathBuf {}
+        pub fn parent(&self) -> Option<&Path> {}
+        pub fn file_name(&self) -> Option<&OsStr> {}
+        pub fn extension(&self) -> Option<&OsStr> {}
+        pub fn exists(&self) -> bool {}
+        pub fn is_file(&self) -> bool {}
+        pub fn is_dir(&self) -> bool {}
+    }
+
+    impl PathBuf {
+        pub fn new() -> PathBuf {}
+        pub fn as_path(&self) -> &Path {}
+        pub fn push<P: core::convert::AsRef<Path>>(&mut self, path: P) {}
+        pub fn pop(&mut self) -> bool {}
+    }
+
+    impl core::ops::Deref for PathBuf {
+        type Target = Path;
+        fn deref(&self) -> &Path {}
+    }
+    impl core::convert::AsRef<Path> for Path {
+        fn as_ref(&self) -> &Path {}
+    }
+    impl core::convert::AsRef<Path> for PathBuf {
+        fn as_ref(&self) -> &Path {}
+    }
+    impl core::convert::AsRef<Path> for str {
+        fn as_ref(&self) -> &Path {}
+    }
+    impl core::convert::AsRef<Path> for crate::string::String {
+        fn as_ref(&self) -> &Path {}
+    }
+    impl core::clone::Clone for PathBuf {
+        fn clone(&self) -> PathBuf {}
+    }
+    impl core::fmt::Debug for Path {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {}
+    }
+    impl core::fmt::Debug for PathBuf {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {}
+    }
+    impl<T: ?Sized + core::convert::AsRef<OsStr>> core::convert::From<&T> for PathBuf {
+        fn from(s: &T) -> PathBuf {}
+    }
+}
+
+pub mod fs {
+    use crate::io::Result;
+    use crate::path::Path;
+
+    pub struct File {
+        inner: i32,
+    }
+
+    pub struct Metadata {
+        len: u64,
+    }
+
+    impl File {
+        pub fn open<P: core::convert::AsRef<Path>>(path: P) -> Result<File> {}
+        pub fn create<P: core::convert::AsRef<Path>>(path: P) -> Result<File> {}
+        pub fn metadata(&self) -> Result<Metadata> {}
+    }
+
+    impl Metadata {
+        pub fn len(&self) -> u64 {}
+        pub fn is_dir(&self) -> bool {}
+        pub fn is_file(&self) -> bool {}
+    }
+
+    impl crate::io::Read for File {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {}
+    }
+    impl crate::io::Write for File {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {}
+        fn flush(&mut self) -> Result<()> {}
+    }
+
+    pub fn read<P: core::convert::AsRef<Path>>(path: P) -> Result<crate::vec::Vec<u8>> {}
+    pub fn read_to_string<P: core::convert::AsRef<Path>>(path: P) -> Result<crate::string::String> {}
+    pub fn write<P: core::convert::AsRef<Path>, C: core::convert::AsRef<[u8]>>(path: P, contents: C) -> Result<()> {}
+    pub fn create_dir_all<P: core::convert::AsRef<Path>>(path: P) -> Result<()> {}
+    pub fn remove_file<P: core::convert::AsRef<Path>>(path: P) -> Result<()> {}
+}
+
+pub mod time {
+    pub use core::time::*;
+
+    pub struct Instant {
+        t: u64,
+    }
+
+    pub struct SystemTime {
+        t: u64,
+    }
+
+    pub const UNIX_EPOCH: SystemTime = SystemTime { t: 0 };
+
+    impl Instant {
+        pub fn now() -> Instant {}
+        pub fn elapsed(&self) -> Duration {}
+        pub fn duration_since(&self, earlier: Instant) -> Duration {}
+    }
+
+    impl SystemTime {
+        pub fn now() -> SystemTime {}
+    }
+
+    impl core::clone::Clone for Instant {
+        fn clone(&self) -> Instant {}
+    }
+    impl core::marker::Copy for Instant {}
+    impl core::ops::Sub for Instant {
+        type Output = Duration;
+        fn sub(self, other: Instant) -> Duration {}
+    }
+    impl core::ops::Add<Duration> for Instant {
+        type Output = Instant;
+        fn add(self, other: Duration) -> Instant {}
+    }
+}
+
+pub mod thread {
+    pub struct JoinHandle<T> {
+        native: crate::sync::Arc<T>,
+    }
+
+    pub struct Thread {
+        inner: crate::sync::Arc<()>,
+    }
+
+    pub fn spawn<F, T>(f: F) -> JoinHandle<T>
+    where
+        F: core::ops::FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+    }
+
+    pub fn sleep(dur: core::time::Duration) {}
+    pub fn current() -> Thread {}
+
+    impl<T> JoinHandle<T> {
+        pub fn join(self) -> core::result::Result<T, crate::boxed::Box<dyn core::any::Any + Send + 'static>> {}
+        pub fn thread(&self) -> &Thread {}
+    }
+}
+
+pub mod env {
+    pub fn var<K: core::convert::AsRef<crate::ffi::OsStr>>(key: K) -> core::result::Result<crate::string::String, VarError> {}
+    pub fn args() -> Args {}
+
+    pub enum VarError {
+        NotPresent,
+        NotUnicode(crate::ffi::OsString),
+    }
+
+    pub struct Args {
+        inner: crate::vec::Vec<crate::string::String>,
+    }
+
+    impl core::iter::Iterator for Args {
+        type Item = crate::string::String;
+        fn next(&mut self) -> core::option::Option<crate::string::String> {}
+    }
+}
+
+pub mod process {
+    pub fn exit(code: i32) -> ! {}
+    pub fn abort() -> ! {}
+}
+
+pub mod prelude {
+    pub mod v1 {
+        pub use core::prelude::v1::*;
+        pub use alloc::borrow::ToOwned;
+        pub use alloc::boxed::Box;
+        pub use alloc::string::{String, ToString};
+        pub use alloc::vec::Vec;
+    }
+
+    pub mod rust_2015 {
+        pub use crate::prelude::v1::*;
+    }
+
+    pub mod rust_2018 {
+        pub use crate::prelude::v1::*;
+    }
+
+    // one glob per prelude, the core ones repeat `v1`
+    pub mod rust_2021 {
+        pub use crate::prelude::v1::*;
+        pub use core::convert::{TryFrom, TryInto};
+        pub use core::iter::FromIterator;
+    }
+
+    pub mod rust_2024 {
+        pub use crate::prelude::rust_2021::*;
+        pub use core::future::{Future, IntoFuture};
+    }
+}
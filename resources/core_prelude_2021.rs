@@ -1,4 +0,0 @@
-// https://doc.rust-lang.org/core/prelude/rust_2021/index.html
-pub use core::iter::FromIterator;
-pub use core::convert::TryFrom;
-pub use core::convert::TryInto;
@@ -1,66 +0,0 @@
-// https://doc.rust-lang.org/std/prelude/v1/index.html
-pub use std::marker::Send;
-pub use std::marker::Sized;
-pub use std::marker::Sync;
-pub use std::marker::Unpin;
-pub use std::ops::Drop;
-pub use std::ops::Fn;
-pub use std::ops::FnMut;
-pub use std::ops::FnOnce;
-pub use std::mem::drop;
-pub use std::convert::AsMut;
-pub use std::convert::AsRef;
-pub use std::convert::From;
-pub use std::convert::Into;
-pub use std::iter::DoubleEndedIterator;
-pub use std::iter::ExactSizeIterator;
-pub use std::iter::Extend;
-pub use std::iter::IntoIterator;
-pub use std::iter::Iterator;
-pub use std::option::Option;
-pub use std::option::Option::None;
-pub use std::option::Option::Some;
-pub use std::result::Result;
-pub use std::result::Result::Err;
-pub use std::result::Result::Ok;
-pub use core::prelude::v1::assert;
-pub use core::prelude::v1::cfg;
-pub use core::prelude::v1::column;
-pub use core::prelude::v1::compile_error;
-pub use core::prelude::v1::concat;
-pub use core::prelude::v1::concat_idents;//	Experimental
-pub use core::prelude::v1::env;
-pub use core::prelude::v1::file;
-pub use core::prelude::v1::format_args;
-pub use core::prelude::v1::format_args_nl;//	Experimental
-pub use core::prelude::v1::include;
-pub use core::prelude::v1::include_bytes;
-pub use core::prelude::v1::include_str;
-pub use core::prelude::v1::line;
-pub use core::prelude::v1::log_syntax;//	Experimental
-pub use core::prelude::v1::module_path;
-pub use core::prelude::v1::option_env;
-pub use core::prelude::v1::stringify;
-pub use core::prelude::v1::trace_macros;//	Experimental
-pub use core::prelude::v1::Clone;
-pub use core::prelude::v1::Clone;
-pub use core::prelude::v1::Copy;
-pub use core::prelude::v1::Copy;
-pub use core::prelude::v1::Debug;
-pub use core::prelude::v1::Default;
-pub use core::prelude::v1::Default;
-pub use core::prelude::v1::Eq;
-pub use core::prelude::v1::Eq;
-pub use core::prelude::v1::Hash;
-pub use core::prelude::v1::Ord;
-pub use core::prelude::v1::Ord;
-pub use core::prelude::v1::PartialEq;
-pub use core::prelude::v1::PartialEq;
-pub use core::prelude::v1::PartialOrd;
-pub use core::prelude::v1::PartialOrd;
-pub use std::borrow::ToOwned;
-pub use std::boxed::Box;
-pub use std::string::String;
-pub use std::string::ToString;
-pub use std::vec::Vec;
-pub use core::prelude::v1::concat_bytes;//	Experimental
\ No newline at end of file
@@ -3,43 +3,114 @@ use std::{fs, rc::Rc, str::from_utf8, sync::Arc};
 
 use quote::ToTokens;
 use syn::{
-    parse_file, visit::Visit, visit_mut::VisitMut, Expr, Ident, Item, Lit, MetaNameValue, UseTree,
+    parse_file, punctuated::Punctuated, visit::Visit, visit_mut::VisitMut, Expr, File, ForeignItem, Ident, Item, ItemEnum, ItemExternCrate, ItemForeignMod, ItemMacro, ItemTrait, Lit, MetaNameValue, Path, TraitItem, UseTree, Visibility,
 };
 
 use crate::{
-    eval_cfg::DeleteByCfg, resolve_idents::BlocksClear, Ast, Binding, Database, Decl, DeclAst, GlobalIdent, IdentPart, ImportKind, RefstrExt, WildcardImport
+    crate_meta::{CrateMeta, Edition}, eval_cfg::{CfgSet, DeleteByCfg}, include::include_path, macro_rules::{display_invocation, parse_items, MacroRules}, resolve_idents::BlocksClear, Ast, Binding, Database, Decl, DeclAst, GlobalIdent, IdentPart, ImportKind, RefstrExt, WildcardImport
 };
 
 impl Database {
     pub fn add_crate(&mut self, base_path: &str, name: &str) {
         let src_path = base_path.concat("/").concat(name).concat("/src");
         let lib_path = src_path.add_file_segment("lib.rs");
+        self.set_default_manifest_dir(base_path, name);
+        let manifest_path = manifest_path(base_path, name);
         let mut visitor = SymbolsExplorer {
             crate_src: src_path.to_string(),
+            file: Default::default(),
             mod_stack: Default::default(),
+            impl_count: 0,
+            edition: manifest_edition(&manifest_path),
+            cfg: self.cfg_of(name, Some(&manifest_path)),
+            macros: Default::default(),
+            macro_depth: 0,
+            derived: Default::default(),
             db: self,
         };
         visitor.add_file(name, &lib_path);
+        visitor.add_derived_impls();
+    }
+
+    /// adds a crate from a single source file (nested modules should be inline)
+    pub fn add_crate_source(&mut self, name: &str, source: &str, edition: Edition) {
+        let mut visitor = SymbolsExplorer {
+            crate_src: Default::default(),
+            file: Default::default(),
+            mod_stack: Default::default(),
+            impl_count: 0,
+            edition,
+            cfg: self.cfg_of(name, None),
+            macros: Default::default(),
+            macro_depth: 0,
+            derived: Default::default(),
+            db: self,
+        };
+        visitor.add_source(name, source);
+        visitor.add_derived_impls();
     }
 
     pub fn add_crate_expanded(&mut self, base_path: &str, name: &str) {
         let src_path = base_path.concat("/").concat(name).concat("/src");
         let lib_path = base_path.concat("/").concat(name).add_file_segment("expanded.rs");
+        self.set_default_manifest_dir(base_path, name);
+        let manifest_path = manifest_path(base_path, name);
         let mut visitor = SymbolsExplorer {
             crate_src: src_path.to_string(),
+            file: Default::default(),
             mod_stack: Default::default(),
+            impl_count: 0,
+            edition: manifest_edition(&manifest_path),
+            cfg: self.cfg_of(name, Some(&manifest_path)),
+            macros: Default::default(),
+            macro_depth: 0,
+            derived: Default::default(),
             db: self,
         };
         if fs::metadata(&lib_path).is_err() {
             panic!("file {:?} not found. please run `cargo expand > expanded.rs` in the {:?} directory", lib_path, base_path.concat("/").concat(name));
         }
         visitor.add_file(name, &lib_path);
+        visitor.add_derived_impls();
     }
+
+    fn set_default_manifest_dir(&mut self, base_path: &str, name: &str) {
+        if self.crate_env_of(&name.replace('-', "_"), "CARGO_MANIFEST_DIR").is_none() {
+            self.set_crate_env(name, "CARGO_MANIFEST_DIR", &base_path.concat("/").concat(name));
+        }
+    }
+}
+
+const MAX_MACRO_DEPTH: usize = 64;
+
+fn manifest_path(base_path: &str, name: &str) -> String {
+    base_path.concat("/").concat(name).add_file_segment("Cargo.toml").to_string()
+}
+
+/// edition from `Cargo.toml` of the crate. 2021 if there is no manifest
+fn manifest_edition(manifest_path: &str) -> Edition {
+    Edition::from_manifest(manifest_path).unwrap_or_else(|| {
+        println!("WARN: cannot read edition from {:?}, 2021 is used", manifest_path);
+        Edition::E2021
+    })
 }
 
 struct SymbolsExplorer<'a> {
     crate_src: String,
+    /// file being visited, base for relative `include!` paths
+    file: String,
     mod_stack: Vec<String>,
+    /// impl blocks have no names, so they are stored as `{impl#N}` children of their mod
+    impl_count: usize,
+    edition: Edition,
+    /// options of this crate, with its features
+    cfg: CfgSet,
+    /// `macro_rules!` in textual scope, later definitions shadow earlier ones
+    macros: Vec<Rc<MacroRules>>,
+    /// nesting of macro expansions, to stop recursive macros
+    macro_depth: usize,
+    /// items with `#[derive(...)]` and their mods. impls are added after the crate, when its imports are known
+    derived: Vec<(Vec<String>, Item)>,
     db: &'a mut Database,
 }
 
@@ -56,10 +127,10 @@ impl SymbolsExplorer<'_> {
 
         self.mod_stack.push(name.clone());
 
-        parent.add_child(
-            IdentPart::from_name(&name),
-            Binding::new_empty(GlobalIdent::from_mod_and_name(&parent_path, &name)),
-        );
+        // mod may share the name with an import of a macro or a function
+        let mod_ = parent.get_or_create_child(&IdentPart::from_name(&name));
+        // crate roots are public, `visit_item_mod` corrects the rest
+        mod_.mod_public.get_or_insert(parent_path.to_parts().is_empty());
 
         let r = f(self);
         self.mod_stack.pop();
@@ -68,27 +139,136 @@ impl SymbolsExplorer<'_> {
 
     fn add_file(&mut self, name: &str, fs_path: &str) {
         println!("add_file( {:?}, {:?} )", name, fs_path);
-        let content = fs::read(fs_path).unwrap();
-        let content = from_utf8(&content).unwrap();
-        let mut ast = parse_file(content).unwrap();
-        let prelude = parse_file(concat!(
-            include_str!("../resources/std_prelude_v1.rs"),
-            include_str!("../resources/core_prelude_v1.rs"),
-            include_str!("../resources/core_prelude_2021.rs"),
-        )).unwrap();
+        let content = match fs::read(fs_path) {
+            Ok(it) => it,
+            Err(err) => {
+                println!("WARN: cannot read {:?}: {}", fs_path, err);
+                return;
+            }
+        };
+        let content = match from_utf8(&content) {
+            Ok(it) => it,
+            Err(err) => {
+                println!("WARN: {:?} is not UTF-8: {}", fs_path, err);
+                return;
+            }
+        };
+        let prev_file = std::mem::replace(&mut self.file, fs_path.to_string());
+        self.add_source(name, content);
+        self.file = prev_file;
+    }
+
+    fn add_source(&mut self, name: &str, content: &str) {
+        let mut ast = match parse_file(content) {
+            Ok(it) => it,
+            Err(err) => {
+                println!("WARN: cannot parse mod {}: {}", name, err);
+                return;
+            }
+        };
+        // before clearing the blocks, so the bodies of the items expanded from `cfg_if!` are cleared too
+        DeleteByCfg { cfg: &self.cfg }.visit_file_mut(&mut ast);
         BlocksClear.visit_file_mut(&mut ast);
-        DeleteByCfg.visit_file_mut(&mut ast);
+        if self.mod_stack.is_empty() {
+            let has_attr = |name: &str| ast.attrs.iter().any(|it| it.path().is_ident(name));
+            let meta = CrateMeta::new(self.edition, has_attr("no_std"), has_attr("no_core"));
+            println!("add crate {} (edition {}, prelude {:?})", name, meta.edition.name(), meta.prelude);
+            self.db.crates.insert(name.replace('-', "_"), meta);
+        }
         self.with_mod(name, |visitor| {
-            visitor.visit_file(&prelude);
             visitor.visit_file(&ast);
         });
     }
 
+    fn add_impl(&mut self, item: &Item) {
+        let mod_ = GlobalIdent::from_path(&self.mod_stack);
+        let address = GlobalIdent::from_mod_and_name(&mod_, &format!("{{impl#{}}}", self.impl_count));
+        self.impl_count += 1;
+        println!("add impl {}", address);
+        self.db.decls.find_mut_unchecked(&mod_).add_child(
+            address.last_part(),
+            Binding::new_non_type_ast(address, item.clone()),
+        );
+    }
+
+    fn add_derived_impls(&mut self) {
+        for (mod_stack, item) in std::mem::take(&mut self.derived) {
+            let impls = self.db.derived_impls(&item, &GlobalIdent::from_path(&mod_stack));
+            self.mod_stack = mod_stack;
+            for it in impls {
+                self.add_impl(&Item::Impl(it));
+            }
+        }
+        self.mod_stack.clear();
+    }
+
+    /// stores the item in the current mod. returns false if the name is already occupied in its namespace,
+    /// the first declaration is kept then: alternatives under undecided cfgs (`feature = "x"` and
+    /// `not(feature = "x")`) are all kept
+    fn add_decl(&mut self, ident: &Ident, ast: Ast, is_type: bool) -> bool {
+        let address = GlobalIdent::from_path_and_ident(&self.mod_stack, ident);
+        let node = self.db.decls.find_mut_unchecked(&address.parent());
+        println!("add ast {}", address);
+        let binding = node.get_or_create_child(&IdentPart::from_ident(ident));
+        let field = if is_type {
+            &mut binding.type_ast
+        } else {
+            &mut binding.non_type_ast
+        };
+        if let Some(existing) = field {
+            println!("WARN: name {} already occupied with {}", address, existing);
+            return false;
+        }
+        *field = Some(DeclAst { address, ast });
+        true
+    }
+
+    /// every variant is stored as a child of its enum in both namespaces, along with the enum without variants,
+    /// so generics of the enum stay in scope
+    fn add_variants(&mut self, item: &ItemEnum) {
+        let enum_ = ItemEnum {
+            attrs: vec![],
+            variants: Punctuated::new(),
+            ..item.clone()
+        };
+        self.mod_stack.push(item.ident.to_string());
+        for variant in item.variants.iter() {
+            let ast = Ast::Variant(enum_.clone(), variant.clone());
+            self.add_decl(&variant.ident, ast.clone(), true);
+            self.add_decl(&variant.ident, ast, false);
+        }
+        self.mod_stack.pop();
+    }
+
+    /// trait items are stored as children of the trait like enum variants.
+    /// associated types go to the type namespace, methods and consts - to the value one
+    fn add_trait_items(&mut self, item: &ItemTrait) {
+        let trait_ = ItemTrait {
+            attrs: vec![],
+            items: vec![],
+            ..item.clone()
+        };
+        self.mod_stack.push(item.ident.to_string());
+        for trait_item in item.items.iter() {
+            let (ident, is_type) = match trait_item {
+                TraitItem::Fn(it) => (&it.sig.ident, false),
+                TraitItem::Const(it) => (&it.ident, false),
+                TraitItem::Type(it) => (&it.ident, true),
+                it => {
+                    println!("WARN: skip trait item: {}", it.to_token_stream());
+                    continue;
+                }
+            };
+            self.add_decl(ident, Ast::TraitItem(trait_.clone(), trait_item.clone()), is_type);
+        }
+        self.mod_stack.pop();
+    }
+
     fn ident_of_item(item: &Item) -> Option<&Ident> {
         match item {
             Item::Const(it) => Some(&it.ident),
             Item::Enum(it) => Some(&it.ident),
-            Item::ExternCrate(it) => Some(&it.ident),
+            Item::ExternCrate(_it) => None,
             Item::Fn(it) => Some(&it.sig.ident),
             Item::ForeignMod(_it) => None,
             Item::Impl(_it) => None,
@@ -101,12 +281,201 @@ impl SymbolsExplorer<'_> {
             Item::Type(it) => Some(&it.ident),
             Item::Union(it) => Some(&it.ident),
             Item::Use(_it) => None,
-            Item::Verbatim(it) => unimplemented!("Vermatim({})", it),
-            it => unimplemented!("unknown: {:?}: {}", it, it.to_token_stream()),
+            Item::Verbatim(it) => {
+                println!("WARN: skip verbatim item: {}", it);
+                None
+            }
+            it => {
+                println!("WARN: skip unknown item: {}", it.to_token_stream());
+                None
+            }
+        }
+    }
+
+    /// registers `macro_rules!` or expands an invocation of a known one in place
+    fn add_macro_item(&mut self, item: &ItemMacro) {
+        let crate_ = self.mod_stack.first().unwrap().clone();
+        if item.mac.path.is_ident("macro_rules") {
+            let Some(name) = &item.ident else {
+                return;
+            };
+            let Some(rules) = MacroRules::parse(&name.to_string(), &crate_, item.mac.tokens.clone()) else {
+                return;
+            };
+            let rules = Rc::new(rules);
+            println!("add {}", rules);
+            if item.attrs.iter().any(|it| it.path().is_ident("macro_export")) {
+                self.db
+                    .macros
+                    .insert(GlobalIdent::from_path_and_ident(&[crate_], name), rules.clone());
+            }
+            self.macros.push(rules);
+            return;
+        }
+        if item.mac.path.is_ident("include") {
+            self.add_include(item);
+            return;
+        }
+        let invocation = display_invocation(&item.mac.path);
+        let Some(rules) = self.find_macro(&item.mac.path) else {
+            println!("WARN: skip {}!, macro_rules not found", invocation);
+            return;
+        };
+        if self.macro_depth >= MAX_MACRO_DEPTH {
+            println!("WARN: skip {}!, recursion limit reached", invocation);
+            return;
         }
+        let Some(tokens) = rules.expand(item.mac.tokens.clone()) else {
+            println!("WARN: skip {}!, no rules matched: {}", invocation, item.mac.tokens);
+            return;
+        };
+        let items = match parse_items(tokens) {
+            Ok(it) => it,
+            Err(err) => {
+                println!("WARN: skip {}!, expansion is not items: {}", invocation, err);
+                return;
+            }
+        };
+        println!("expand {}! ({} items)", invocation, items.len());
+        let file = File {
+            shebang: None,
+            attrs: vec![],
+            items,
+        };
+        self.add_expanded_items(file);
     }
 
-    fn collect_uses(&mut self, tree: &UseTree, path: Vec<String>) {
+    /// splices items of `include!("...")` into the current mod
+    fn add_include(&mut self, item: &ItemMacro) {
+        let crate_ = self.mod_stack.first().unwrap();
+        let env = self.db.crate_env.get(crate_).cloned().unwrap_or_default();
+        let path = match include_path(&item.mac, &env, &self.file) {
+            Ok(it) => it,
+            Err(err) => {
+                println!("WARN: skip include!({}): {}", item.mac.tokens, err);
+                return;
+            }
+        };
+        if self.macro_depth >= MAX_MACRO_DEPTH {
+            println!("WARN: skip include!({:?}), recursion limit reached", path);
+            return;
+        }
+        let content = match fs::read_to_string(&path) {
+            Ok(it) => it,
+            Err(err) => {
+                println!("WARN: cannot read included {:?}: {}", path, err);
+                return;
+            }
+        };
+        let file = match parse_file(&content) {
+            Ok(it) => it,
+            Err(err) => {
+                println!("WARN: cannot parse included {:?}: {}", path, err);
+                return;
+            }
+        };
+        println!("include {:?} ({} items)", path, file.items.len());
+        let prev_file = std::mem::replace(&mut self.file, path);
+        self.add_expanded_items(file);
+        self.file = prev_file;
+    }
+
+    fn add_expanded_items(&mut self, mut file: File) {
+        DeleteByCfg { cfg: &self.cfg }.visit_file_mut(&mut file);
+        BlocksClear.visit_file_mut(&mut file);
+        self.macro_depth += 1;
+        for item in file.items.iter() {
+            self.visit_item(item);
+        }
+        self.macro_depth -= 1;
+    }
+
+    /// textual scope first, then macros exported by `#[macro_export]`
+    fn find_macro(&self, path: &Path) -> Option<Rc<MacroRules>> {
+        let crate_ = self.mod_stack.first().unwrap();
+        let name = path.segments.last()?.ident.to_string();
+        if path.segments.len() == 1 {
+            if let Some(it) = self.macros.iter().rev().find(|it| it.name == name) {
+                return Some(it.clone());
+            }
+            return self.db.macros.get(&GlobalIdent::from_path_and_name(std::slice::from_ref(crate_), &name)).cloned();
+        }
+        let mut first = path.segments.first()?.ident.to_string();
+        if first == "crate" {
+            first.clone_from(crate_);
+        }
+        self.db.macros.get(&GlobalIdent::from_path_and_name(&[first], &name)).cloned()
+    }
+
+    /// `extern crate foo as bar;` imports the root of `foo` as `bar`.
+    /// at the crate root it also adds `bar` to the extern prelude
+    fn add_extern_crate(&mut self, item: &ItemExternCrate) {
+        let crate_ = self.mod_stack.first().unwrap().clone();
+        let source = if item.ident == "self" {
+            crate_.clone()
+        } else {
+            item.ident.to_string()
+        };
+        let name = match &item.rename {
+            Some((_, rename)) => rename.to_string(),
+            None => source.clone(),
+        };
+        if name != "_" {
+            let target = GlobalIdent::from_path_and_name(&self.mod_stack, &name);
+            println!("add extern crate {} (from {})", target, source);
+            self.db
+                .decls
+                .find_mut_unchecked(&target.parent())
+                .get_or_create_child(&target.last_part())
+                .alias_for
+                .push((GlobalIdent::from_path(std::slice::from_ref(&source)), ImportKind::Normal));
+            if self.mod_stack.len() == 1 {
+                if let Some(meta) = self.db.crates.get_mut(&crate_) {
+                    meta.extern_prelude.insert(name, source.clone());
+                }
+            }
+        }
+        if item.attrs.iter().any(|it| it.path().is_ident("macro_use")) {
+            let macros = self
+                .db
+                .macros
+                .values()
+                .filter(|it| it.crate_ == source)
+                .cloned()
+                .collect::<Vec<_>>();
+            if macros.is_empty() {
+                println!("WARN: no exported macros found in {} for #[macro_use]", source);
+            }
+            for it in macros {
+                println!("import macro {}", it);
+                self.macros.push(it);
+            }
+        }
+    }
+
+    /// `#[prelude_import] use prelude::rust_2021::*;` at the crate root replaces the implicit prelude
+    fn set_prelude_import(&mut self, tree: &UseTree) {
+        let crate_ = self.mod_stack.first().unwrap().clone();
+        let mut path = vec![crate_.clone()];
+        let mut tree = tree;
+        while let UseTree::Path(it) = tree {
+            if it.ident != "crate" && it.ident != "self" {
+                path.push(it.ident.to_string());
+            }
+            tree = &it.tree;
+        }
+        if !matches!(tree, UseTree::Glob(_)) {
+            println!("WARN: prelude import of {} is not a glob", crate_);
+            return;
+        }
+        let prelude = GlobalIdent::from_path(&path);
+        println!("prelude of {} is {}", crate_, prelude);
+        if let Some(meta) = self.db.crates.get_mut(&crate_) {
+            meta.prelude = Some(prelude);
+        }
+    }
+
+    fn collect_uses(&mut self, tree: &UseTree, path: Vec<String>, public: bool) {
         match tree {
             UseTree::Path(it) => {
                 let new_path = if path.is_empty() {
@@ -114,8 +483,11 @@ impl SymbolsExplorer<'_> {
                     if name == "self" {
                         self.mod_stack.clone()
                     } else {
+                        let crate_ = self.mod_stack.first().unwrap();
                         if name == "crate" {
-                            name.clone_from(self.mod_stack.first().unwrap());
+                            name.clone_from(crate_);
+                        } else if let Some(it) = self.db.extern_crate_of(crate_, &name) {
+                            name.clone_from(it);
                         }
                         vec![name]
                     }
@@ -124,7 +496,7 @@ impl SymbolsExplorer<'_> {
                     v.push(it.ident.to_string());
                     v
                 };
-                self.collect_uses(&it.tree, new_path);
+                self.collect_uses(&it.tree, new_path, public);
             }
             UseTree::Name(it) => {
                 let (source, target) = if it.ident == "self" {
@@ -141,23 +513,29 @@ impl SymbolsExplorer<'_> {
                     (source, target)
                 };
                 println!("add import {} (from {})", target, source);
-                self.db
+                let binding = self
+                    .db
                     .decls
                     .find_mut_unchecked(&target.parent())
-                    .get_or_create_child(&target.last_part())
-                    .alias_for
-                    .push((source, ImportKind::Normal));
+                    .get_or_create_child(&target.last_part());
+                if public {
+                    binding.public_imports.insert(source.clone());
+                }
+                binding.alias_for.push((source, ImportKind::Normal));
             }
             UseTree::Rename(it) => {
                 let source = GlobalIdent::from_path_and_name(&path, it.ident.to_string().as_str());
                 let target = GlobalIdent::from_path_and_ident(&self.mod_stack, &it.rename);
                 println!("add import {} (from {})", target, source);
-                self.db
+                let binding = self
+                    .db
                     .decls
                     .find_mut_unchecked(&target.parent())
-                    .get_or_create_child(&target.last_part())
-                    .alias_for
-                    .push((source, ImportKind::Normal));
+                    .get_or_create_child(&target.last_part());
+                if public {
+                    binding.public_imports.insert(source.clone());
+                }
+                binding.alias_for.push((source, ImportKind::Normal));
             }
             UseTree::Glob(_it) => {
                 let current_mod = self
@@ -168,6 +546,9 @@ impl SymbolsExplorer<'_> {
                 current_mod
                     .wildcard_alias_for
                     .insert(GlobalIdent::from_path(&path));
+                if public {
+                    current_mod.public_imports.insert(GlobalIdent::from_path(&path));
+                }
 
                 self.db.wildcard_imports_temp.push(Rc::new(WildcardImport {
                     target: GlobalIdent::from_path(&self.mod_stack),
@@ -176,7 +557,7 @@ impl SymbolsExplorer<'_> {
             }
             UseTree::Group(it) => {
                 for it in it.items.iter() {
-                    self.collect_uses(it, path.clone());
+                    self.collect_uses(it, path.clone(), public);
                 }
             }
         }
@@ -190,6 +571,18 @@ impl<'ast> Visit<'ast> for SymbolsExplorer<'_> {
             // mods are already handled
             return;
         }
+        if let Item::Impl(_) = i {
+            self.add_impl(i);
+            return;
+        }
+        if let Item::Macro(it) = i {
+            self.add_macro_item(it);
+            return;
+        }
+        if let Item::ExternCrate(it) = i {
+            self.add_extern_crate(it);
+            return;
+        }
         if let Some(ident) = Self::ident_of_item(i) {
             if ident == "test" {
                 return;
@@ -197,26 +590,49 @@ impl<'ast> Visit<'ast> for SymbolsExplorer<'_> {
             if ident == "tests" {
                 return;
             }
-            let address = GlobalIdent::from_path_and_ident(&self.mod_stack, ident);
-            let node = self.db.decls.find_mut_unchecked(&address.parent());
-            println!("add ast {}", address);
-            let binding = node.get_or_create_child(&IdentPart::from_ident(ident));
-            if let Some(ast) = &binding.non_type_ast {
-                panic!("name {:?} already occupied with {}", address, ast);
+            let is_type = matches!(
+                i,
+                Item::Enum(_) | Item::Struct(_) | Item::Trait(_) | Item::Type(_) | Item::Union(_)
+            );
+            if !self.add_decl(ident, Ast::Real(i.clone()), is_type) {
+                return;
+            }
+            if let Item::Enum(it) = i {
+                self.add_variants(it);
+            }
+            if let Item::Trait(it) = i {
+                self.add_trait_items(it);
             }
-            let field = match i {
-                Item::Enum(_) => &mut binding.type_ast,
-                Item::Struct(_) => &mut binding.type_ast,
-                Item::Trait(_) => &mut binding.type_ast,
-                Item::Type(_) => &mut binding.type_ast,
-                Item::Union(_) => &mut binding.type_ast,
-                _ => &mut binding.non_type_ast,
+
+            let attrs = match i {
+                Item::Enum(it) => &it.attrs[..],
+                Item::Struct(it) => &it.attrs[..],
+                Item::Union(it) => &it.attrs[..],
+                _ => &[],
             };
+            if attrs.iter().any(|it| it.path().is_ident("derive")) {
+                self.derived.push((self.mod_stack.clone(), i.clone()));
+            }
+        }
+    }
 
-            *field = Some(DeclAst {
-                address,
-                ast: Ast::Real(i.clone()),
-            });
+    fn visit_item_foreign_mod(&mut self, i: &'ast syn::ItemForeignMod) {
+        for item in i.items.iter() {
+            let (ident, is_type) = match item {
+                ForeignItem::Fn(it) => (&it.sig.ident, false),
+                ForeignItem::Static(it) => (&it.ident, false),
+                ForeignItem::Type(it) => (&it.ident, true),
+                it => {
+                    println!("WARN: skip foreign item: {}", it.to_token_stream());
+                    continue;
+                }
+            };
+            // every foreign item is stored as a block of its own to keep ABI and `#[link]` attributes
+            let block = ItemForeignMod {
+                items: vec![item.clone()],
+                ..i.clone()
+            };
+            self.add_decl(ident, Ast::Real(Item::ForeignMod(block)), is_type);
         }
     }
 
@@ -227,6 +643,26 @@ impl<'ast> Visit<'ast> for SymbolsExplorer<'_> {
         if i.ident == "tests" {
             return;
         }
+        let macros_in_scope = self.macros.len();
+        self.visit_mod_content(i);
+        let address = GlobalIdent::from_path_and_ident(&self.mod_stack, &i.ident);
+        self.db.decls.find_mut_unchecked(&address).get_value_mut().mod_public = Some(matches!(i.vis, Visibility::Public(_)));
+        // macros defined inside a mod are visible after it only with `#[macro_use]`
+        if !i.attrs.iter().any(|it| it.path().is_ident("macro_use")) {
+            self.macros.truncate(macros_in_scope);
+        }
+    }
+
+    fn visit_item_use(&mut self, i: &'ast syn::ItemUse) {
+        if i.attrs.iter().any(|it| it.path().is_ident("prelude_import")) {
+            self.set_prelude_import(&i.tree);
+        }
+        self.collect_uses(&i.tree, vec![], matches!(i.vis, Visibility::Public(_)));
+    }
+}
+
+impl SymbolsExplorer<'_> {
+    fn visit_mod_content(&mut self, i: &syn::ItemMod) {
         match &i.content {
             Some((_brace, content)) => {
                 self.with_mod(i.ident.to_string().as_str(), |self_| {
@@ -282,7 +718,68 @@ impl<'ast> Visit<'ast> for SymbolsExplorer<'_> {
         }
     }
 
-    fn visit_item_use(&mut self, i: &'ast syn::ItemUse) {
-        self.collect_uses(&i.tree, vec![]);
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+
+    use crate::{crate_meta::Edition, Ast, Database, GlobalIdent};
+
+    fn decl(db: &Database, name: &str, is_type: bool) -> Option<String> {
+        let binding = db.decls.find_value(GlobalIdent::from_qualified_name(name).to_parts())?;
+        let ast = if is_type { &binding.type_ast } else { &binding.non_type_ast };
+        Some(ast.as_ref()?.ast.as_ref()?.to_token_stream().to_string())
+    }
+
+    #[test]
+    fn first_declaration_is_kept() {
+        let mut db = Database::default();
+        db.add_crate_source(
+            "c",
+            r#"
+            pub struct A { first: u8 }
+            pub type A = u16;
+            #[cfg(feature = "wide")]
+            pub fn f() -> u16 { 2 }
+            #[cfg(not(feature = "wide"))]
+            pub fn f() -> u8 { 1 }
+            "#,
+            Edition::E2021,
+        );
+        assert!(decl(&db, "c::A", true).unwrap().contains("first"));
+        assert!(decl(&db, "c::f", false).unwrap().contains("u16"));
+    }
+
+    #[test]
+    fn namespaces_are_separate() {
+        let mut db = Database::default();
+        db.add_crate_source(
+            "c",
+            "
+            pub fn a() {}
+            pub struct a {}
+            ",
+            Edition::E2021,
+        );
+        assert!(decl(&db, "c::a", false).unwrap().contains("fn"));
+        assert!(decl(&db, "c::a", true).unwrap().contains("struct"));
+    }
+
+    #[test]
+    fn variants_are_stored_with_their_enum() {
+        let mut db = Database::default();
+        db.add_crate_source("c", "pub enum E<T> { A(T), B { b: u8 } }", Edition::E2021);
+        let binding = db.decls.find_value(GlobalIdent::from_qualified_name("c::E::A").to_parts()).unwrap();
+        for decl in [&binding.type_ast, &binding.non_type_ast] {
+            let Ast::Variant(enum_, variant) = &decl.as_ref().unwrap().ast else {
+                panic!("not a variant: {:?}", decl);
+            };
+            assert_eq!(variant.ident, "A");
+            assert!(enum_.variants.is_empty());
+            assert_eq!(enum_.generics.params.len(), 1);
+        }
+        assert!(decl(&db, "c::E::A", true).is_none());
+        assert!(decl(&db, "c::E", true).unwrap().contains("B"));
     }
 }
@@ -0,0 +1,267 @@
+use syn::{Fields, Item};
+
+use crate::{
+    generic_bounds::{ResolvedGenericParam, ResolvedGenerics},
+    trait_resolution::{unify, ImplMatch, Solver},
+    Ast, Database, GlobalIdent, ResolvedType, TraitRef,
+};
+
+const MAX_CHAIN_LENGTH: usize = 32;
+
+/// answer of `Database::auto_trait`
+#[derive(Debug, Clone)]
+pub struct AutoTraitAnswer {
+    pub ty: ResolvedType,
+    pub trait_: TraitRef,
+    pub implemented: bool,
+    /// fields from the type down to the one breaking the trait, empty if the type itself breaks it
+    pub chain: Vec<AutoTraitLink>,
+    /// why the last type of the chain doesn't implement the trait
+    pub reason: Option<String>,
+}
+
+/// field of `owner` on the way to the type breaking an auto trait
+#[derive(Debug, Clone)]
+pub struct AutoTraitLink {
+    pub owner: ResolvedType,
+    /// `cache`, `0` for tuples and tuple structs, `Some.0` for enum variants, `[]` for arrays and slices,
+    /// `*` for references, `<T>` for type args (like `T` of `PhantomData<T>` or `Vec<T>`), `=` for type aliases
+    pub field: String,
+    pub ty: ResolvedType,
+}
+
+impl Database {
+    /// `Send`, `Sync`, `Unpin` and other `auto trait`s
+    pub fn is_auto_trait(&self, trait_: &GlobalIdent) -> bool {
+        let Some(binding) = self.decls.find_value(trait_.to_parts()) else {
+            return false;
+        };
+        matches!(
+            binding.type_ast.as_ref().map(|it| &it.ast),
+            Some(Ast::Real(Item::Trait(it))) if it.auto_token.is_some()
+        )
+    }
+
+    /// whether `ty` implements the auto trait (like `core::marker::Send`), explaining the failure
+    pub fn auto_trait(&self, ty: &ResolvedType, trait_: &GlobalIdent) -> AutoTraitAnswer {
+        self.auto_trait_in(ty, trait_, &Default::default())
+    }
+
+    pub fn auto_trait_in(&self, ty: &ResolvedType, trait_: &GlobalIdent, env: &ResolvedGenerics) -> AutoTraitAnswer {
+        let trait_ = TraitRef {
+            address: trait_.clone(),
+            args: vec![],
+        };
+        if !self.is_auto_trait(&trait_.address) {
+            println!("WARN: {} is not an auto trait", trait_);
+        }
+        let mut solver = Solver::new(self, env);
+        let mut answer = AutoTraitAnswer {
+            ty: ty.clone(),
+            trait_: trait_.clone(),
+            implemented: true,
+            chain: vec![],
+            reason: None,
+        };
+        let ImplMatch::NoMatch(reasons) = solver.solve(ty, &trait_) else {
+            return answer;
+        };
+        answer.implemented = false;
+        answer.reason = Some(reasons.join("; "));
+        // descend through the fields, or through the type args if the trait comes from an impl
+        // (like `unsafe impl<T: Send> Send for Vec<T>`), until the type breaking the trait is found
+        let mut current = solver.normalize(&self.expand_aliases(ty));
+        while answer.chain.len() < MAX_CHAIN_LENGTH {
+            let components = if solver.has_impl_header(&current, &trait_) {
+                self.type_args(&current)
+            } else {
+                solver.auto_components(&current)
+            };
+            let Some(components) = components else {
+                break;
+            };
+            let broken = components.into_iter().find_map(|(field, ty)| match solver.solve(&ty, &trait_) {
+                ImplMatch::NoMatch(reasons) => Some((field, ty, reasons)),
+                _ => None,
+            });
+            let Some((field, ty, reasons)) = broken else {
+                break;
+            };
+            answer.reason = Some(reasons.join("; "));
+            answer.chain.push(AutoTraitLink {
+                owner: current,
+                field,
+                ty: ty.clone(),
+            });
+            current = solver.normalize(&self.expand_aliases(&ty));
+        }
+        answer
+    }
+}
+
+impl Solver<'_> {
+    /// auto trait of a type without impls of it: implemented if all the components implement it
+    pub(crate) fn solve_auto(&mut self, ty: &ResolvedType, trait_: &TraitRef) -> ImplMatch {
+        if let ResolvedType::TraitObject(traits) | ResolvedType::ImplTrait(traits) = ty {
+            return if traits.iter().any(|it| it.address == trait_.address) {
+                ImplMatch::Auto
+            } else {
+                ImplMatch::NoMatch(vec![format!("{} doesn't list {}", ty, trait_)])
+            };
+        }
+        let Some(components) = self.auto_components(ty) else {
+            return ImplMatch::NoMatch(vec![match ty {
+                ResolvedType::Generic(name) => format!("{} has no bound {}", name, trait_),
+                _ => format!("cannot infer {} for {}", trait_, ty),
+            }]);
+        };
+        for (field, component) in components {
+            if let ImplMatch::NoMatch(reasons) = self.solve(&component, trait_) {
+                return ImplMatch::NoMatch(vec![format!("{} of {}: {}", field, ty, reasons.join("; "))]);
+            }
+        }
+        ImplMatch::Auto
+    }
+
+    /// types an auto trait of `ty` is derived from, `None` if they aren't known
+    pub(crate) fn auto_components(&self, ty: &ResolvedType) -> Option<Vec<(String, ResolvedType)>> {
+        match ty {
+            ResolvedType::Primitive(_)
+            | ResolvedType::Fn { .. }
+            | ResolvedType::Never
+            | ResolvedType::Const(_) => Some(vec![]),
+            ResolvedType::Reference { elem, .. } => Some(vec![("*".to_string(), *elem.clone())]),
+            ResolvedType::Slice(elem) | ResolvedType::Array { elem, .. } => Some(vec![("[]".to_string(), *elem.clone())]),
+            ResolvedType::Tuple(items) => Some(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, it)| (i.to_string(), it.clone()))
+                    .collect(),
+            ),
+            // the compiler treats `PhantomData<T>` as if it owned `T`
+            ResolvedType::Path { address, args } if address.to_string() == "core::marker::PhantomData" => {
+                Some(args.iter().map(|it| ("<T>".to_string(), it.clone())).collect())
+            }
+            ResolvedType::Path { address, args } => match self.db.expand_alias(address, args) {
+                Some(it) => Some(vec![("=".to_string(), it)]),
+                None => self.db.field_types(address, args),
+            },
+            _ => None,
+        }
+    }
+
+    /// whether some impl (positive or negative) of the trait is written for the type,
+    /// so its fields don't matter
+    pub(crate) fn has_impl_header(&self, ty: &ResolvedType, trait_: &TraitRef) -> bool {
+        let Some(impls) = self.db.impls.get(&trait_.address) else {
+            return false;
+        };
+        let ty = self.db.with_default_args(ty);
+        impls
+            .iter()
+            .any(|record| unify(&record.self_ty, &ty, &record.params(), &mut Default::default()))
+    }
+}
+
+impl Database {
+    /// `<T>` to `u8` for `Vec<u8>`
+    fn type_args(&self, ty: &ResolvedType) -> Option<Vec<(String, ResolvedType)>> {
+        match ty {
+            ResolvedType::Path { address, args } => {
+                let generics = self.generics_of(address)?;
+                let params = generics.params.iter().filter_map(|it| match it {
+                    ResolvedGenericParam::Type { name, .. } | ResolvedGenericParam::Const { name, .. } => Some(name),
+                    ResolvedGenericParam::Lifetime { .. } => None,
+                });
+                Some(params.zip(args.iter()).map(|(name, arg)| (format!("<{}>", name), arg.clone())).collect())
+            }
+            ResolvedType::Reference { elem, .. } => Some(vec![("*".to_string(), *elem.clone())]),
+            _ => None,
+        }
+    }
+
+    /// types of all the fields of the struct, enum or union with generic params substituted
+    fn field_types(&self, address: &GlobalIdent, args: &[ResolvedType]) -> Option<Vec<(String, ResolvedType)>> {
+        let decl = self.decls.find_value(address.to_parts())?.type_ast.as_ref()?;
+        let Ast::Real(item) = &decl.ast else {
+            println!("WARN: declaration of {} is not available to infer auto traits", address);
+            return None;
+        };
+        let (generics, fields) = match item {
+            Item::Struct(it) => (&it.generics, named_fields("", &it.fields)),
+            Item::Enum(it) => (
+                &it.generics,
+                it.variants
+                    .iter()
+                    .flat_map(|v| named_fields(&format!("{}.", v.ident), &v.fields))
+                    .collect(),
+            ),
+            Item::Union(it) => (&it.generics, named_fields("", &Fields::Named(it.fields.clone()))),
+            _ => return None,
+        };
+        let substitution = self.substitution_of(generics, args);
+        Some(
+            fields
+                .into_iter()
+                .map(|(name, ty)| (name, self.resolve_type(&ty).substitute(&substitution)))
+                .collect(),
+        )
+    }
+}
+
+fn named_fields(prefix: &str, fields: &Fields) -> Vec<(String, syn::Type)> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, it)| {
+            let name = it.ident.as_ref().map(|it| it.to_string()).unwrap_or_else(|| i.to_string());
+            (format!("{}{}", prefix, name), it.ty.clone())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_support::compiled, GlobalIdent, ResolvedType};
+
+    const SOURCE: &str = "
+        use std::cell::Cell;
+        pub struct Raw(*const u8);
+        pub struct Holder { pub id: u32, pub cache: Option<Raw> }
+        pub struct Shared { pub count: Cell<u32> }
+        pub struct Wrapped(*mut u8);
+        unsafe impl Send for Wrapped {}
+    ";
+
+    fn path(name: &str) -> ResolvedType {
+        ResolvedType::Path {
+            address: GlobalIdent::from_qualified_name(name),
+            args: vec![],
+        }
+    }
+
+    #[test]
+    fn chain_to_the_breaking_field() {
+        let db = compiled(SOURCE);
+        let send = GlobalIdent::from_qualified_name("core::marker::Send");
+        let answer = db.auto_trait(&path("c::Holder"), &send);
+        assert!(!answer.implemented);
+        let chain = answer.chain.iter().map(|it| it.field.as_str()).collect::<Vec<_>>();
+        assert_eq!(chain, ["cache", "Some.0", "0"]);
+        assert_eq!(answer.chain.last().unwrap().ty.to_string(), "*const u8");
+        assert!(db.auto_trait(&path("c::Shared"), &send).implemented);
+    }
+
+    #[test]
+    fn explicit_impls_win() {
+        let db = compiled(SOURCE);
+        let send = GlobalIdent::from_qualified_name("core::marker::Send");
+        let sync = GlobalIdent::from_qualified_name("core::marker::Sync");
+        assert!(db.auto_trait(&path("c::Wrapped"), &send).implemented);
+        assert!(!db.auto_trait(&path("c::Wrapped"), &sync).implemented);
+        let answer = db.auto_trait(&path("c::Shared"), &sync);
+        assert!(!answer.implemented);
+        assert!(answer.reason.is_some());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{crate_meta::Edition, Database, IdentPart};
+
+/// signature-only models of the standard crates, used when the real sources are not added
+const BUILTIN_CRATES: [(&str, &str); 3] = [
+    ("core", include_str!("../resources/builtin/core.rs")),
+    ("alloc", include_str!("../resources/builtin/alloc.rs")),
+    ("std", include_str!("../resources/builtin/std.rs")),
+];
+
+impl Database {
+    pub fn add_builtin_crates(&mut self) {
+        for (name, source) in BUILTIN_CRATES {
+            if self.decls.get_child(&IdentPart::from_name(name)).is_some() {
+                println!("skip builtin crate {} (already added)", name);
+                continue;
+            }
+            self.add_crate_source(name, source, Edition::E2021);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::Item;
+
+    use crate::{crate_meta::Edition, test_support::compiled, Database, GlobalIdent};
+
+    #[test]
+    fn prelude_types_resolve_without_sources() {
+        let db = compiled("pub struct Holder { pub s: String, pub o: Option<i32>, pub v: Vec<bool> }");
+        let binding = db.decls.find_value(GlobalIdent::from_qualified_name("c::Holder").to_parts()).unwrap();
+        let Some(Item::Struct(holder)) = binding.type_ast.as_ref().and_then(|it| it.ast.as_ref()) else {
+            panic!("no struct");
+        };
+        let fields = holder.fields.iter().map(|it| db.resolve_type(&it.ty).to_string()).collect::<Vec<_>>();
+        assert_eq!(fields, ["alloc::string::String", "core::option::Option<i32>", "alloc::vec::Vec<bool>"]);
+    }
+
+    #[test]
+    fn added_crates_are_not_replaced() {
+        let mut db = Database::default();
+        db.add_crate_source("core", "pub struct Marker;", Edition::E2021);
+        db.compile();
+        assert!(db.decls.find_value(GlobalIdent::from_qualified_name("core::Marker").to_parts()).is_some());
+        assert!(db.decls.find_value(GlobalIdent::from_qualified_name("core::option::Option").to_parts()).is_none());
+        assert!(db.decls.find_value(GlobalIdent::from_qualified_name("alloc::string::String").to_parts()).is_some());
+    }
+}
@@ -0,0 +1,763 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+};
+
+use syn::{Attribute, Expr, Fields, FnArg, Item, Lit, Pat, Signature, Variant};
+
+use crate::{
+    codegen::{is_std, unique_names}, dedoc::doc_of, Ast, Database, GlobalIdent, IdentPart, Primitive, ResolvedType,
+};
+
+/// C header of the FFI surface of a crate, see `Database::c_header`
+#[derive(Debug, Clone, Default)]
+pub struct CHeader {
+    pub code: String,
+    /// items left out because they can't be expressed in C, with the reason
+    pub skipped: Vec<(String, String)>,
+}
+
+/// `#[repr(...)]` of a struct, enum or union
+#[derive(Debug, Clone, Default)]
+struct Repr {
+    c: bool,
+    transparent: bool,
+    /// `u8`, `i32`... of enums
+    int: Option<String>,
+    /// `packed` or `align(N)`, the layout can't be expressed in plain C
+    modified: bool,
+}
+
+impl Repr {
+    fn is_ffi_safe(&self) -> bool {
+        (self.c || self.transparent || self.int.is_some()) && !self.modified
+    }
+}
+
+struct CGenerator<'a> {
+    db: &'a Database,
+    /// `#[repr(C)]` (or `transparent`, or `#[repr(u8)]`...) types with their C names
+    types: BTreeMap<GlobalIdent, (String, Item)>,
+    /// other types used behind pointers, declared as incomplete structs
+    opaque: BTreeMap<GlobalIdent, String>,
+    /// `#[no_mangle]` functions and statics by the symbol name
+    fns: Vec<(GlobalIdent, String, Item)>,
+    skipped: Vec<(String, String)>,
+}
+
+impl Database {
+    /// C declarations of the `#[repr(C)]` structs, enums and unions of the crate, and prototypes of its
+    /// `extern "C"` functions and statics exported with `#[no_mangle]` (or `#[export_name]`).
+    /// types of other crates mentioned by them are declared too, aliases are expanded to what they stand for
+    pub fn c_header(&self, crate_: &str) -> CHeader {
+        let mut generator = CGenerator {
+            db: self,
+            types: Default::default(),
+            opaque: Default::default(),
+            fns: vec![],
+            skipped: vec![],
+        };
+        generator.collect(crate_);
+        let code = generator.generate(crate_);
+        CHeader {
+            code,
+            skipped: generator.skipped,
+        }
+    }
+}
+
+impl CGenerator<'_> {
+    fn collect(&mut self, crate_: &str) {
+        let Some(node) = self.db.decls.find(vec![IdentPart::from_name(crate_)]) else {
+            println!("WARN: crate {} is not found", crate_);
+            return;
+        };
+        let mut queue = vec![];
+        node.for_each(&mut |_, binding| {
+            for decl in binding.type_ast.iter().chain(binding.non_type_ast.iter()) {
+                let Ast::Real(item) = &decl.ast else {
+                    continue;
+                };
+                match item {
+                    Item::Struct(it) if repr_of(&it.attrs).is_ffi_safe() => {
+                        queue.push(decl.address.clone())
+                    }
+                    Item::Enum(it) if repr_of(&it.attrs).is_ffi_safe() => {
+                        queue.push(decl.address.clone())
+                    }
+                    Item::Union(it) if repr_of(&it.attrs).is_ffi_safe() => {
+                        queue.push(decl.address.clone())
+                    }
+                    Item::Fn(it) => {
+                        if let Some(symbol) = exported_symbol(&it.attrs, &it.sig.ident) {
+                            match it.sig.abi.as_ref().map(|it| it.name.as_ref().map(|it| it.value())) {
+                                Some(None) => self.fns.push((decl.address.clone(), symbol, item.clone())),
+                                Some(Some(abi)) if abi == "C" || abi == "C-unwind" => {
+                                    self.fns.push((decl.address.clone(), symbol, item.clone()))
+                                }
+                                _ => self.skipped.push((decl.address.to_string(), "not extern \"C\"".to_string())),
+                            }
+                        }
+                    }
+                    Item::Static(it) => {
+                        if let Some(symbol) = exported_symbol(&it.attrs, &it.ident) {
+                            self.fns.push((decl.address.clone(), symbol, item.clone()));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        });
+        for (_, _, item) in self.fns.clone() {
+            for (ty, _) in self.signature_types(&item) {
+                self.mention(&ty, &mut queue);
+            }
+        }
+        // types the fields mention, transitively
+        while let Some(address) = queue.pop() {
+            if self.types.contains_key(&address) {
+                continue;
+            }
+            let Some(item) = self.type_item(&address) else {
+                continue;
+            };
+            for ty in self.field_types(&item) {
+                self.mention(&ty, &mut queue);
+            }
+            self.types.insert(address, (String::new(), item));
+        }
+        self.opaque.retain(|it, _| !self.types.contains_key(it));
+        self.name_types();
+    }
+
+    /// queues `repr(C)` types the type mentions, marks the other ones behind pointers as opaque
+    fn mention(&mut self, ty: &ResolvedType, queue: &mut Vec<GlobalIdent>) {
+        ty.for_each(&mut |it| {
+            let ResolvedType::Path { address, .. } = it else {
+                return;
+            };
+            if is_std(address) {
+                if !is_std_pointer(address) && address.to_string() != "core::ffi::c_void" {
+                    self.opaque.insert(address.clone(), String::new());
+                }
+                return;
+            }
+            match self.type_item(address) {
+                Some(_) => queue.push(address.clone()),
+                None => {
+                    self.opaque.insert(address.clone(), String::new());
+                }
+            }
+        });
+    }
+
+    /// the struct, enum or union if it has a C layout
+    fn type_item(&self, address: &GlobalIdent) -> Option<Item> {
+        let decl = self.db.decls.find_value(address.to_parts())?.type_ast.as_ref()?;
+        let Ast::Real(item) = &decl.ast else {
+            return None;
+        };
+        let attrs = match item {
+            Item::Struct(it) => &it.attrs,
+            Item::Enum(it) => &it.attrs,
+            Item::Union(it) => &it.attrs,
+            _ => return None,
+        };
+        repr_of(attrs).is_ffi_safe().then(|| item.clone())
+    }
+
+    fn field_types(&self, item: &Item) -> Vec<ResolvedType> {
+        let types: Vec<&syn::Type> = match item {
+            Item::Struct(it) => it.fields.iter().map(|it| &it.ty).collect(),
+            Item::Enum(it) => it.variants.iter().flat_map(|it| it.fields.iter()).map(|it| &it.ty).collect(),
+            Item::Union(it) => it.fields.named.iter().map(|it| &it.ty).collect(),
+            _ => vec![],
+        };
+        types.into_iter().map(|it| self.resolve(it)).collect()
+    }
+
+    /// params with their names and the return type (named `""`) of a function, the type of a static
+    fn signature_types(&self, item: &Item) -> Vec<(ResolvedType, String)> {
+        match item {
+            Item::Fn(it) => {
+                let mut types = params(&it.sig)
+                    .into_iter()
+                    .map(|(name, ty)| (self.resolve(&ty), name))
+                    .collect::<Vec<_>>();
+                types.push((self.db.expand_aliases(&self.db.resolve_return_type(&it.sig.output)), String::new()));
+                types
+            }
+            Item::Static(it) => vec![(self.resolve(&it.ty), it.ident.to_string())],
+            _ => vec![],
+        }
+    }
+
+    fn resolve(&self, ty: &syn::Type) -> ResolvedType {
+        self.db.expand_aliases(&self.db.normalize(&self.db.resolve_type(ty)))
+    }
+
+    /// `Point`, or `WorldPoint` if another `Point` is declared too
+    fn name_types(&mut self) {
+        let names = unique_names(self.types.keys().chain(self.opaque.keys()));
+        for (address, (it, _)) in self.types.iter_mut() {
+            *it = names[address].clone();
+        }
+        for (address, it) in self.opaque.iter_mut() {
+            *it = names[address].clone();
+        }
+    }
+
+    fn generate(&mut self, crate_: &str) -> String {
+        let guard = format!("{}_H", crate_.to_uppercase());
+        let mut out = String::new();
+        writeln!(out, "/* generated by syg from the resolved signatures */").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "#ifndef {}", guard).unwrap();
+        writeln!(out, "#define {}", guard).unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "#include <stdbool.h>").unwrap();
+        writeln!(out, "#include <stdint.h>").unwrap();
+
+        // types which can't be defined are declared opaque, so the ones containing them are retried
+        let definitions = loop {
+            let mut definitions = BTreeMap::new();
+            let mut failed = vec![];
+            for (address, (name, item)) in self.types.iter() {
+                match self.type_definition(address, name, item) {
+                    Ok(it) => {
+                        definitions.insert(address.clone(), it);
+                    }
+                    Err(reason) => failed.push((address.clone(), reason)),
+                }
+            }
+            if failed.is_empty() {
+                break definitions;
+            }
+            for (address, reason) in failed {
+                println!("WARN: {} is left out of the header: {}", address, reason);
+                self.skipped.push((address.to_string(), reason));
+                let (name, _) = self.types.remove(&address).unwrap();
+                self.opaque.insert(address, name);
+            }
+        };
+
+        let forward = self
+            .opaque
+            .values()
+            .map(|name| format!("typedef struct {} {};", name, name))
+            .chain(definitions.values().filter_map(|it| it.forward.clone()))
+            .collect::<Vec<_>>();
+        if !forward.is_empty() {
+            writeln!(out).unwrap();
+            for it in forward {
+                writeln!(out, "{}", it).unwrap();
+            }
+        }
+        for address in self.definition_order(&definitions) {
+            writeln!(out).unwrap();
+            write_doc(&mut out, &doc_of(item_attrs(&self.types[&address].1)));
+            write!(out, "{}", definitions[&address].body).unwrap();
+        }
+
+        let mut prototypes = vec![];
+        for (address, symbol, item) in self.fns.clone() {
+            match self.prototype(&symbol, &item) {
+                Ok(it) => prototypes.push((doc_of(item_attrs(&item)), it)),
+                Err(reason) => {
+                    println!("WARN: {} is left out of the header: {}", address, reason);
+                    self.skipped.push((address.to_string(), reason));
+                }
+            }
+        }
+        if !prototypes.is_empty() {
+            writeln!(out).unwrap();
+            writeln!(out, "#ifdef __cplusplus").unwrap();
+            writeln!(out, "extern \"C\" {{").unwrap();
+            writeln!(out, "#endif").unwrap();
+            for (doc, it) in prototypes {
+                writeln!(out).unwrap();
+                write_doc(&mut out, &doc);
+                writeln!(out, "{}", it).unwrap();
+            }
+            writeln!(out).unwrap();
+            writeln!(out, "#ifdef __cplusplus").unwrap();
+            writeln!(out, "}}").unwrap();
+            writeln!(out, "#endif").unwrap();
+        }
+        writeln!(out).unwrap();
+        writeln!(out, "#endif /* {} */", guard).unwrap();
+        out
+    }
+
+    /// definitions after the ones they contain by value (pointers are fine with forward declarations)
+    fn definition_order(&self, definitions: &BTreeMap<GlobalIdent, Definition>) -> Vec<GlobalIdent> {
+        fn visit(
+            address: &GlobalIdent,
+            definitions: &BTreeMap<GlobalIdent, Definition>,
+            visited: &mut BTreeSet<GlobalIdent>,
+            order: &mut Vec<GlobalIdent>,
+        ) {
+            if !visited.insert(address.clone()) {
+                return;
+            }
+            for it in definitions[address].contains.iter() {
+                if definitions.contains_key(it) {
+                    visit(it, definitions, visited, order);
+                }
+            }
+            order.push(address.clone());
+        }
+        let mut visited = BTreeSet::new();
+        let mut order = vec![];
+        for address in definitions.keys() {
+            visit(address, definitions, &mut visited, &mut order);
+        }
+        order
+    }
+
+    fn type_definition(&self, address: &GlobalIdent, name: &str, item: &Item) -> Result<Definition, String> {
+        let mut definition = Definition::default();
+        match item {
+            Item::Struct(it) if repr_of(&it.attrs).transparent => {
+                let field = it.fields.iter().next().ok_or("transparent struct without fields")?;
+                let ty = self.resolve(&field.ty);
+                definition.contains.extend(by_value(&ty));
+                definition.body = format!("typedef {};\n", self.declaration(&ty, name)?);
+            }
+            Item::Struct(it) => {
+                if it.fields.is_empty() {
+                    return Err("C structs can't be empty".to_string());
+                }
+                definition.forward = Some(format!("typedef struct {} {};", name, name));
+                definition.body = format!("struct {} {{\n{}}};\n", name, self.fields(&it.fields, &mut definition.contains)?);
+            }
+            Item::Union(it) => {
+                definition.forward = Some(format!("typedef union {} {};", name, name));
+                let fields = Fields::Named(it.fields.clone());
+                definition.body = format!("union {} {{\n{}}};\n", name, self.fields(&fields, &mut definition.contains)?);
+            }
+            Item::Enum(it) => {
+                let repr = repr_of(&it.attrs);
+                let tag = match it.variants.iter().all(|it| it.fields.is_empty()) {
+                    true => name.to_string(),
+                    false => format!("{}_Tag", name),
+                };
+                let variants = it
+                    .variants
+                    .iter()
+                    .map(|v| match discriminant(v) {
+                        Ok(Some(value)) => Ok(format!("    {}_{} = {},\n", name, v.ident, value)),
+                        Ok(None) => Ok(format!("    {}_{},\n", name, v.ident)),
+                        Err(it) => Err(it),
+                    })
+                    .collect::<Result<String, String>>()?;
+                // `#[repr(u8)]` fixes the size of the tag, C enums are `int`, C++ ones can have the underlying type
+                definition.body = match &repr.int {
+                    Some(int) => {
+                        let int = c_int_name(int)?;
+                        format!(
+                            "#ifdef __cplusplus\nenum {tag} : {int} {{\n#else\nenum {tag} {{\n#endif\n{variants}}};\n\
+                             #ifndef __cplusplus\ntypedef {int} {tag};\n#endif\n"
+                        )
+                    }
+                    None => format!("typedef enum {} {{\n{}}} {};\n", tag, variants, tag),
+                };
+                if tag != name {
+                    definition.forward = Some(format!("typedef {} {} {};", if repr.c { "struct" } else { "union" }, name, name));
+                    definition.body.push_str(&self.tagged_union(name, &tag, it.variants.iter(), repr.c, &mut definition.contains)?);
+                }
+            }
+            _ => return Err(format!("{} is not a struct, enum or union", address)),
+        }
+        Ok(definition)
+    }
+
+    /// data-carrying enum as in RFC 2195: `struct { tag; union { bodies } }` for `repr(C)`,
+    /// `union { tag; bodies starting with the tag }` for `repr(u8)` and other ints
+    fn tagged_union<'v>(
+        &self,
+        name: &str,
+        tag: &str,
+        variants: impl Iterator<Item = &'v Variant>,
+        repr_c: bool,
+        contains: &mut BTreeSet<GlobalIdent>,
+    ) -> Result<String, String> {
+        let mut out = String::new();
+        let mut members = vec![];
+        for v in variants {
+            if v.fields.is_empty() {
+                continue;
+            }
+            let body = format!("{}_{}_Body", name, v.ident);
+            let mut fields = self.fields(&v.fields, contains)?;
+            if !repr_c {
+                fields.insert_str(0, &format!("    {} tag;\n", tag));
+            }
+            writeln!(out, "\ntypedef struct {} {{\n{}}} {};", body, fields, body).unwrap();
+            members.push(format!("{} {};", body, snake_case(&v.ident.to_string())));
+        }
+        writeln!(out).unwrap();
+        if repr_c {
+            writeln!(out, "struct {} {{\n    {} tag;\n    union {{", name, tag).unwrap();
+            for it in members {
+                writeln!(out, "        {}", it).unwrap();
+            }
+            writeln!(out, "    }};\n}};").unwrap();
+        } else {
+            writeln!(out, "union {} {{\n    {} tag;", name, tag).unwrap();
+            for it in members {
+                writeln!(out, "    {}", it).unwrap();
+            }
+            writeln!(out, "}};").unwrap();
+        }
+        Ok(out)
+    }
+
+    /// `    int32_t x;` lines, tuple fields are named `_0`, `_1`...
+    fn fields(&self, fields: &Fields, contains: &mut BTreeSet<GlobalIdent>) -> Result<String, String> {
+        let mut out = String::new();
+        for (i, field) in fields.iter().enumerate() {
+            let name = field.ident.as_ref().map(|it| it.to_string()).unwrap_or_else(|| format!("_{}", i));
+            let ty = self.resolve(&field.ty);
+            contains.extend(by_value(&ty));
+            if let Some(doc) = doc_of(&field.attrs) {
+                for line in doc.lines() {
+                    writeln!(out, "    /* {} */", line).unwrap();
+                }
+            }
+            let declaration = self.declaration(&ty, &name).map_err(|it| format!("field {}: {}", name, it))?;
+            writeln!(out, "    {};", declaration).unwrap();
+        }
+        Ok(out)
+    }
+
+    fn prototype(&self, symbol: &str, item: &Item) -> Result<String, String> {
+        match item {
+            Item::Fn(it) => {
+                let mut params = vec![];
+                for (ty, name) in self.signature_types(item) {
+                    if name.is_empty() {
+                        continue;
+                    }
+                    params.push(self.declaration(&ty, &name).map_err(|it| format!("param {}: {}", name, it))?);
+                }
+                if it.sig.variadic.is_some() {
+                    params.push("...".to_string());
+                }
+                if params.is_empty() {
+                    params.push("void".to_string());
+                }
+                let output = self.db.expand_aliases(&self.db.resolve_return_type(&it.sig.output));
+                let declarator = format!("{}({})", symbol, params.join(", "));
+                let declaration = match output {
+                    ResolvedType::Never => format!("void {}", declarator),
+                    it => self.declaration(&it, &declarator).map_err(|it| format!("output: {}", it))?,
+                };
+                Ok(format!("{};", declaration))
+            }
+            Item::Static(it) => {
+                let ty = self.resolve(&it.ty);
+                let declaration = self.declaration(&ty, symbol)?;
+                let constant = match it.mutability {
+                    syn::StaticMutability::Mut(_) => "",
+                    _ => "const ",
+                };
+                Ok(format!("extern {}{};", constant, declaration))
+            }
+            _ => Err("not a function or a static".to_string()),
+        }
+    }
+
+    /// C declaration of `name` having the type: `int32_t x`, `const Foo *p`, `uint8_t buf[16]`,
+    /// `int32_t (*cb)(int32_t)`. an empty name gives an abstract declaration
+    fn declaration(&self, ty: &ResolvedType, name: &str) -> Result<String, String> {
+        let named = |base: &str| match name.is_empty() {
+            true => base.to_string(),
+            false => format!("{} {}", base, name),
+        };
+        match ty {
+            ResolvedType::Primitive(it) => Ok(named(c_primitive(*it).ok_or(format!("{} has no C counterpart", it))?)),
+            ResolvedType::Tuple(items) if items.is_empty() => Ok(named("void")),
+            ResolvedType::Ptr { mutable, elem } | ResolvedType::Reference { mutable, elem } => {
+                self.pointer(elem, *mutable, name)
+            }
+            ResolvedType::Array { elem, len } => {
+                let name = if name.starts_with('*') { format!("({})", name) } else { name.to_string() };
+                self.declaration(elem, &format!("{}[{}]", name, len))
+            }
+            ResolvedType::Fn { inputs, output } => {
+                let mut params = inputs.iter().map(|it| self.declaration(it, "")).collect::<Result<Vec<_>, _>>()?;
+                if params.is_empty() {
+                    params.push("void".to_string());
+                }
+                self.declaration(output, &format!("(*{})({})", name, params.join(", ")))
+            }
+            ResolvedType::Path { address, args } => {
+                let path = address.to_string();
+                match (path.as_str(), args.as_slice()) {
+                    ("core::ffi::c_void", []) => return Ok(named("void")),
+                    // null is `None`
+                    ("core::option::Option", [inner]) if is_nullable(inner) => return self.declaration(inner, name),
+                    ("alloc::boxed::Box" | "core::ptr::NonNull", [inner]) => return self.pointer(inner, true, name),
+                    _ => {}
+                }
+                if let Some((it, _)) = self.types.get(address) {
+                    return Ok(named(it));
+                }
+                if self.opaque.contains_key(address) {
+                    return Err(format!("{} can only be used behind a pointer", ty));
+                }
+                Err(format!("{} is not repr(C)", ty))
+            }
+            _ => Err(format!("{} has no C counterpart", ty)),
+        }
+    }
+
+    fn pointer(&self, elem: &ResolvedType, mutable: bool, name: &str) -> Result<String, String> {
+        let pointee = match elem {
+            ResolvedType::Path { address, .. } => self.opaque.get(address).cloned(),
+            _ => None,
+        };
+        let name = format!("*{}", name);
+        let declaration = match pointee {
+            Some(opaque) => format!("{} {}", opaque, name),
+            None if mutable => self.declaration(elem, &name)?,
+            None if is_pointer(elem) => self.declaration(elem, &format!("const {}", name))?,
+            None => format!("const {}", self.declaration(elem, &name)?),
+        };
+        Ok(match (mutable, is_pointer(elem), declaration.starts_with("const ")) {
+            (false, false, false) => format!("const {}", declaration),
+            _ => declaration,
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct Definition {
+    /// `typedef struct Foo Foo;`
+    forward: Option<String>,
+    body: String,
+    /// types contained by value, so defined before
+    contains: BTreeSet<GlobalIdent>,
+}
+
+fn repr_of(attrs: &[Attribute]) -> Repr {
+    let mut repr = Repr::default();
+    for attr in attrs.iter().filter(|it| it.path().is_ident("repr")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            let name = meta.path.get_ident().map(|it| it.to_string()).unwrap_or_default();
+            match name.as_str() {
+                "C" => repr.c = true,
+                "transparent" => repr.transparent = true,
+                "packed" | "align" => {
+                    repr.modified = true;
+                    if meta.input.peek(syn::token::Paren) {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        content.parse::<proc_macro2::TokenStream>()?;
+                    }
+                }
+                it if c_int_name(it).is_ok() => repr.int = Some(it.to_string()),
+                _ => {}
+            }
+            Ok(())
+        });
+    }
+    repr
+}
+
+/// name of the symbol if the item is exported unmangled
+fn exported_symbol(attrs: &[Attribute], ident: &syn::Ident) -> Option<String> {
+    for attr in attrs {
+        let path = attr.path();
+        if path.is_ident("no_mangle") {
+            return Some(ident.to_string());
+        }
+        if path.is_ident("export_name") {
+            if let syn::Meta::NameValue(it) = &attr.meta {
+                if let Expr::Lit(syn::ExprLit { lit: Lit::Str(it), .. }) = &it.value {
+                    return Some(it.value());
+                }
+            }
+        }
+    }
+    None
+}
+
+fn params(sig: &Signature) -> Vec<(String, syn::Type)> {
+    sig.inputs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, it)| match it {
+            FnArg::Typed(it) => {
+                let name = match &*it.pat {
+                    Pat::Ident(it) => it.ident.to_string(),
+                    _ => format!("arg{}", i),
+                };
+                Some((name, (*it.ty).clone()))
+            }
+            FnArg::Receiver(_) => None,
+        })
+        .collect()
+}
+
+/// literal value of `A = 5`, explicit discriminants have to be literals to be copied to C
+fn discriminant(variant: &Variant) -> Result<Option<String>, String> {
+    let Some((_, expr)) = &variant.discriminant else {
+        return Ok(None);
+    };
+    let negative = matches!(expr, Expr::Unary(it) if matches!(it.op, syn::UnOp::Neg(_)));
+    let literal = match expr {
+        Expr::Unary(it) if negative => &*it.expr,
+        it => it,
+    };
+    match literal {
+        Expr::Lit(syn::ExprLit { lit: Lit::Int(it), .. }) => {
+            Ok(Some(format!("{}{}", if negative { "-" } else { "" }, it.base10_digits())))
+        }
+        _ => Err(format!("discriminant of {} is not a literal", variant.ident)),
+    }
+}
+
+fn c_primitive(it: Primitive) -> Option<&'static str> {
+    Some(match it {
+        Primitive::Bool => "bool",
+        Primitive::Char => "uint32_t",
+        Primitive::Isize => "intptr_t",
+        Primitive::Usize => "uintptr_t",
+        Primitive::F32 => "float",
+        Primitive::F64 => "double",
+        it => return c_int_name(it.name()).ok(),
+    })
+}
+
+fn c_int_name(it: &str) -> Result<&'static str, String> {
+    Ok(match it {
+        "i8" => "int8_t",
+        "i16" => "int16_t",
+        "i32" => "int32_t",
+        "i64" => "int64_t",
+        "u8" => "uint8_t",
+        "u16" => "uint16_t",
+        "u32" => "uint32_t",
+        "u64" => "uint64_t",
+        "isize" => "intptr_t",
+        "usize" => "uintptr_t",
+        it => return Err(format!("{} has no C counterpart", it)),
+    })
+}
+
+fn is_std_pointer(address: &GlobalIdent) -> bool {
+    matches!(
+        address.to_string().as_str(),
+        "core::option::Option" | "alloc::boxed::Box" | "core::ptr::NonNull"
+    )
+}
+
+fn is_pointer(ty: &ResolvedType) -> bool {
+    match ty {
+        ResolvedType::Ptr { .. } | ResolvedType::Reference { .. } => true,
+        ResolvedType::Path { address, .. } => is_std_pointer(address),
+        _ => false,
+    }
+}
+
+/// types which `Option` of is a nullable pointer
+fn is_nullable(ty: &ResolvedType) -> bool {
+    match ty {
+        ResolvedType::Reference { .. } | ResolvedType::Fn { .. } => true,
+        ResolvedType::Path { address, .. } => {
+            matches!(address.to_string().as_str(), "alloc::boxed::Box" | "core::ptr::NonNull")
+        }
+        _ => false,
+    }
+}
+
+/// types the type contains by value (not behind a pointer)
+fn by_value(ty: &ResolvedType) -> Vec<GlobalIdent> {
+    match ty {
+        ResolvedType::Path { address, .. } if !is_std_pointer(address) => vec![address.clone()],
+        ResolvedType::Array { elem, .. } => by_value(elem),
+        _ => vec![],
+    }
+}
+
+fn item_attrs(item: &Item) -> &[Attribute] {
+    match item {
+        Item::Struct(it) => &it.attrs,
+        Item::Enum(it) => &it.attrs,
+        Item::Union(it) => &it.attrs,
+        Item::Fn(it) => &it.attrs,
+        Item::Static(it) => &it.attrs,
+        _ => &[],
+    }
+}
+
+fn write_doc(out: &mut String, doc: &Option<String>) {
+    let Some(doc) = doc else {
+        return;
+    };
+    writeln!(out, "/**").unwrap();
+    for line in doc.lines() {
+        writeln!(out, " * {}", line).unwrap();
+    }
+    writeln!(out, " */").unwrap();
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, ch) in name.char_indices() {
+        if ch.is_uppercase() && i > 0 {
+            out.push('_');
+        }
+        out.extend(ch.to_lowercase());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::compiled;
+
+    const SOURCE: &str = r#"
+        #[repr(C)]
+        pub struct Pair { pub a: i32, pub b: *const u8 }
+        #[repr(u8)]
+        pub enum Mode { On = 1, Off = 2 }
+        pub struct NotC { pub a: i32 }
+        #[no_mangle]
+        pub extern "C" fn pair_sum(p: *const Pair) -> i32 { 0 }
+        pub extern "C" fn not_exported() {}
+        #[no_mangle]
+        pub fn rust_abi() {}
+        pub mod a { pub mod geo { #[repr(C)] pub struct Point { pub x: f64 } } }
+        pub mod b { pub mod geo { #[repr(C)] pub struct Point { pub x: f64 } } }
+        #[no_mangle]
+        pub extern "C" fn distance(a: a::geo::Point, b: b::geo::Point) -> f64 { 0.0 }
+    "#;
+
+    #[test]
+    fn header_of_repr_c_items_and_exported_functions() {
+        let header = compiled(SOURCE).c_header("c");
+        let code = &header.code;
+        for expected in [
+            "#ifndef C_H\n#define C_H\n",
+            "typedef struct Pair Pair;",
+            "struct Pair {\n    int32_t a;\n    const uint8_t *b;\n};",
+            "    Mode_On = 1,\n    Mode_Off = 2,\n};",
+            "typedef uint8_t Mode;",
+            "int32_t pair_sum(const Pair *p);",
+        ] {
+            assert!(code.contains(expected), "{}\nis not in\n{}", expected, code);
+        }
+        for unexpected in ["NotC", "not_exported", "rust_abi"] {
+            assert!(!code.contains(unexpected), "{} is in\n{}", unexpected, code);
+        }
+    }
+
+    #[test]
+    fn same_named_types_are_prefixed() {
+        let code = compiled(SOURCE).c_header("c").code;
+        assert!(code.contains("double distance(AGeoPoint a, BGeoPoint b);"), "{}", code);
+    }
+}
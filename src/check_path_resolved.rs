@@ -3,7 +3,7 @@ use std::collections::BTreeSet;
 use quote::ToTokens;
 use syn::{
     visit::{visit_path, Visit},
-    Path, PathResolution,
+    Path, PathResolution, Type,
 };
 
 use crate::named_tree::NamedNode;
@@ -22,6 +22,12 @@ impl PathResolutionCheck {
         s.visit_path(path);
         s
     }
+
+    pub fn check_type(ty: &Type) -> PathResolutionCheck {
+        let mut s = Self::default();
+        s.visit_type(ty);
+        s
+    }
 }
 
 #[extend::ext]
@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+
+use crate::GlobalIdent;
+
+/// crates of the standard library, the generators map their types instead of declaring them
+pub(crate) const STD_CRATES: [&str; 3] = ["core", "alloc", "std"];
+
+/// the item is declared in `core`, `alloc` or `std`
+pub(crate) fn is_std(address: &GlobalIdent) -> bool {
+    STD_CRATES.contains(&address.first_part().to_string().as_str())
+}
+
+/// short names of the items, unique among them: the last segment, prefixed with as many parent segments
+/// as needed to tell apart the items of the same name. `a::geo::Point` and `b::geo::Point` become
+/// `AGeoPoint` and `BGeoPoint`, while `c::Line` stays `Line`. names may still collide only when the
+/// camel case of different paths is the same, like for `c::GeoPoint` and `c::geo::Point`
+pub(crate) fn unique_names<'a>(addresses: impl IntoIterator<Item = &'a GlobalIdent>) -> BTreeMap<GlobalIdent, String> {
+    let addresses = addresses.into_iter().map(|it| (it, it.to_parts().len())).collect::<Vec<_>>();
+    let mut depths = vec![1; addresses.len()];
+    loop {
+        let names = addresses
+            .iter()
+            .zip(depths.iter())
+            .map(|((address, _), depth)| prefixed_name(address, *depth))
+            .collect::<Vec<_>>();
+        let mut counts = BTreeMap::<&str, usize>::new();
+        for it in names.iter() {
+            *counts.entry(it).or_default() += 1;
+        }
+        let mut prefixed = false;
+        for (i, (_, len)) in addresses.iter().enumerate() {
+            if counts[names[i].as_str()] > 1 && depths[i] < *len {
+                depths[i] += 1;
+                prefixed = true;
+            }
+        }
+        if !prefixed {
+            return addresses.iter().map(|(it, _)| (*it).clone()).zip(names).collect();
+        }
+    }
+}
+
+/// the last `depth` segments of the address, camel-cased and joined
+fn prefixed_name(address: &GlobalIdent, depth: usize) -> String {
+    let parts = address.to_parts();
+    let parents = parts[parts.len() - depth..parts.len() - 1].iter().map(|it| camel_case(&it.to_string()));
+    parents.chain([address.last_part().to_string()]).collect()
+}
+
+pub(crate) fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|it| {
+            let mut chars = it.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::GlobalIdent;
+
+    use super::unique_names;
+
+    fn names(addresses: &[&str]) -> Vec<String> {
+        let addresses = addresses.iter().map(|it| GlobalIdent::from_qualified_name(it)).collect::<Vec<_>>();
+        let names = unique_names(addresses.iter());
+        addresses.iter().map(|it| names[it].clone()).collect()
+    }
+
+    #[test]
+    fn names_are_prefixed_until_unique() {
+        assert_eq!(names(&["c::Line", "c::geo::Point"]), ["Line", "Point"]);
+        assert_eq!(names(&["c::geo::Point", "c::world::Point"]), ["GeoPoint", "WorldPoint"]);
+        assert_eq!(
+            names(&["a::geo::Point", "b::geo::Point", "c::Line"]),
+            ["AGeoPoint", "BGeoPoint", "Line"]
+        );
+        assert_eq!(names(&["c::raw_geo::Point", "c::Point"]), ["RawGeoPoint", "CPoint"]);
+    }
+}
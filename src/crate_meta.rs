@@ -0,0 +1,261 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
+
+use crate::{Database, GlobalIdent};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Edition {
+    E2015,
+    E2018,
+    E2021,
+    E2024,
+}
+
+impl Edition {
+    pub fn from_name(name: &str) -> Option<Edition> {
+        match name {
+            "2015" => Some(Edition::E2015),
+            "2018" => Some(Edition::E2018),
+            "2021" => Some(Edition::E2021),
+            "2024" => Some(Edition::E2024),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Edition::E2015 => "2015",
+            Edition::E2018 => "2018",
+            Edition::E2021 => "2021",
+            Edition::E2024 => "2024",
+        }
+    }
+
+    /// reads `package.edition` of the manifest (following `edition.workspace = true`).
+    /// Cargo defaults to 2015 if the key is absent
+    pub fn from_manifest(manifest_path: &str) -> Option<Edition> {
+        let manifest = read_manifest(manifest_path)?;
+        match manifest.get("package")?.get("edition") {
+            None => Some(Edition::E2015),
+            Some(toml::Value::String(it)) => Edition::from_name(it),
+            Some(toml::Value::Table(it)) if it.get("workspace").and_then(|it| it.as_bool()) == Some(true) => {
+                workspace_edition(manifest_path)
+            }
+            Some(it) => {
+                println!("WARN: unexpected edition {} in {:?}", it, manifest_path);
+                None
+            }
+        }
+    }
+}
+
+/// `workspace.package.edition` of the closest workspace manifest containing the crate
+fn workspace_edition(manifest_path: &str) -> Option<Edition> {
+    let mut dir = Path::new(manifest_path).parent();
+    while let Some(it) = dir {
+        let workspace = read_manifest(&it.join("Cargo.toml").to_string_lossy())
+            .and_then(|it| it.get("workspace").cloned());
+        if let Some(workspace) = workspace {
+            let edition = workspace.get("package").and_then(|it| it.get("edition")).and_then(|it| it.as_str());
+            return Edition::from_name(edition?);
+        }
+        dir = it.parent();
+    }
+    None
+}
+
+fn read_manifest(manifest_path: &str) -> Option<toml::Table> {
+    let content = fs::read_to_string(manifest_path).ok()?;
+    match content.parse::<toml::Table>() {
+        Ok(it) => Some(it),
+        Err(err) => {
+            println!("WARN: cannot parse {:?}: {}", manifest_path, err);
+            None
+        }
+    }
+}
+
+/// `default` of the `[features]` of the manifest with the features it enables, transitively.
+/// `None` if there is no manifest
+pub fn default_features(manifest_path: &str) -> Option<BTreeSet<String>> {
+    let manifest = read_manifest(manifest_path)?;
+    let features = manifest.get("features").and_then(|it| it.as_table()).cloned().unwrap_or_default();
+    let mut enabled = BTreeSet::new();
+    let mut queue = vec!["default".to_string()];
+    while let Some(feature) = queue.pop() {
+        let Some(implied) = features.get(&feature) else {
+            // optional dependencies are implicit features
+            enabled.insert(feature);
+            continue;
+        };
+        if !enabled.insert(feature) {
+            continue;
+        }
+        for it in implied.as_array().into_iter().flatten().filter_map(|it| it.as_str()) {
+            // `dep:foo` enables only the dependency, `foo?/bar` - a feature of the dependency if it's enabled anyway
+            match it.split_once('/') {
+                _ if it.starts_with("dep:") => {}
+                Some((dep, _)) if !dep.ends_with('?') => queue.push(dep.to_string()),
+                Some(_) => {}
+                None => queue.push(it.to_string()),
+            }
+        }
+    }
+    if !features.contains_key("default") {
+        enabled.remove("default");
+    }
+    Some(enabled)
+}
+
+/// crate-level properties affecting name resolution
+#[derive(Debug, Clone)]
+pub struct CrateMeta {
+    pub edition: Edition,
+    pub no_std: bool,
+    pub no_core: bool,
+    /// module glob-imported into every module of the crate with the lowest priority
+    pub prelude: Option<GlobalIdent>,
+    /// names of `extern crate` items at the crate root (`na` of `extern crate nalgebra as na;`)
+    /// to crates they refer to. they are visible in every module of the crate
+    pub extern_prelude: BTreeMap<String, String>,
+}
+
+impl CrateMeta {
+    pub fn new(edition: Edition, no_std: bool, no_core: bool) -> Self {
+        let prelude = if no_core {
+            None
+        } else {
+            let std = if no_std { "core" } else { "std" };
+            let prelude = match edition {
+                Edition::E2015 => "rust_2015",
+                Edition::E2018 => "rust_2018",
+                Edition::E2021 => "rust_2021",
+                Edition::E2024 => "rust_2024",
+            };
+            Some(GlobalIdent::from_qualified_name(&format!("{}::prelude::{}", std, prelude)))
+        };
+        CrateMeta {
+            edition,
+            no_std,
+            no_core,
+            prelude,
+            extern_prelude: Default::default(),
+        }
+    }
+}
+
+impl Database {
+    pub fn prelude_of(&self, crate_: &str) -> Option<&GlobalIdent> {
+        self.crates.get(crate_)?.prelude.as_ref()
+    }
+
+    /// crate referred by `name` in the extern prelude of `crate_`
+    pub fn extern_crate_of(&self, crate_: &str, name: &str) -> Option<&String> {
+        self.crates.get(crate_)?.extern_prelude.get(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use syn::{FnArg, Item, ReturnType};
+
+    use crate::{Database, GlobalIdent};
+
+    use super::Edition;
+
+    fn temp_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("syg_{}_{}", name, std::process::id()));
+        for (path, content) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    fn edition_of(dir: &Path, crate_: &str) -> Option<Edition> {
+        Edition::from_manifest(&dir.join(crate_).join("Cargo.toml").to_string_lossy())
+    }
+
+    #[test]
+    fn edition_of_manifest() {
+        let dir = temp_dir(
+            "edition",
+            &[
+                ("Cargo.toml", "[workspace]\nmembers = [\"*\"]\n\n[workspace.package]\nedition = \"2018\"\n"),
+                ("plain/Cargo.toml", "[package]\nname = \"plain\"\nedition = \"2024\" # the latest\n"),
+                ("absent/Cargo.toml", "[package]\nname = \"absent\"\n"),
+                ("dotted/Cargo.toml", "[package]\nname = \"dotted\"\nedition.workspace = true\n"),
+                ("inline/Cargo.toml", "[package]\nname = \"inline\"\nedition = { workspace = true }\n"),
+                ("broken/Cargo.toml", "[package\n"),
+            ],
+        );
+        assert_eq!(edition_of(&dir, "plain"), Some(Edition::E2024));
+        assert_eq!(edition_of(&dir, "absent"), Some(Edition::E2015));
+        assert_eq!(edition_of(&dir, "dotted"), Some(Edition::E2018));
+        assert_eq!(edition_of(&dir, "inline"), Some(Edition::E2018));
+        assert_eq!(edition_of(&dir, "broken"), None);
+        assert_eq!(edition_of(&dir, "missing"), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// resolved types of the parameters and the result of the function
+    fn signature(db: &Database, name: &str) -> Vec<String> {
+        let binding = db.decls.find_value(GlobalIdent::from_qualified_name(name).to_parts()).unwrap();
+        let Some(Item::Fn(it)) = binding.non_type_ast.as_ref().and_then(|it| it.ast.as_ref()) else {
+            panic!("no fn {}", name);
+        };
+        let mut types = it
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|it| match it {
+                FnArg::Typed(it) => Some(db.resolve_type(&it.ty).to_string()),
+                FnArg::Receiver(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if let ReturnType::Type(_, ty) = &it.sig.output {
+            types.push(db.resolve_type(ty).to_string());
+        }
+        types
+    }
+
+    #[test]
+    fn prelude_of_edition() {
+        let mut db = Database::default();
+        db.add_crate_source("c", "pub fn run<F: Future>(f: F) -> Vec<F::Output> {}", Edition::E2024);
+        db.add_crate_source("nostd", "#![no_std]\npub fn first(it: Option<u8>) -> u8 {}", Edition::E2021);
+        db.compile();
+        assert_eq!(db.prelude_of("c"), Some(&GlobalIdent::from_qualified_name("std::prelude::rust_2024")));
+        assert_eq!(db.prelude_of("nostd"), Some(&GlobalIdent::from_qualified_name("core::prelude::rust_2021")));
+        assert_eq!(signature(&db, "c::run"), ["F", "alloc::vec::Vec<<F as core::future::Future>::Output>"]);
+        assert_eq!(signature(&db, "nostd::first"), ["core::option::Option<u8>", "u8"]);
+    }
+
+    #[test]
+    fn extern_crate_aliases() {
+        let mut db = Database::default();
+        db.add_crate_source(
+            "lin",
+            "pub struct Vector;\n#[macro_export]\nmacro_rules! made { () => { pub struct Made; } }",
+            Edition::E2021,
+        );
+        db.add_crate_source(
+            "c",
+            "#[macro_use]\nextern crate lin as la;\npub mod m { pub fn scale(v: la::Vector) {} }\nmade!();",
+            Edition::E2021,
+        );
+        db.compile();
+        assert_eq!(db.extern_crate_of("c", "la"), Some(&"lin".to_string()));
+        assert_eq!(signature(&db, "c::m::scale"), ["lin::Vector"]);
+        assert!(db.decls.find_value(GlobalIdent::from_qualified_name("c::Made").to_parts()).is_some());
+    }
+}
@@ -1,4 +1,4 @@
-use syn::{visit_mut::*, Item, ItemType};
+use syn::{visit_mut::*, Attribute, Expr, ExprLit, Item, ItemType, Lit, Meta};
 
 #[extend::ext]
 pub impl Item {
@@ -18,6 +18,25 @@ pub impl ItemType {
 	}
 }
 
+/// text of `///` comments (`#[doc = "..."]` attributes), `None` if there are none
+pub fn doc_of(attrs: &[Attribute]) -> Option<String> {
+	let lines = attrs
+		.iter()
+		.filter_map(|it| match &it.meta {
+			Meta::NameValue(it) if it.path.is_ident("doc") => match &it.value {
+				Expr::Lit(ExprLit { lit: Lit::Str(it), .. }) => Some(it.value()),
+				_ => None,
+			},
+			_ => None,
+		})
+		.map(|it| it.strip_prefix(' ').map(|it| it.to_string()).unwrap_or(it))
+		.collect::<Vec<_>>();
+	if lines.is_empty() {
+		return None;
+	}
+	Some(lines.join("\n"))
+}
+
 struct DedocVisit;
 
 impl VisitMut for DedocVisit {
@@ -0,0 +1,400 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display, Write},
+};
+
+use syn::{FnArg, ForeignItem, ImplItem, Item, Signature, TraitItem};
+
+use crate::{Ast, Database, GlobalIdent, ImportKind, Resolution, ResolvedType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DepEdgeKind {
+    /// struct, enum or union has a field of the type
+    Field,
+    /// function (or a method of the type or trait) takes the type
+    Param,
+    /// function (or a method of the type or trait) returns the type
+    Returns,
+    /// type implements the trait
+    Implements,
+    /// `use` binding re-exports the declaration
+    ReExport,
+    /// type alias stands for the type
+    Alias,
+}
+
+impl Display for DepEdgeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            DepEdgeKind::Field => "field",
+            DepEdgeKind::Param => "param",
+            DepEdgeKind::Returns => "returns",
+            DepEdgeKind::Implements => "implements",
+            DepEdgeKind::ReExport => "re-exports",
+            DepEdgeKind::Alias => "alias",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DepEdge {
+    pub from: GlobalIdent,
+    pub to: GlobalIdent,
+    pub kind: DepEdgeKind,
+}
+
+/// resolved dependencies between declarations, see `Database::dep_graph`
+#[derive(Debug, Clone, Default)]
+pub struct DepGraph {
+    pub nodes: BTreeSet<GlobalIdent>,
+    pub edges: BTreeSet<DepEdge>,
+}
+
+/// which part of the graph to export. empty filter keeps everything
+#[derive(Debug, Clone, Default)]
+pub struct DepGraphFilter {
+    /// keep only declarations of these crates
+    pub crates: BTreeSet<String>,
+    /// keep only declarations inside this module
+    pub module: Option<GlobalIdent>,
+    /// keep only declarations reachable from this one
+    pub root: Option<GlobalIdent>,
+    /// max number of edges from the root, unlimited if not set
+    pub depth: Option<usize>,
+}
+
+impl DepGraphFilter {
+    fn accepts(&self, address: &GlobalIdent) -> bool {
+        if !self.crates.is_empty() && !self.crates.contains(&address.first_part().to_string()) {
+            return false;
+        }
+        if let Some(module) = &self.module {
+            let (module, address) = (module.to_string(), address.to_string());
+            if address != module && !address.starts_with(&format!("{}::", module)) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl Database {
+    /// graph of declarations connected by the types their signatures mention
+    pub fn dep_graph(&self, filter: &DepGraphFilter) -> DepGraph {
+        let mut graph = DepGraph::default();
+        // `use` bindings, kept only if the declaration they re-export is kept
+        let mut reexports = BTreeSet::new();
+        self.decls.for_each(&mut |_, binding| {
+            for decl in binding.type_ast.iter().chain(binding.non_type_ast.iter()) {
+                if let Ast::Real(item) = &decl.ast {
+                    self.add_item_edges(&decl.address, item, &mut graph);
+                }
+            }
+            if binding.alias_for.is_empty() {
+                return;
+            }
+            for to in self.reexported(&binding.address, &binding.alias_for) {
+                graph.add_edge(&binding.address, &to, DepEdgeKind::ReExport);
+                reexports.insert(binding.address.clone());
+            }
+        });
+        graph.nodes.retain(|it| filter.accepts(it));
+        let nodes = graph.nodes.clone();
+        graph.edges.retain(|it| nodes.contains(&it.from) && nodes.contains(&it.to));
+        let sources = graph.edges.iter().map(|it| it.from.clone()).collect::<BTreeSet<_>>();
+        graph.nodes.retain(|it| !reexports.contains(it) || sources.contains(it));
+        if let Some(root) = &filter.root {
+            graph = graph.reachable_from(root, filter.depth);
+        }
+        graph
+    }
+
+    /// declarations (of both namespaces) the `use` binding refers to. paths of `use` may be relative to its mod
+    pub(crate) fn reexported(&self, address: &GlobalIdent, alias_for: &[(GlobalIdent, ImportKind)]) -> BTreeSet<GlobalIdent> {
+        let mut result = BTreeSet::new();
+        for (source, _) in alias_for.iter() {
+            let path = source.to_parts().iter().map(|it| it.to_string()).collect::<Vec<_>>();
+            for candidate in self.candidates(&address.parent(), &path) {
+                if candidate == *address {
+                    continue;
+                }
+                for resolution in [self.lookup_decl(&candidate), self.lookup_value_decl(&candidate)] {
+                    if let Resolution::Fully(it) = resolution {
+                        result.insert(it.address);
+                    }
+                }
+                if !result.is_empty() {
+                    return result;
+                }
+            }
+        }
+        result
+    }
+
+    fn add_item_edges(&self, address: &GlobalIdent, item: &Item, graph: &mut DepGraph) {
+        match item {
+            Item::Struct(it) => {
+                graph.nodes.insert(address.clone());
+                for field in it.fields.iter() {
+                    graph.add_type_edges(address, &self.resolve_type(&field.ty), DepEdgeKind::Field);
+                }
+            }
+            Item::Union(it) => {
+                graph.nodes.insert(address.clone());
+                for field in it.fields.named.iter() {
+                    graph.add_type_edges(address, &self.resolve_type(&field.ty), DepEdgeKind::Field);
+                }
+            }
+            Item::Enum(it) => {
+                graph.nodes.insert(address.clone());
+                for field in it.variants.iter().flat_map(|it| it.fields.iter()) {
+                    graph.add_type_edges(address, &self.resolve_type(&field.ty), DepEdgeKind::Field);
+                }
+            }
+            Item::Type(it) => {
+                graph.nodes.insert(address.clone());
+                graph.add_type_edges(address, &self.resolve_type(&it.ty), DepEdgeKind::Alias);
+            }
+            Item::Fn(it) => {
+                graph.nodes.insert(address.clone());
+                self.add_signature_edges(address, &it.sig, graph);
+            }
+            Item::Trait(it) => {
+                graph.nodes.insert(address.clone());
+                for item in it.items.iter() {
+                    if let TraitItem::Fn(it) = item {
+                        self.add_signature_edges(address, &it.sig, graph);
+                    }
+                }
+            }
+            Item::ForeignMod(it) => {
+                if let [ForeignItem::Fn(it)] = it.items.as_slice() {
+                    graph.nodes.insert(address.clone());
+                    self.add_signature_edges(address, &it.sig, graph);
+                }
+            }
+            // methods and trait impls are attributed to the self type
+            Item::Impl(it) => {
+                let ResolvedType::Path { address: self_ty, .. } = self.resolve_type(&it.self_ty) else {
+                    return;
+                };
+                if let Some((None, path, _)) = &it.trait_ {
+                    if let Some(trait_) = self.resolve_trait_ref(path) {
+                        graph.add_edge(&self_ty, &trait_.address, DepEdgeKind::Implements);
+                    }
+                }
+                for item in it.items.iter() {
+                    if let ImplItem::Fn(it) = item {
+                        self.add_signature_edges(&self_ty, &it.sig, graph);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn add_signature_edges(&self, from: &GlobalIdent, sig: &Signature, graph: &mut DepGraph) {
+        for arg in sig.inputs.iter() {
+            if let FnArg::Typed(it) = arg {
+                graph.add_type_edges(from, &self.resolve_type(&it.ty), DepEdgeKind::Param);
+            }
+        }
+        graph.add_type_edges(from, &self.resolve_return_type(&sig.output), DepEdgeKind::Returns);
+    }
+}
+
+impl DepGraph {
+    fn add_edge(&mut self, from: &GlobalIdent, to: &GlobalIdent, kind: DepEdgeKind) {
+        if from == to {
+            return;
+        }
+        self.nodes.insert(from.clone());
+        self.nodes.insert(to.clone());
+        self.edges.insert(DepEdge {
+            from: from.clone(),
+            to: to.clone(),
+            kind,
+        });
+    }
+
+    /// edges to every declaration mentioned by the type, including its args (`Vec<Node>` gives `Vec` and `Node`)
+    fn add_type_edges(&mut self, from: &GlobalIdent, ty: &ResolvedType, kind: DepEdgeKind) {
+        let mut mentioned = vec![];
+        ty.for_each(&mut |it| match it {
+            ResolvedType::Path { address, .. } => mentioned.push(address.clone()),
+            ResolvedType::TraitObject(traits) | ResolvedType::ImplTrait(traits) => {
+                mentioned.extend(traits.iter().map(|it| it.address.clone()))
+            }
+            _ => {}
+        });
+        for to in mentioned {
+            self.add_edge(from, &to, kind);
+        }
+    }
+
+    /// subgraph of the declarations reachable from the root by at most `depth` edges
+    pub fn reachable_from(&self, root: &GlobalIdent, depth: Option<usize>) -> DepGraph {
+        let mut outgoing: BTreeMap<&GlobalIdent, Vec<&DepEdge>> = BTreeMap::new();
+        for edge in self.edges.iter() {
+            outgoing.entry(&edge.from).or_default().push(edge);
+        }
+        let mut result = DepGraph::default();
+        if !self.nodes.contains(root) {
+            println!("WARN: {} is not in the graph", root);
+            return result;
+        }
+        result.nodes.insert(root.clone());
+        let mut front = vec![root];
+        let mut level = 0;
+        while !front.is_empty() && !matches!(depth, Some(it) if level >= it) {
+            let mut next = vec![];
+            for node in front {
+                for edge in outgoing.get(node).into_iter().flatten() {
+                    result.edges.insert((*edge).clone());
+                    if result.nodes.insert(edge.to.clone()) {
+                        next.push(&edge.to);
+                    }
+                }
+            }
+            front = next;
+            level += 1;
+        }
+        result
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "digraph syg {{").unwrap();
+        writeln!(out, "    node [shape=box];").unwrap();
+        for node in self.nodes.iter() {
+            writeln!(out, "    {};", dot_quote(&node.to_string())).unwrap();
+        }
+        for edge in self.edges.iter() {
+            writeln!(
+                out,
+                "    {} -> {} [label={}];",
+                dot_quote(&edge.from.to_string()),
+                dot_quote(&edge.to.to_string()),
+                dot_quote(&edge.kind.to_string())
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+        out
+    }
+
+    pub fn to_graphml(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+        writeln!(out, r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#).unwrap();
+        writeln!(out, r#"  <key id="kind" for="edge" attr.name="kind" attr.type="string"/>"#).unwrap();
+        writeln!(out, r#"  <graph id="syg" edgedefault="directed">"#).unwrap();
+        for node in self.nodes.iter() {
+            writeln!(out, r#"    <node id="{}"/>"#, xml_escape(&node.to_string())).unwrap();
+        }
+        for edge in self.edges.iter() {
+            writeln!(
+                out,
+                r#"    <edge source="{}" target="{}"><data key="kind">{}</data></edge>"#,
+                xml_escape(&edge.from.to_string()),
+                xml_escape(&edge.to.to_string()),
+                edge.kind
+            )
+            .unwrap();
+        }
+        writeln!(out, "  </graph>").unwrap();
+        writeln!(out, "</graphml>").unwrap();
+        out
+    }
+}
+
+fn dot_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{test_support::compiled, GlobalIdent};
+
+    use super::{DepGraph, DepGraphFilter};
+
+    const SOURCE: &str = "
+        pub mod shapes {
+            pub struct Point { pub x: f32 }
+            pub struct Line { pub from: Point, pub to: Point }
+            pub type Segment = Line;
+        }
+        pub use crate::shapes::Line;
+        pub trait Draw { fn bounds(&self) -> shapes::Point; }
+        impl Draw for shapes::Line { fn bounds(&self) -> shapes::Point { todo!() } }
+        pub fn render(line: &Line) {}
+    ";
+
+    fn crate_c() -> DepGraphFilter {
+        DepGraphFilter {
+            crates: BTreeSet::from(["c".to_string()]),
+            ..Default::default()
+        }
+    }
+
+    fn edges(graph: &DepGraph) -> Vec<String> {
+        graph.edges.iter().map(|it| format!("{} {} {}", it.from, it.kind, it.to)).collect()
+    }
+
+    #[test]
+    fn edges_of_signatures() {
+        let graph = compiled(SOURCE).dep_graph(&crate_c());
+        assert_eq!(
+            edges(&graph),
+            [
+                "c::Draw returns c::shapes::Point",
+                "c::Line re-exports c::shapes::Line",
+                "c::render param c::shapes::Line",
+                "c::shapes::Line implements c::Draw",
+                "c::shapes::Line field c::shapes::Point",
+                "c::shapes::Line returns c::shapes::Point",
+                "c::shapes::Segment alias c::shapes::Line",
+            ]
+        );
+    }
+
+    #[test]
+    fn filters_and_exports() {
+        let db = compiled(SOURCE);
+        let graph = db.dep_graph(&DepGraphFilter {
+            root: Some(GlobalIdent::from_qualified_name("c::render")),
+            depth: Some(1),
+            ..crate_c()
+        });
+        assert_eq!(edges(&graph), ["c::render param c::shapes::Line"]);
+        assert!(graph
+            .to_dot()
+            .contains(r#""c::render" -> "c::shapes::Line" [label="param"];"#));
+        assert!(graph.to_graphml().contains(
+            r#"<edge source="c::render" target="c::shapes::Line"><data key="kind">param</data></edge>"#
+        ));
+
+        let graph = db.dep_graph(&DepGraphFilter {
+            module: Some(GlobalIdent::from_qualified_name("c::shapes")),
+            ..Default::default()
+        });
+        assert_eq!(
+            edges(&graph),
+            [
+                "c::shapes::Line field c::shapes::Point",
+                "c::shapes::Line returns c::shapes::Point",
+                "c::shapes::Segment alias c::shapes::Line",
+            ]
+        );
+    }
+}
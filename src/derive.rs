@@ -0,0 +1,236 @@
+use std::collections::BTreeMap;
+
+use quote::{quote, ToTokens};
+use syn::{
+    parse2, parse_quote, parse_str, punctuated::Punctuated, visit::Visit, Generics, Ident, Item, ItemImpl, Lifetime,
+    LifetimeParam, Path, Token,
+};
+
+use crate::{Database, GlobalIdent};
+
+/// derive macros by `crate::Name`, where the crate is the one the macro is imported from, and the traits
+/// they implement. lifetimes of the trait path become impl params
+pub fn default_derives() -> BTreeMap<String, String> {
+    [
+        ("core::Clone", "core::clone::Clone"),
+        ("core::Copy", "core::marker::Copy"),
+        ("core::Debug", "core::fmt::Debug"),
+        ("core::Default", "core::default::Default"),
+        ("core::PartialEq", "core::cmp::PartialEq"),
+        ("core::Eq", "core::cmp::Eq"),
+        ("core::PartialOrd", "core::cmp::PartialOrd"),
+        ("core::Ord", "core::cmp::Ord"),
+        ("core::Hash", "core::hash::Hash"),
+        ("serde::Serialize", "serde::Serialize"),
+        ("serde::Deserialize", "serde::Deserialize<'de>"),
+        ("serde_derive::Serialize", "serde::Serialize"),
+        ("serde_derive::Deserialize", "serde::Deserialize<'de>"),
+        ("fyrox::Reflect", "fyrox_core::reflect::Reflect"),
+        ("fyrox::Visit", "fyrox_core::visitor::Visit"),
+        ("fyrox_core::Reflect", "fyrox_core::reflect::Reflect"),
+        ("fyrox_core::Visit", "fyrox_core::visitor::Visit"),
+    ]
+    .into_iter()
+    .map(|(derive, trait_)| (derive.to_string(), trait_.to_string()))
+    .collect()
+}
+
+impl Database {
+    /// impls generated by `#[derive(...)]` of the struct, enum or union in `mod_`, bounding every type param by
+    /// the trait. must be called after the `use` items of the mod are added
+    pub(crate) fn derived_impls(&self, item: &Item, mod_: &GlobalIdent) -> Vec<ItemImpl> {
+        let (attrs, ident, generics) = match item {
+            Item::Struct(it) => (&it.attrs, &it.ident, &it.generics),
+            Item::Enum(it) => (&it.attrs, &it.ident, &it.generics),
+            Item::Union(it) => (&it.attrs, &it.ident, &it.generics),
+            _ => return vec![],
+        };
+        let mut impls = vec![];
+        for attr in attrs.iter().filter(|it| it.path().is_ident("derive")) {
+            let derives = match attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated) {
+                Ok(it) => it,
+                Err(err) => {
+                    println!("WARN: cannot parse {}: {}", attr.to_token_stream(), err);
+                    continue;
+                }
+            };
+            for derive in derives.iter() {
+                let name = format!("{}::{}", self.derive_crate(derive, mod_), derive.segments.last().unwrap().ident);
+                let Some(trait_) = self.derives.get(&name) else {
+                    println!("    skip unknown derive {} of {}", name, ident);
+                    continue;
+                };
+                let trait_ = match parse_str::<Path>(trait_) {
+                    Ok(it) => it,
+                    Err(err) => {
+                        println!("WARN: bad trait {:?} of derive {}: {}", trait_, name, err);
+                        continue;
+                    }
+                };
+                let Some(it) = derived_impl(ident, generics, &trait_) else {
+                    continue;
+                };
+                impls.push(it);
+            }
+        }
+        impls
+    }
+
+    /// crate the derive macro comes from: the first segment of its path or of its `use`, or the source of a glob
+    /// import of the mod which has a known derive of this name. `core` for the derives of the prelude
+    fn derive_crate(&self, derive: &Path, mod_: &GlobalIdent) -> String {
+        let crate_ = mod_.first_part().to_string();
+        let name = derive.segments.last().unwrap().ident.to_string();
+        let source = if derive.segments.len() > 1 {
+            let first = derive.segments[0].ident.to_string();
+            match first.as_str() {
+                "crate" | "self" | "super" => crate_,
+                _ => self.extern_crate_of(&crate_, &first).cloned().unwrap_or(first),
+            }
+        } else if let Some((import, _)) = self
+            .decls
+            .find_value(GlobalIdent::from_mod_and_name(mod_, &name).to_parts())
+            .and_then(|it| it.alias_for.first())
+        {
+            import.first_part().to_string()
+        } else {
+            let globs = self.decls.find_value(mod_.to_parts()).map(|it| &it.wildcard_alias_for);
+            globs
+                .into_iter()
+                .flatten()
+                .map(|it| it.first_part().to_string())
+                .find(|it| self.derives.contains_key(&format!("{}::{}", it, name)))
+                .unwrap_or("core".to_string())
+        };
+        match source.as_str() {
+            "std" | "alloc" => "core".to_string(),
+            _ => source,
+        }
+    }
+}
+
+fn derived_impl(ident: &Ident, generics: &Generics, trait_: &Path) -> Option<ItemImpl> {
+    let mut impl_generics = generics.clone();
+    for param in impl_generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#trait_));
+    }
+    let mut lifetimes = TraitLifetimes::default();
+    lifetimes.visit_path(trait_);
+    for lifetime in lifetimes.0.into_iter().rev() {
+        if !impl_generics.lifetimes().any(|it| it.lifetime == lifetime) {
+            impl_generics.params.insert(0, LifetimeParam::new(lifetime).into());
+        }
+    }
+    let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let ast = quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_ for #ident #ty_generics #where_clause {}
+    };
+    match parse2::<ItemImpl>(ast.clone()) {
+        Ok(it) => Some(it),
+        Err(err) => {
+            println!("WARN: cannot derive {} for {}: {}: {}", trait_.to_token_stream(), ident, err, ast);
+            None
+        }
+    }
+}
+
+/// lifetimes like `'de` in `serde::Deserialize<'de>`
+#[derive(Default)]
+struct TraitLifetimes(Vec<Lifetime>);
+
+impl Visit<'_> for TraitLifetimes {
+    fn visit_lifetime(&mut self, i: &Lifetime) {
+        if !self.0.contains(i) {
+            self.0.push(i.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use quote::ToTokens;
+    use syn::Item;
+
+    use crate::{crate_meta::Edition, Database, GlobalIdent};
+
+    /// `Type: Trait` of the derived impls in the crate
+    fn derived(source: &str) -> BTreeSet<String> {
+        let mut db = Database::default();
+        db.add_crate_source("c", source, Edition::E2021);
+        let mut impls = BTreeSet::new();
+        db.decls.for_each(&mut |_, binding| {
+            if let Some(Item::Impl(it)) = binding.non_type_ast.as_ref().and_then(|it| it.ast.as_ref()) {
+                let (_, trait_, _) = it.trait_.as_ref().unwrap();
+                impls.insert(format!("{}: {}", it.self_ty.to_token_stream(), trait_.to_token_stream()).replace(' ', ""));
+            }
+        });
+        impls
+    }
+
+    #[test]
+    fn derives_of_prelude_and_imports() {
+        let impls = derived(
+            "
+            use serde::Serialize;
+            #[derive(Clone, std::fmt::Debug, Serialize, serde::Deserialize)]
+            pub struct A;
+            #[derive(Hash)]
+            pub struct B;
+            use std::hash::Hash;
+            ",
+        );
+        let expected = [
+            "A: core::clone::Clone",
+            "A: core::fmt::Debug",
+            "A: serde::Serialize",
+            "A: serde::Deserialize<'de>",
+            "B: core::hash::Hash",
+        ];
+        assert_eq!(impls, expected.iter().map(|it| it.replace(' ', "")).collect());
+    }
+
+    #[test]
+    fn derives_of_glob_imports() {
+        let impls = derived(
+            "
+            mod game {
+                use fyrox::core::reflect::prelude::*;
+                #[derive(Reflect)]
+                pub struct G;
+            }
+            ",
+        );
+        assert_eq!(impls, ["G:fyrox_core::reflect::Reflect".to_string()].into());
+    }
+
+    #[test]
+    fn derives_of_other_crates_are_skipped() {
+        let impls = derived(
+            "
+            mod other {
+                use bevy_reflect::Reflect;
+                use my_serde::*;
+                #[derive(Reflect, Serialize, Custom)]
+                pub struct O;
+            }
+            ",
+        );
+        assert_eq!(impls, BTreeSet::new());
+    }
+
+    #[test]
+    fn malformed_trait_of_derive_is_skipped() {
+        let mut db = Database::default();
+        db.derives.insert("core::Broken".to_string(), "core::Broken<".to_string());
+        let impls = db.derived_impls(
+            &syn::parse_str("#[derive(Broken, Clone)]\npub struct A;").unwrap(),
+            &GlobalIdent::from_qualified_name("c"),
+        );
+        let traits = impls.iter().map(|it| it.trait_.as_ref().unwrap().1.to_token_stream().to_string()).collect::<Vec<_>>();
+        assert_eq!(traits, ["core :: clone :: Clone"]);
+    }
+}
@@ -1,34 +1,519 @@
-use quote::{quote, ToTokens};
-use syn::{punctuated::Punctuated, visit_mut::VisitMut, Attribute, Item};
-use to_vec::ToVec;
+use std::collections::BTreeSet;
 
+use quote::ToTokens;
+use syn::{
+	braced, parse::{ParseStream, Parser}, parse_quote, punctuated::Punctuated, visit_mut::{self, VisitMut}, Attribute, Expr, ExprLit, Fields, ForeignItem, ImplItem, Item, Lit, Meta, Token, TraitItem
+};
 
-pub struct DeleteByCfg;
+use crate::{crate_meta::default_features, Database};
 
-fn should_be_ignored(attrs: &[Attribute]) -> bool {
-	attrs.iter().any(|it| it.to_token_stream().to_string() == quote! {#[cfg(target_arch = "wasm32")]}.to_string())
+
+/// configuration options considered set when evaluating `#[cfg(...)]` and `#[cfg_attr(...)]`
+#[derive(Debug, Clone, Default)]
+pub struct CfgSet {
+	/// options like `unix` or `debug_assertions`
+	pub flags: BTreeSet<String>,
+	/// options like `target_os = "linux"`
+	pub values: BTreeSet<(String, String)>,
+	/// enabled features of the crate. `None` if they are unknown, then `feature = "..."` is undecided
+	pub features: Option<BTreeSet<String>>,
 }
 
-fn visit_items(items: &mut Vec<Item>) {
-	for i in (0..items.len()).rev() {
-		let ignore = match items.get(i).unwrap() {
-			Item::Trait(it) => should_be_ignored(&it.attrs),
-			Item::Type(it) => should_be_ignored(&it.attrs),
-			_ => false,
+impl CfgSet {
+	/// options of the platform syg is running on
+	pub fn host() -> Self {
+		let mut cfg = CfgSet::default();
+		cfg.set_flag(std::env::consts::FAMILY);
+		cfg.set_value("target_family", std::env::consts::FAMILY);
+		cfg.set_value("target_os", std::env::consts::OS);
+		cfg.set_value("target_arch", std::env::consts::ARCH);
+		cfg.set_value("target_pointer_width", &usize::BITS.to_string());
+		cfg.set_value("target_endian", if cfg!(target_endian = "little") { "little" } else { "big" });
+		let env = if cfg!(target_env = "gnu") {
+			"gnu"
+		} else if cfg!(target_env = "musl") {
+			"musl"
+		} else if cfg!(target_env = "msvc") {
+			"msvc"
+		} else {
+			""
+		};
+		cfg.set_value("target_env", env);
+		let vendor = if cfg!(target_vendor = "apple") {
+			"apple"
+		} else if cfg!(target_vendor = "pc") {
+			"pc"
+		} else {
+			"unknown"
 		};
-		if ignore {
-			items.remove(i);
+		cfg.set_value("target_vendor", vendor);
+		for width in ["8", "16", "32", "64", "ptr"] {
+			cfg.set_value("target_has_atomic", width);
+		}
+		cfg.set_value("panic", "unwind");
+		cfg
+	}
+
+	pub fn set_flag(&mut self, name: &str) {
+		self.flags.insert(name.to_string());
+	}
+
+	pub fn set_value(&mut self, key: &str, value: &str) {
+		self.values.insert((key.to_string(), value.to_string()));
+	}
+
+	/// evaluates a configuration predicate. unknown options are considered unset.
+	/// `None` if the result depends on features which are unknown
+	pub fn eval(&self, predicate: &Meta) -> Option<bool> {
+		match predicate {
+			Meta::Path(path) => Some(self.flags.contains(&path.to_token_stream().to_string())),
+			Meta::NameValue(it) => match &it.value {
+				Expr::Lit(ExprLit { lit: Lit::Str(value), .. }) => {
+					let key = it.path.to_token_stream().to_string();
+					if key == "feature" {
+						return self.features.as_ref().map(|it| it.contains(&value.value()));
+					}
+					Some(self.values.contains(&(key, value.value())))
+				}
+				_ => Some(false),
+			},
+			Meta::List(it) => {
+				let args = match Punctuated::<Meta, Token![,]>::parse_terminated.parse2(it.tokens.clone()) {
+					Ok(args) => args,
+					Err(err) => {
+						println!("WARN: cannot parse cfg predicate {}: {}", it.to_token_stream(), err);
+						return Some(false);
+					}
+				};
+				let results = args.iter().map(|it| self.eval(it)).collect::<Vec<_>>();
+				match it.path.to_token_stream().to_string().as_str() {
+					"all" if results.contains(&Some(false)) => Some(false),
+					"all" if results.contains(&None) => None,
+					"all" => Some(true),
+					"any" if results.contains(&Some(true)) => Some(true),
+					"any" if results.contains(&None) => None,
+					"any" => Some(false),
+					"not" => match results.as_slice() {
+						[it] => it.map(|it| !it),
+						_ => Some(false),
+					},
+					other => {
+						println!("WARN: unknown cfg predicate {}", other);
+						Some(false)
+					}
+				}
+			}
+		}
+	}
+
+	/// expands `#[cfg_attr(...)]` in place and checks `#[cfg(...)]`.
+	/// undecided items are kept, undecided attributes are not applied
+	pub fn retain(&self, attrs: &mut Vec<Attribute>) -> bool {
+		self.expand_cfg_attrs(attrs);
+		attrs.iter().all(|attr| match &attr.meta {
+			Meta::List(it) if it.path.is_ident("cfg") => match it.parse_args::<Meta>() {
+				Ok(predicate) => self.eval(&predicate) != Some(false),
+				Err(err) => {
+					println!("WARN: cannot parse {}: {}", attr.to_token_stream(), err);
+					true
+				}
+			},
+			_ => true,
+		})
+	}
+
+	fn expand_cfg_attrs(&self, attrs: &mut Vec<Attribute>) {
+		if !attrs.iter().any(|it| it.path().is_ident("cfg_attr")) {
+			return;
+		}
+		let mut expanded = vec![];
+		for attr in attrs.drain(..) {
+			let Meta::List(list) = &attr.meta else {
+				expanded.push(attr);
+				continue;
+			};
+			if !list.path.is_ident("cfg_attr") {
+				expanded.push(attr);
+				continue;
+			}
+			let args = match Punctuated::<Meta, Token![,]>::parse_terminated.parse2(list.tokens.clone()) {
+				Ok(args) => args,
+				Err(err) => {
+					println!("WARN: cannot parse {}: {}", attr.to_token_stream(), err);
+					continue;
+				}
+			};
+			let mut args = args.into_iter();
+			let Some(predicate) = args.next() else {
+				continue;
+			};
+			if self.eval(&predicate) != Some(true) {
+				continue;
+			}
+			let mut inner = args
+				.map(|meta| Attribute {
+					meta,
+					..attr.clone()
+				})
+				.collect::<Vec<_>>();
+			// nested cfg_attr
+			self.expand_cfg_attrs(&mut inner);
+			expanded.extend(inner);
+		}
+		*attrs = expanded;
+	}
+}
+
+impl Database {
+	/// enables exactly these features of the crate instead of the default ones from its manifest.
+	/// must be called before the crate is added
+	pub fn set_features(&mut self, crate_: &str, features: &[&str]) {
+		self.features
+			.insert(crate_.replace('-', "_"), features.iter().map(|it| it.to_string()).collect());
+	}
+
+	/// options for the crate: features set by `set_features`, otherwise the default ones of the manifest,
+	/// otherwise unknown
+	pub(crate) fn cfg_of(&self, crate_: &str, manifest_path: Option<&str>) -> CfgSet {
+		let mut cfg = self.cfg.clone();
+		cfg.features = match self.features.get(&crate_.replace('-', "_")) {
+			Some(it) => Some(it.clone()),
+			None => manifest_path.and_then(default_features),
+		};
+		cfg
+	}
+}
+
+/// removes items, fields and variants disabled by `#[cfg(...)]`.
+/// `cfg_if! { ... }` invocations are expanded to the items of the branches, so they are checked too
+pub struct DeleteByCfg<'a> {
+	pub cfg: &'a CfgSet,
+}
+
+fn item_attrs_mut(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+	match item {
+		Item::Const(it) => Some(&mut it.attrs),
+		Item::Enum(it) => Some(&mut it.attrs),
+		Item::ExternCrate(it) => Some(&mut it.attrs),
+		Item::Fn(it) => Some(&mut it.attrs),
+		Item::ForeignMod(it) => Some(&mut it.attrs),
+		Item::Impl(it) => Some(&mut it.attrs),
+		Item::Macro(it) => Some(&mut it.attrs),
+		Item::Mod(it) => Some(&mut it.attrs),
+		Item::Static(it) => Some(&mut it.attrs),
+		Item::Struct(it) => Some(&mut it.attrs),
+		Item::Trait(it) => Some(&mut it.attrs),
+		Item::TraitAlias(it) => Some(&mut it.attrs),
+		Item::Type(it) => Some(&mut it.attrs),
+		Item::Union(it) => Some(&mut it.attrs),
+		Item::Use(it) => Some(&mut it.attrs),
+		_ => None,
+	}
+}
+
+fn impl_item_attrs_mut(item: &mut ImplItem) -> Option<&mut Vec<Attribute>> {
+	match item {
+		ImplItem::Const(it) => Some(&mut it.attrs),
+		ImplItem::Fn(it) => Some(&mut it.attrs),
+		ImplItem::Type(it) => Some(&mut it.attrs),
+		ImplItem::Macro(it) => Some(&mut it.attrs),
+		_ => None,
+	}
+}
+
+fn trait_item_attrs_mut(item: &mut TraitItem) -> Option<&mut Vec<Attribute>> {
+	match item {
+		TraitItem::Const(it) => Some(&mut it.attrs),
+		TraitItem::Fn(it) => Some(&mut it.attrs),
+		TraitItem::Type(it) => Some(&mut it.attrs),
+		TraitItem::Macro(it) => Some(&mut it.attrs),
+		_ => None,
+	}
+}
+
+fn foreign_item_attrs_mut(item: &mut ForeignItem) -> Option<&mut Vec<Attribute>> {
+	match item {
+		ForeignItem::Fn(it) => Some(&mut it.attrs),
+		ForeignItem::Static(it) => Some(&mut it.attrs),
+		ForeignItem::Type(it) => Some(&mut it.attrs),
+		ForeignItem::Macro(it) => Some(&mut it.attrs),
+		_ => None,
+	}
+}
+
+fn retain_punctuated<T, P: Default>(cfg: &CfgSet, items: &mut Punctuated<T, P>, attrs: fn(&mut T) -> &mut Vec<Attribute>) {
+	let mut retained = Punctuated::new();
+	for mut it in std::mem::take(items).into_iter() {
+		if cfg.retain(attrs(&mut it)) {
+			retained.push(it);
+		}
+	}
+	*items = retained;
+}
+
+/// branches of `cfg_if!` with their predicates, `None` for `else`
+fn parse_cfg_if(input: ParseStream) -> syn::Result<Vec<(Option<Meta>, Vec<Item>)>> {
+	let mut branches = vec![];
+	let mut conditional = true;
+	loop {
+		let predicate = if conditional {
+			input.parse::<Token![if]>()?;
+			let attrs = input.call(Attribute::parse_outer)?;
+			match attrs.as_slice() {
+				[attr] if attr.path().is_ident("cfg") => Some(attr.parse_args::<Meta>()?),
+				_ => return Err(input.error("expected `#[cfg(...)]`")),
+			}
+		} else {
+			None
+		};
+		let content;
+		braced!(content in input);
+		let mut items = vec![];
+		while !content.is_empty() {
+			items.push(content.parse()?);
+		}
+		branches.push((predicate, items));
+		if !conditional || input.is_empty() {
+			return Ok(branches);
 		}
+		input.parse::<Token![else]>()?;
+		conditional = input.peek(Token![if]);
 	}
 }
 
-impl VisitMut for DeleteByCfg {
+fn is_cfg_if(item: &Item) -> bool {
+	matches!(item, Item::Macro(it) if it.mac.path.segments.last().is_some_and(|it| it.ident == "cfg_if"))
+}
+
+impl DeleteByCfg<'_> {
+	/// replaces `cfg_if!` with the items of all its branches, each one under the `#[cfg(...)]` of its branch
+	/// and not of the previous ones. the macro comes from the `cfg-if` crate, but `std` uses it a lot
+	fn expand_cfg_if(&self, items: &mut Vec<Item>) {
+		while items.iter().any(is_cfg_if) {
+			for item in std::mem::take(items) {
+				let Item::Macro(mac) = &item else {
+					items.push(item);
+					continue;
+				};
+				if !is_cfg_if(&item) {
+					items.push(item);
+					continue;
+				}
+				let branches = match parse_cfg_if.parse2(mac.mac.tokens.clone()) {
+					Ok(it) => it,
+					Err(err) => {
+						println!("WARN: cannot expand {}: {}", mac.mac.path.to_token_stream(), err);
+						continue;
+					}
+				};
+				let mut previous: Vec<Meta> = vec![];
+				for (predicate, branch_items) in branches {
+					let cfg: Meta = match (&predicate, previous.is_empty()) {
+						(Some(it), true) => it.clone(),
+						(Some(it), false) => parse_quote!(all(not(any(#(#previous),*)), #it)),
+						(None, _) => parse_quote!(not(any(#(#previous),*))),
+					};
+					for mut it in branch_items {
+						match item_attrs_mut(&mut it) {
+							Some(attrs) => {
+								// the macro may be under a cfg itself
+								attrs.extend(mac.attrs.iter().cloned());
+								attrs.push(parse_quote!(#[cfg(#cfg)]));
+							}
+							None if self.cfg.eval(&cfg) == Some(false) => continue,
+							None => {}
+						}
+						items.push(it);
+					}
+					previous.extend(predicate);
+				}
+			}
+		}
+	}
+
+	fn retain_vec<T>(&self, items: &mut Vec<T>, attrs: fn(&mut T) -> Option<&mut Vec<Attribute>>) {
+		items.retain_mut(|it| match attrs(it) {
+			Some(attrs) => self.cfg.retain(attrs),
+			None => true,
+		});
+	}
+}
+
+impl VisitMut for DeleteByCfg<'_> {
+	fn visit_file_mut(&mut self, i: &mut syn::File) {
+		// crate attributes like `#![cfg_attr(not(feature = "std"), no_std)]`
+		self.cfg.retain(&mut i.attrs);
+		self.expand_cfg_if(&mut i.items);
+		self.retain_vec(&mut i.items, item_attrs_mut);
+		visit_mut::visit_file_mut(self, i);
+	}
+
 	fn visit_item_mod_mut(&mut self, i: &mut syn::ItemMod) {
 		if let Some((_, items)) = &mut i.content {
-			visit_items(items);
+			self.expand_cfg_if(items);
+			self.retain_vec(items, item_attrs_mut);
 		}
+		visit_mut::visit_item_mod_mut(self, i);
 	}
-	fn visit_file_mut(&mut self, i: &mut syn::File) {
-		visit_items(&mut i.items);
+
+	fn visit_item_impl_mut(&mut self, i: &mut syn::ItemImpl) {
+		self.retain_vec(&mut i.items, impl_item_attrs_mut);
+		visit_mut::visit_item_impl_mut(self, i);
+	}
+
+	fn visit_item_trait_mut(&mut self, i: &mut syn::ItemTrait) {
+		self.retain_vec(&mut i.items, trait_item_attrs_mut);
+		visit_mut::visit_item_trait_mut(self, i);
+	}
+
+	fn visit_item_foreign_mod_mut(&mut self, i: &mut syn::ItemForeignMod) {
+		self.retain_vec(&mut i.items, foreign_item_attrs_mut);
+		visit_mut::visit_item_foreign_mod_mut(self, i);
+	}
+
+	fn visit_item_enum_mut(&mut self, i: &mut syn::ItemEnum) {
+		retain_punctuated(self.cfg, &mut i.variants, |it| &mut it.attrs);
+		visit_mut::visit_item_enum_mut(self, i);
+	}
+
+	fn visit_fields_mut(&mut self, i: &mut Fields) {
+		match i {
+			Fields::Named(it) => retain_punctuated(self.cfg, &mut it.named, |it| &mut it.attrs),
+			Fields::Unnamed(it) => retain_punctuated(self.cfg, &mut it.unnamed, |it| &mut it.attrs),
+			Fields::Unit => {}
+		}
+		visit_mut::visit_fields_mut(self, i);
 	}
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+	use syn::{parse_str, Meta};
+
+	use crate::{crate_meta::Edition, Database, GlobalIdent};
+
+	use super::CfgSet;
+
+	const SOURCE: &str = r#"
+		#![cfg_attr(not(feature = "std"), no_std)]
+		#[cfg(feature = "serde")]
+		pub struct WithSerde;
+		#[cfg(not(feature = "serde"))]
+		pub struct WithoutSerde;
+		#[cfg(all(feature = "serde", target_os = "none"))]
+		pub struct Never;
+	"#;
+
+	fn eval(cfg: &CfgSet, predicate: &str) -> Option<bool> {
+		cfg.eval(&parse_str::<Meta>(predicate).unwrap())
+	}
+
+	fn has(db: &Database, name: &str) -> bool {
+		db.decls.find_value(GlobalIdent::from_qualified_name(name).to_parts()).is_some_and(|it| it.type_ast.is_some())
+	}
+
+	#[test]
+	fn unknown_features_are_undecided() {
+		let cfg = CfgSet::default();
+		assert_eq!(eval(&cfg, r#"feature = "std""#), None);
+		assert_eq!(eval(&cfg, r#"not(feature = "std")"#), None);
+		assert_eq!(eval(&cfg, r#"all(feature = "std", unix)"#), Some(false));
+		assert_eq!(eval(&cfg, r#"any(feature = "std", not(unix))"#), Some(true));
+		assert_eq!(eval(&cfg, r#"any(feature = "std", unix)"#), None);
+	}
+
+	#[test]
+	fn features_set_explicitly() {
+		let mut db = Database::default();
+		db.set_features("c", &["serde"]);
+		db.add_crate_source("c", SOURCE, Edition::E2021);
+		assert!(has(&db, "c::WithSerde"));
+		assert!(!has(&db, "c::WithoutSerde"));
+		assert!(!has(&db, "c::Never"));
+		assert!(db.crates["c"].no_std);
+	}
+
+	#[test]
+	fn undecided_items_are_kept() {
+		let mut db = Database::default();
+		db.add_crate_source("c", SOURCE, Edition::E2021);
+		assert!(has(&db, "c::WithSerde"));
+		assert!(has(&db, "c::WithoutSerde"));
+		assert!(!has(&db, "c::Never"));
+		assert!(!db.crates["c"].no_std);
+	}
+
+	#[test]
+	fn default_features_of_manifest() {
+		let dir = std::env::temp_dir().join(format!("syg_cfg_{}", std::process::id()));
+		std::fs::create_dir_all(dir.join("c/src")).unwrap();
+		std::fs::write(
+			dir.join("c/Cargo.toml"),
+			"[package]\nname = \"c\"\nedition = \"2021\"\n\n[features]\ndefault = [\"std\"] # comment\nstd = [\"serde?/std\", \"dep:log\"]\nserde = []\n",
+		)
+		.unwrap();
+		std::fs::write(dir.join("c/src/lib.rs"), SOURCE).unwrap();
+		let mut db = Database::default();
+		db.add_crate(dir.to_str().unwrap(), "c");
+		std::fs::remove_dir_all(&dir).unwrap();
+		assert!(!has(&db, "c::WithSerde"));
+		assert!(has(&db, "c::WithoutSerde"));
+		assert!(!db.crates["c"].no_std);
+	}
+
+	#[test]
+	fn cfg_if_is_expanded() {
+		let mut db = Database::default();
+		db.set_features("c", &["b"]);
+		db.add_crate_source(
+			"c",
+			r#"
+			cfg_if::cfg_if! {
+				if #[cfg(feature = "a")] {
+					pub struct A;
+				} else if #[cfg(feature = "b")] {
+					pub struct B;
+					cfg_if! {
+						if #[cfg(target_os = "none")] {
+							pub struct BNone;
+						} else {
+							pub struct BSome;
+						}
+					}
+				} else {
+					pub struct Other;
+				}
+			}
+			"#,
+			Edition::E2021,
+		);
+		assert!(!has(&db, "c::A"));
+		assert!(has(&db, "c::B"));
+		assert!(!has(&db, "c::BNone"));
+		assert!(has(&db, "c::BSome"));
+		assert!(!has(&db, "c::Other"));
+	}
+
+	#[test]
+	fn undecided_cfg_if_branches_are_kept() {
+		let mut db = Database::default();
+		db.add_crate_source(
+			"c",
+			r#"
+			cfg_if! {
+				if #[cfg(feature = "a")] {
+					pub struct A;
+				} else if #[cfg(unix)] {
+					pub struct Unix;
+				} else {
+					pub struct Other;
+				}
+			}
+			"#,
+			Edition::E2021,
+		);
+		assert!(has(&db, "c::A"));
+		assert_eq!(has(&db, "c::Unix"), cfg!(unix));
+		assert_eq!(has(&db, "c::Other"), !cfg!(unix));
+	}
+}
@@ -0,0 +1,152 @@
+use syn::{punctuated::Punctuated, Expr, ExprLit, ForeignItem, Item, Lit, Meta, MetaNameValue, Token};
+
+use crate::{Ast, Database, DeclAst, GlobalIdent};
+
+/// function, static or type declared in an `extern` block
+#[derive(Debug, Clone)]
+pub struct ForeignDecl {
+    pub address: GlobalIdent,
+    /// `"C"` if the block has no explicit ABI
+    pub abi: String,
+    pub item: ForeignItem,
+    /// `fn printf(format: *const c_char, ...)`
+    pub variadic: bool,
+    /// libraries of `#[link(name = "...")]` on the block
+    pub link: Vec<String>,
+    /// `#[link_name = "..."]` of the item or its ident
+    pub symbol: String,
+}
+
+impl ForeignDecl {
+    pub fn from_decl(decl: &DeclAst) -> Option<ForeignDecl> {
+        let Ast::Real(Item::ForeignMod(block)) = &decl.ast else {
+            return None;
+        };
+        let [item] = block.items.as_slice() else {
+            return None;
+        };
+        let (ident, attrs, variadic) = match item {
+            ForeignItem::Fn(it) => (&it.sig.ident, &it.attrs, it.sig.variadic.is_some()),
+            ForeignItem::Static(it) => (&it.ident, &it.attrs, false),
+            ForeignItem::Type(it) => (&it.ident, &it.attrs, false),
+            _ => return None,
+        };
+        let abi = block
+            .abi
+            .name
+            .as_ref()
+            .map(|it| it.value())
+            .unwrap_or_else(|| "C".to_string());
+        let mut link = vec![];
+        for attr in block.attrs.iter() {
+            let Meta::List(list) = &attr.meta else {
+                continue;
+            };
+            if !list.path.is_ident("link") {
+                continue;
+            }
+            let Ok(args) = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) else {
+                println!("WARN: cannot parse #[link({})] of {}", list.tokens, decl.address);
+                continue;
+            };
+            for arg in args.iter() {
+                if let Meta::NameValue(it) = arg {
+                    if it.path.is_ident("name") {
+                        link.extend(str_value(it));
+                    }
+                }
+            }
+        }
+        let symbol = attrs
+            .iter()
+            .find_map(|attr| match &attr.meta {
+                Meta::NameValue(it) if it.path.is_ident("link_name") => str_value(it),
+                _ => None,
+            })
+            .unwrap_or_else(|| ident.to_string());
+        Some(ForeignDecl {
+            address: decl.address.clone(),
+            abi,
+            item: item.clone(),
+            variadic,
+            link,
+            symbol,
+        })
+    }
+}
+
+fn str_value(it: &MetaNameValue) -> Option<String> {
+    match &it.value {
+        Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Some(s.value()),
+        _ => None,
+    }
+}
+
+impl Database {
+    pub fn foreign_decl(&self, address: &GlobalIdent) -> Option<ForeignDecl> {
+        let binding = self.decls.find_value(address.to_parts())?;
+        binding
+            .type_ast
+            .iter()
+            .chain(binding.non_type_ast.iter())
+            .find_map(ForeignDecl::from_decl)
+    }
+
+    /// all foreign items of the crate
+    pub fn foreign_decls(&self, crate_: &str) -> Vec<ForeignDecl> {
+        let mut result = vec![];
+        self.decls.for_each(&mut |_, binding| {
+            for decl in binding.type_ast.iter().chain(binding.non_type_ast.iter()) {
+                if decl.address.first_part() != crate_ {
+                    continue;
+                }
+                result.extend(ForeignDecl::from_decl(decl));
+            }
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::ForeignItem;
+
+    use crate::{test_support::compiled, GlobalIdent};
+
+    const SOURCE: &str = r#"
+        #[link(name = "m")]
+        extern {
+            #[link_name = "cos"]
+            pub fn cosine(x: f64) -> f64;
+            pub static errno: i32;
+        }
+        extern "system" {
+            pub fn printf(format: *const u8, ...) -> i32;
+            pub type Handle;
+        }
+    "#;
+
+    #[test]
+    fn attributes_of_foreign_items() {
+        let db = compiled(SOURCE);
+        let cosine = db.foreign_decl(&GlobalIdent::from_qualified_name("c::cosine")).unwrap();
+        assert_eq!(cosine.abi, "C");
+        assert_eq!(cosine.link, ["m"]);
+        assert_eq!(cosine.symbol, "cos");
+        assert!(!cosine.variadic);
+        let printf = db.foreign_decl(&GlobalIdent::from_qualified_name("c::printf")).unwrap();
+        assert_eq!(printf.abi, "system");
+        assert!(printf.link.is_empty());
+        assert_eq!(printf.symbol, "printf");
+        assert!(printf.variadic);
+        let handle = db.foreign_decl(&GlobalIdent::from_qualified_name("c::Handle")).unwrap();
+        assert!(matches!(handle.item, ForeignItem::Type(_)));
+    }
+
+    #[test]
+    fn foreign_items_of_crate() {
+        let mut names = compiled(SOURCE).foreign_decls("c").iter().map(|it| it.address.to_string()).collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, ["c::Handle", "c::cosine", "c::errno", "c::printf"]);
+    }
+}
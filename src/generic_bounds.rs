@@ -0,0 +1,345 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use quote::ToTokens;
+use syn::{
+    BoundLifetimes, GenericArgument, GenericParam, Generics, Item, PathArguments, TraitBoundModifier, TraitItem,
+    TypeParamBound, WherePredicate,
+};
+
+use crate::{Ast, Database, GlobalIdent, ResolvedType, TraitRef};
+
+/// bound of a generic parameter, where-predicate or supertrait list.
+/// Built from the resolved AST, so it's only meaningful after `Database::compile`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GenericBound {
+    /// `for<'a> Trait<'a, T, Assoc = U, Other: Bound>` or `?Sized`
+    Trait {
+        trait_: TraitRef,
+        /// lifetime arguments of the trait, like `'de` of `Deserialize<'de>`
+        lifetimes: Vec<String>,
+        /// `Output = T` of `Add<Output = T>`
+        assoc_bindings: BTreeMap<String, ResolvedType>,
+        /// `Item: Display` of `Iterator<Item: Display>`
+        assoc_constraints: BTreeMap<String, Vec<GenericBound>>,
+        /// `?Sized`
+        maybe: bool,
+        /// lifetimes introduced by `for<'a>`
+        higher_ranked: Vec<String>,
+    },
+    /// `'static` or `'a`
+    Lifetime(String),
+    /// bound which path failed to resolve
+    Unresolved(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResolvedGenericParam {
+    Type {
+        name: String,
+        bounds: Vec<GenericBound>,
+        default: Option<ResolvedType>,
+    },
+    Lifetime {
+        name: String,
+        /// `'a: 'b`
+        bounds: Vec<String>,
+    },
+    Const {
+        name: String,
+        ty: ResolvedType,
+        default: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResolvedPredicate {
+    /// `for<'a> T: Bound`, `Self: Sized`, `Vec<T>: Debug`
+    Type {
+        bounded: ResolvedType,
+        higher_ranked: Vec<String>,
+        bounds: Vec<GenericBound>,
+    },
+    /// `'a: 'b`
+    Lifetime { name: String, bounds: Vec<String> },
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ResolvedGenerics {
+    pub params: Vec<ResolvedGenericParam>,
+    pub where_clause: Vec<ResolvedPredicate>,
+}
+
+impl ResolvedGenerics {
+    /// bounds of the type param both from its declaration and where-clause predicates `T: ...`
+    pub fn bounds_of(&self, param: &str) -> Vec<GenericBound> {
+        let mut bounds = vec![];
+        for it in self.params.iter() {
+            if let ResolvedGenericParam::Type { name, bounds: declared, .. } = it {
+                if name == param {
+                    bounds.extend(declared.iter().cloned());
+                }
+            }
+        }
+        for it in self.where_clause.iter() {
+            if let ResolvedPredicate::Type { bounded: ResolvedType::Generic(name), bounds: declared, .. } = it {
+                if name == param {
+                    bounds.extend(declared.iter().cloned());
+                }
+            }
+        }
+        bounds
+    }
+}
+
+impl Display for GenericBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenericBound::Trait {
+                trait_,
+                lifetimes,
+                assoc_bindings,
+                assoc_constraints,
+                maybe,
+                higher_ranked,
+            } => {
+                if !higher_ranked.is_empty() {
+                    write!(f, "for<{}> ", higher_ranked.join(", "))?;
+                }
+                if *maybe {
+                    write!(f, "?")?;
+                }
+                write!(f, "{}", trait_.address)?;
+                let mut args = lifetimes.clone();
+                args.extend(trait_.args.iter().map(|it| it.to_string()));
+                args.extend(assoc_bindings.iter().map(|(name, ty)| format!("{} = {}", name, ty)));
+                args.extend(
+                    assoc_constraints
+                        .iter()
+                        .map(|(name, bounds)| format!("{}: {}", name, join_bounds(bounds))),
+                );
+                if !args.is_empty() {
+                    write!(f, "<{}>", args.join(", "))?;
+                }
+                Ok(())
+            }
+            GenericBound::Lifetime(it) => write!(f, "{}", it),
+            GenericBound::Unresolved(it) => write!(f, "?{}", it),
+        }
+    }
+}
+
+fn join_bounds(bounds: &[GenericBound]) -> String {
+    bounds.iter().map(|it| it.to_string()).collect::<Vec<_>>().join(" + ")
+}
+
+impl Display for ResolvedPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolvedPredicate::Type { bounded, higher_ranked, bounds } => {
+                if !higher_ranked.is_empty() {
+                    write!(f, "for<{}> ", higher_ranked.join(", "))?;
+                }
+                write!(f, "{}: {}", bounded, join_bounds(bounds))
+            }
+            ResolvedPredicate::Lifetime { name, bounds } => write!(f, "{}: {}", name, bounds.join(" + ")),
+        }
+    }
+}
+
+fn lifetime_names(lifetimes: &Option<BoundLifetimes>) -> Vec<String> {
+    let Some(lifetimes) = lifetimes else {
+        return vec![];
+    };
+    lifetimes
+        .lifetimes
+        .iter()
+        .filter_map(|it| match it {
+            GenericParam::Lifetime(it) => Some(it.lifetime.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+impl Database {
+    pub fn resolve_bound(&self, bound: &TypeParamBound) -> GenericBound {
+        let TypeParamBound::Trait(bound) = bound else {
+            return match bound {
+                TypeParamBound::Lifetime(it) => GenericBound::Lifetime(it.to_string()),
+                it => GenericBound::Unresolved(it.to_token_stream().to_string()),
+            };
+        };
+        let Some(trait_) = self.resolve_trait_ref(&bound.path) else {
+            return GenericBound::Unresolved(bound.path.to_token_stream().to_string());
+        };
+        let mut lifetimes = vec![];
+        let mut assoc_bindings = BTreeMap::new();
+        let mut assoc_constraints = BTreeMap::new();
+        if let PathArguments::AngleBracketed(args) = &bound.path.segments.last().unwrap().arguments {
+            for arg in args.args.iter() {
+                match arg {
+                    GenericArgument::Lifetime(it) => lifetimes.push(it.to_string()),
+                    GenericArgument::AssocType(it) => {
+                        assoc_bindings.insert(it.ident.to_string(), self.resolve_type(&it.ty));
+                    }
+                    GenericArgument::Constraint(it) => {
+                        assoc_constraints.insert(it.ident.to_string(), self.resolve_bounds(it.bounds.iter()));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        GenericBound::Trait {
+            trait_,
+            lifetimes,
+            assoc_bindings,
+            assoc_constraints,
+            maybe: matches!(bound.modifier, TraitBoundModifier::Maybe(_)),
+            higher_ranked: lifetime_names(&bound.lifetimes),
+        }
+    }
+
+    pub fn resolve_bounds<'a>(&self, bounds: impl Iterator<Item = &'a TypeParamBound>) -> Vec<GenericBound> {
+        bounds.map(|it| self.resolve_bound(it)).collect()
+    }
+
+    pub fn resolve_generics(&self, generics: &Generics) -> ResolvedGenerics {
+        let params = generics
+            .params
+            .iter()
+            .map(|it| match it {
+                GenericParam::Type(it) => ResolvedGenericParam::Type {
+                    name: it.ident.to_string(),
+                    bounds: self.resolve_bounds(it.bounds.iter()),
+                    default: it.default.as_ref().map(|it| self.resolve_type(it)),
+                },
+                GenericParam::Lifetime(it) => ResolvedGenericParam::Lifetime {
+                    name: it.lifetime.to_string(),
+                    bounds: it.bounds.iter().map(|it| it.to_string()).collect(),
+                },
+                GenericParam::Const(it) => ResolvedGenericParam::Const {
+                    name: it.ident.to_string(),
+                    ty: self.resolve_type(&it.ty),
+                    default: it.default.as_ref().map(|it| it.to_token_stream().to_string()),
+                },
+            })
+            .collect();
+        let mut where_clause = vec![];
+        for predicate in generics.where_clause.iter().flat_map(|it| it.predicates.iter()) {
+            match predicate {
+                WherePredicate::Type(it) => where_clause.push(ResolvedPredicate::Type {
+                    bounded: self.resolve_type(&it.bounded_ty),
+                    higher_ranked: lifetime_names(&it.lifetimes),
+                    bounds: self.resolve_bounds(it.bounds.iter()),
+                }),
+                WherePredicate::Lifetime(it) => where_clause.push(ResolvedPredicate::Lifetime {
+                    name: it.lifetime.to_string(),
+                    bounds: it.bounds.iter().map(|it| it.to_string()).collect(),
+                }),
+                it => println!("WARN: skip where-predicate {}", it.to_token_stream()),
+            }
+        }
+        ResolvedGenerics { params, where_clause }
+    }
+
+    /// generics of the declaration. for trait items - generics of the item itself (of a method or a GAT)
+    pub fn generics_of(&self, address: &GlobalIdent) -> Option<ResolvedGenerics> {
+        let binding = self.decls.find_value(address.to_parts())?;
+        let decl = binding.type_ast.as_ref().or(binding.non_type_ast.as_ref())?;
+        let item = match &decl.ast {
+            Ast::Real(it) => it,
+            Ast::Variant(enum_, _) => return Some(self.resolve_generics(&enum_.generics)),
+            Ast::TraitItem(_, item) => {
+                let generics = match item {
+                    TraitItem::Fn(it) => &it.sig.generics,
+                    TraitItem::Type(it) => &it.generics,
+                    TraitItem::Const(it) => &it.generics,
+                    _ => return None,
+                };
+                return Some(self.resolve_generics(generics));
+            }
+            Ast::Stub => return None,
+        };
+        let generics = match item {
+            Item::Const(it) => &it.generics,
+            Item::Enum(it) => &it.generics,
+            Item::Fn(it) => &it.sig.generics,
+            Item::Impl(it) => &it.generics,
+            Item::Struct(it) => &it.generics,
+            Item::Trait(it) => &it.generics,
+            Item::TraitAlias(it) => &it.generics,
+            Item::Type(it) => &it.generics,
+            Item::Union(it) => &it.generics,
+            _ => return None,
+        };
+        Some(self.resolve_generics(generics))
+    }
+
+    /// supertraits of the trait, including `Self: Trait` predicates of its where-clause
+    pub fn supertraits(&self, trait_: &GlobalIdent) -> Vec<GenericBound> {
+        let Some(binding) = self.decls.find_value(trait_.to_parts()) else {
+            return vec![];
+        };
+        let Some(Ast::Real(Item::Trait(ast))) = binding.type_ast.as_ref().map(|it| &it.ast) else {
+            return vec![];
+        };
+        let mut bounds = self.resolve_bounds(ast.supertraits.iter());
+        for predicate in self.resolve_generics(&ast.generics).where_clause {
+            if let ResolvedPredicate::Type { bounded: ResolvedType::Generic(name), bounds: declared, .. } = predicate {
+                if name == "Self" {
+                    bounds.extend(declared);
+                }
+            }
+        }
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_support::compiled, GlobalIdent};
+
+    use super::ResolvedGenericParam;
+
+    const SOURCE: &str = "
+        pub trait Shape: Clone where Self: Sized { type Unit; fn scale<S: Into<f32>>(&self, s: S); }
+        pub fn draw<'a, T: Shape<Unit = f32> + 'a, const N: usize>(items: &'a [T; N]) where T: ?Sized + Send {}
+        pub fn visit<F>(f: F) where F: for<'b> Fn(&'b str) {}
+    ";
+
+    fn strings(items: impl IntoIterator<Item = impl ToString>) -> Vec<String> {
+        items.into_iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn bounds_of_params_and_predicates() {
+        let db = compiled(SOURCE);
+        let generics = db.generics_of(&GlobalIdent::from_qualified_name("c::draw")).unwrap();
+        assert!(matches!(&generics.params[0], ResolvedGenericParam::Lifetime { name, .. } if name == "'a"));
+        assert!(matches!(&generics.params[2], ResolvedGenericParam::Const { name, .. } if name == "N"));
+        assert_eq!(
+            strings(generics.bounds_of("T")),
+            ["c::Shape<Unit = f32>", "'a", "?core::marker::Sized", "core::marker::Send"]
+        );
+        let generics = db.generics_of(&GlobalIdent::from_qualified_name("c::visit")).unwrap();
+        assert_eq!(strings(generics.bounds_of("F")), ["for<'b> core::ops::Fn<(&str,), ()>"]);
+    }
+
+    #[test]
+    fn supertraits_include_self_predicates() {
+        let db = compiled(SOURCE);
+        assert_eq!(
+            strings(db.supertraits(&GlobalIdent::from_qualified_name("c::Shape"))),
+            ["core::clone::Clone", "core::marker::Sized"]
+        );
+    }
+
+    #[test]
+    fn generics_of_trait_items() {
+        let db = compiled(SOURCE);
+        let generics = db.generics_of(&GlobalIdent::from_qualified_name("c::Shape::scale")).unwrap();
+        assert!(matches!(generics.params.as_slice(), [ResolvedGenericParam::Type { name, .. }] if name == "S"));
+    }
+}
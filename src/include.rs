@@ -0,0 +1,120 @@
+use std::{collections::BTreeMap, path::Path};
+
+use quote::ToTokens;
+use syn::{punctuated::Punctuated, Expr, ExprLit, Lit, LitStr, Macro, Token};
+
+use crate::Database;
+
+impl Database {
+    /// sets a compile-time environment variable of the crate, visible to `env!` in `include!` paths.
+    /// `OUT_DIR` usually points into an existing `target/` directory, like
+    /// `target/debug/build/<crate>-<hash>/out`
+    pub fn set_crate_env(&mut self, crate_: &str, key: &str, value: &str) {
+        self.crate_env
+            .entry(crate_.replace('-', "_"))
+            .or_default()
+            .insert(key.to_string(), value.to_string());
+    }
+
+    pub fn crate_env_of(&self, crate_: &str, key: &str) -> Option<&String> {
+        self.crate_env.get(crate_)?.get(key)
+    }
+}
+
+/// path of `include!(...)`, relative paths are resolved against the directory of `including_file`
+pub(crate) fn include_path(mac: &Macro, env: &BTreeMap<String, String>, including_file: &str) -> Result<String, String> {
+    let arg = mac
+        .parse_body::<Expr>()
+        .map_err(|err| format!("cannot parse {}: {}", mac.tokens, err))?;
+    let path = eval_str(&arg, env)?;
+    if Path::new(&path).is_absolute() {
+        return Ok(path);
+    }
+    match Path::new(including_file).parent() {
+        Some(dir) if !including_file.is_empty() => Ok(dir.join(&path).to_string_lossy().replace('\\', "/")),
+        _ => Err(format!("relative path {:?} outside of a file", path)),
+    }
+}
+
+/// evaluates a string literal or `concat!` / `env!` of string literals
+fn eval_str(expr: &Expr, env: &BTreeMap<String, String>) -> Result<String, String> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Str(it) => Ok(it.value()),
+            Lit::Char(it) => Ok(it.value().to_string()),
+            Lit::Int(it) => Ok(it.base10_digits().to_string()),
+            Lit::Float(it) => Ok(it.base10_digits().to_string()),
+            Lit::Bool(it) => Ok(it.value.to_string()),
+            it => Err(format!("unsupported literal {}", it.to_token_stream())),
+        },
+        Expr::Macro(it) if it.mac.path.is_ident("concat") => {
+            let args = it
+                .mac
+                .parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+                .map_err(|err| format!("cannot parse concat!({}): {}", it.mac.tokens, err))?;
+            let mut result = String::new();
+            for arg in args.iter() {
+                result += &eval_str(arg, env)?;
+            }
+            Ok(result)
+        }
+        Expr::Macro(it) if it.mac.path.is_ident("env") => {
+            let args = it
+                .mac
+                .parse_body_with(Punctuated::<LitStr, Token![,]>::parse_terminated)
+                .map_err(|err| format!("cannot parse env!({}): {}", it.mac.tokens, err))?;
+            let Some(key) = args.first() else {
+                return Err("env! without arguments".to_string());
+            };
+            env.get(&key.value())
+                .cloned()
+                .ok_or_else(|| format!("environment variable {} is not configured", key.value()))
+        }
+        it => Err(format!("unsupported expression {}", it.to_token_stream())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, fs};
+
+    use syn::{parse_quote, Macro};
+
+    use super::include_path;
+    use crate::{Database, GlobalIdent};
+
+    #[test]
+    fn paths_of_includes() {
+        let env = BTreeMap::from([("OUT_DIR".to_string(), "/target/out".to_string())]);
+        let mac: Macro = parse_quote!(include!("gen/items.rs"));
+        assert_eq!(include_path(&mac, &env, "/c/src/lib.rs"), Ok("/c/src/gen/items.rs".to_string()));
+        let mac: Macro = parse_quote!(include!(concat!(env!("OUT_DIR"), "/bindings_", 2, ".rs")));
+        assert_eq!(include_path(&mac, &env, "/c/src/lib.rs"), Ok("/target/out/bindings_2.rs".to_string()));
+        let mac: Macro = parse_quote!(include!(concat!(env!("MISSING"), "/x.rs")));
+        assert!(include_path(&mac, &env, "/c/src/lib.rs").is_err());
+        let mac: Macro = parse_quote!(include!("x.rs"));
+        assert!(include_path(&mac, &env, "").is_err());
+    }
+
+    #[test]
+    fn included_items_are_added() {
+        let dir = std::env::temp_dir().join(format!("syg_include_{}", std::process::id()));
+        let out_dir = dir.join("out");
+        for (path, content) in [
+            ("c/src/lib.rs", "pub mod m { include!(\"m/items.rs\"); }\ninclude!(concat!(env!(\"OUT_DIR\"), \"/gen.rs\"));"),
+            ("c/src/m/items.rs", "pub struct Local;"),
+            ("out/gen.rs", "pub struct Generated;"),
+        ] {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        let mut db = Database::default();
+        db.set_crate_env("c", "OUT_DIR", &out_dir.to_string_lossy());
+        db.add_crate(&dir.to_string_lossy(), "c");
+        for name in ["c::m::Local", "c::Generated"] {
+            assert!(db.decls.find_value(GlobalIdent::from_qualified_name(name).to_parts()).is_some(), "{}", name);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,12 +1,25 @@
 use std::collections::BTreeMap;
 
 use quote::ToTokens;
-use syn::{visit::Visit, Item, ItemType, Path, Type, TypePath};
+use syn::{
+    visit_mut::{visit_expr_mut, visit_generic_argument_mut, visit_lifetime_mut, visit_type_mut, VisitMut},
+    Expr, GenericArgument, GenericParam, Item, ItemType, Lifetime, PathArguments, PathResolution, Type, TypePath,
+};
 
 use crate::{
-    check_path_resolved::PathResolutionCheck, dedoc::ItemTypeExt, ident_part::RefSliceOfIdentPartExt, stopwatch::start_watch, Ast, Database, GlobalIdent
+    check_path_resolved::PathResolutionCheck, dedoc::ItemTypeExt, ident_part::RefSliceOfIdentPartExt, stopwatch::start_watch, Ast, Database, GlobalIdent, ResolvedType
 };
 
+/// type alias at the place it was used, before it was inlined
+#[derive(Debug, Clone)]
+pub struct AliasUse {
+    pub alias: GlobalIdent,
+    /// the type as written, like `Vector3<f32>`. its path stays resolved to the alias
+    pub written: Type,
+    /// what the type was replaced with
+    pub inlined: Type,
+}
+
 impl Database {
     pub(crate) fn inline_types(&mut self) {
         let _watch = start_watch("inline_types");
@@ -17,51 +30,334 @@ impl Database {
             - write alias name to the final type decl (to know all aliases)
         traverse decls
             - traverse ast
-                - write final type everywhere (replace original Path with final type AST,
-                which could be not a path, though, I'm not sure we really should support this non-Path case)
+                - write final type everywhere (replace original Path with final type AST)
          */
 
-        let mut type_by_alias: BTreeMap<GlobalIdent, Path> = Default::default();
+        let mut type_by_alias: BTreeMap<GlobalIdent, ItemType> = Default::default();
 
         self.decls.for_each(&mut |path, binding| {
 			if let Some(ast) = &binding.type_ast {
 				if let Ast::Real(ast) = &ast.ast {
 					if let Item::Type(ast) = ast {
-						match &*ast.ty {
-							Type::Path(ty) => {
-								if ty.qself.is_some() {
-									println!("WARN: qself is not implemented. {} for {}", ty.to_token_stream(), path.to_global_path())
-								} else {
-									let check = PathResolutionCheck::check_path(&ty.path);
-									if check.not_attempted.node_count() > 1 || check.failed.node_count() > 1 {
-										println!("WARN: resolution not attempted or failed for the type of {}: {}", path.to_global_path(), ast.dedoc().to_token_stream());
-										check.aggregated.for_each(&mut |path, v| {
-											for (ty, res) in v.iter() {
-												let res = match res {
-													syn::PathResolution::NotAttempted => "NotAttempted".to_owned(),
-													syn::PathResolution::Failed => "Failed".to_owned(),
-													syn::PathResolution::Resolved(it) => format!("Resolved({})", it.to_token_stream()),
-												};
-												println!("{}{} - {}", "    ".repeat(path.len()), ty, res);	
-											}
-										});
-									} else {
-										println!("INFO: resolution OK for {}: {}", path.to_global_path(), ast.dedoc().to_token_stream());
-										type_by_alias.insert(path.to_global_path(), ty.path.clone());
-									}
+						let check = PathResolutionCheck::check_type(&ast.ty);
+						if check.not_attempted.node_count() > 1 || check.failed.node_count() > 1 {
+							println!("WARN: resolution not attempted or failed for the type of {}: {}", path.to_global_path(), ast.dedoc().to_token_stream());
+							check.aggregated.for_each(&mut |path, v| {
+								for (ty, res) in v.iter() {
+									let res = match res {
+										syn::PathResolution::NotAttempted => "NotAttempted".to_owned(),
+										syn::PathResolution::Failed => "Failed".to_owned(),
+										syn::PathResolution::Resolved(it) => format!("Resolved({})", it.to_token_stream()),
+									};
+									println!("{}{} - {}", "    ".repeat(path.len()), ty, res);
 								}
-							},
-							_ => {
-								println!("WARN: type alias ignored, because non-path aliases not supported: {} ({:?})", ast.dedoc().to_token_stream(), ast.dedoc());
-							},
+							});
+						} else {
+							println!("INFO: resolution OK for {}: {}", path.to_global_path(), ast.dedoc().to_token_stream());
+							type_by_alias.insert(path.to_global_path(), ast.clone());
 						}
 					}
 				}
 			}
 		 });
 
-        for (path, type_) in type_by_alias.iter() {
-            // self.lookup_decl(candidate)
+        let mut final_types: BTreeMap<GlobalIdent, Vec<GlobalIdent>> = Default::default();
+        let mut cyclic: Vec<GlobalIdent> = Default::default();
+        for (alias, ast) in type_by_alias.iter() {
+            let mut ty = (*ast.ty).clone();
+            AliasInline {
+                aliases: &type_by_alias,
+                stack: vec![alias.clone()],
+                uses: &mut vec![],
+            }
+            .visit_type_mut(&mut ty);
+            if let Type::Path(TypePath { qself: None, path }) = &ty {
+                if let PathResolution::Resolved(it) = &path.resolution {
+                    let final_type = GlobalIdent::from_qualified_name(it);
+                    if type_by_alias.contains_key(&final_type) {
+                        cyclic.push(alias.clone());
+                    } else if !it.starts_with('<') {
+                        final_types.entry(final_type).or_default().push(alias.clone());
+                    }
+                }
+            }
+        }
+        // cyclic aliases are left as is
+        for it in cyclic {
+            type_by_alias.remove(&it);
+        }
+        for (final_type, aliases) in final_types {
+            if let Some(node) = self.decls.find_mut(&final_type) {
+                node.get_value_mut().known_aliases.extend(aliases);
+            }
+        }
+
+        let mut alias_uses: BTreeMap<GlobalIdent, Vec<AliasUse>> = Default::default();
+        self.decls.for_each_mut(&mut |_, binding, path| {
+            for decl in [&mut binding.type_ast, &mut binding.non_type_ast].into_iter().flatten() {
+                let mut uses = vec![];
+                decl.ast.visit_item_mut(|ast| {
+                    AliasInline {
+                        aliases: &type_by_alias,
+                        stack: vec![path.to_global_path()],
+                        uses: &mut uses,
+                    }
+                    .visit_item_mut(ast)
+                });
+                if !uses.is_empty() {
+                    alias_uses.entry(decl.address.clone()).or_default().extend(uses);
+                }
+            }
+        });
+        self.alias_uses = alias_uses;
+    }
+
+    /// aliases inlined in the signature of the declaration, in the order they were written
+    pub fn alias_uses(&self, address: &GlobalIdent) -> &[AliasUse] {
+        self.alias_uses.get(address).map(|it| it.as_slice()).unwrap_or_default()
+    }
+
+    /// final type of the alias instantiated with the given generic arguments
+    pub fn expand_alias(&self, alias: &GlobalIdent, args: &[ResolvedType]) -> Option<ResolvedType> {
+        let decl = self.decls.find_value(alias)?.type_ast.as_ref()?;
+        let Ast::Real(Item::Type(ast)) = &decl.ast else {
+            return None;
+        };
+        let params = ast
+            .generics
+            .params
+            .iter()
+            .filter_map(|it| match it {
+                GenericParam::Type(it) => Some((it.ident.to_string(), it.default.as_ref().map(|it| self.resolve_type(it)))),
+                GenericParam::Const(it) => Some((it.ident.to_string(), None)),
+                GenericParam::Lifetime(_) => None,
+            });
+        let mut substitutions: BTreeMap<String, ResolvedType> = Default::default();
+        for (i, (name, default)) in params.enumerate() {
+            if let Some(it) = args.get(i).cloned().or(default) {
+                substitutions.insert(name, it);
+            }
+        }
+        Some(self.resolve_type(&ast.ty).substitute(&substitutions))
+    }
+}
+
+/// replaces usages of type aliases with their (recursively inlined) types
+struct AliasInline<'a> {
+    aliases: &'a BTreeMap<GlobalIdent, ItemType>,
+    /// aliases being inlined at the moment - to detect cycles
+    stack: Vec<GlobalIdent>,
+    /// aliases used directly by the visited declaration
+    uses: &'a mut Vec<AliasUse>,
+}
+
+impl AliasInline<'_> {
+    fn inline(&mut self, alias: &GlobalIdent, ast: &ItemType, args: &PathArguments) -> Type {
+        let args = match args {
+            PathArguments::AngleBracketed(it) => it.args.iter().collect::<Vec<_>>(),
+            _ => vec![],
+        };
+        let mut substitute = Substitute::default();
+        let mut lifetime_args = args.iter().filter_map(|it| match it {
+            GenericArgument::Lifetime(it) => Some(it),
+            _ => None,
+        });
+        let mut other_args = args.iter().filter(|it| !matches!(it, GenericArgument::Lifetime(_)));
+        for param in ast.generics.params.iter() {
+            match param {
+                GenericParam::Lifetime(param) => {
+                    if let Some(arg) = lifetime_args.next() {
+                        substitute.lifetimes.insert(param.lifetime.ident.to_string(), (*arg).clone());
+                    }
+                }
+                GenericParam::Type(param) => {
+                    let arg = match other_args.next() {
+                        Some(arg) => (*arg).clone(),
+                        None => match &param.default {
+                            Some(default) => GenericArgument::Type(default.clone()),
+                            None => {
+                                println!("WARN: no argument for {} of alias {}", param.ident, alias);
+                                continue;
+                            }
+                        },
+                    };
+                    substitute.types.insert(format!("<{}>", param.ident), arg);
+                }
+                GenericParam::Const(param) => {
+                    if let Some(arg) = other_args.next() {
+                        substitute.types.insert(format!("<{}>", param.ident), (*arg).clone());
+                    }
+                }
+            }
+        }
+
+        let mut ty = (*ast.ty).clone();
+        substitute.visit_type_mut(&mut ty);
+
+        self.stack.push(alias.clone());
+        self.visit_type_mut(&mut ty);
+        self.stack.pop();
+        ty
+    }
+}
+
+impl VisitMut for AliasInline<'_> {
+    fn visit_expr_mut(&mut self, _i: &mut Expr) {}
+
+    fn visit_type_mut(&mut self, i: &mut Type) {
+        if let Type::Path(TypePath { qself: None, path }) = i {
+            if let PathResolution::Resolved(it) = &path.resolution {
+                let alias = GlobalIdent::from_qualified_name(it);
+                if let Some(ast) = self.aliases.get(&alias) {
+                    if self.stack.contains(&alias) {
+                        println!("WARN: cyclic type alias {} (via {:?})", alias, self.stack);
+                    } else {
+                        println!("  inline {} as {}", path.to_token_stream(), ast.ty.to_token_stream());
+                        let args = path.segments.last().unwrap().arguments.clone();
+                        let inlined = self.inline(&alias, ast, &args);
+                        if self.stack.len() == 1 {
+                            self.uses.push(AliasUse {
+                                alias,
+                                written: i.clone(),
+                                inlined: inlined.clone(),
+                            });
+                        }
+                        *i = inlined;
+                        return;
+                    }
+                }
+            }
+        }
+        visit_type_mut(self, i);
+    }
+}
+
+/// replaces generic params of the alias with the arguments
+#[derive(Default)]
+struct Substitute {
+    /// keys are in the format of resolved generic params, i.e. `<T>`
+    types: BTreeMap<String, GenericArgument>,
+    lifetimes: BTreeMap<String, Lifetime>,
+}
+
+impl Substitute {
+    fn type_param(&self, i: &Type) -> Option<&GenericArgument> {
+        match i {
+            Type::Path(TypePath { qself: None, path }) if path.segments.len() == 1 => match &path.resolution {
+                PathResolution::Resolved(it) => self.types.get(it),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl VisitMut for Substitute {
+    fn visit_type_mut(&mut self, i: &mut Type) {
+        match self.type_param(i) {
+            Some(GenericArgument::Type(it)) => *i = it.clone(),
+            _ => visit_type_mut(self, i),
+        }
+    }
+
+    fn visit_generic_argument_mut(&mut self, i: &mut GenericArgument) {
+        if let GenericArgument::Type(ty) = i {
+            if let Some(it) = self.type_param(ty) {
+                // const params are parsed as types when passed to another generic
+                *i = it.clone();
+                return;
+            }
+        }
+        visit_generic_argument_mut(self, i);
+    }
+
+    fn visit_expr_mut(&mut self, i: &mut Expr) {
+        if let Expr::Path(it) = i {
+            if let Some(ident) = it.path.get_ident() {
+                if let Some(GenericArgument::Const(arg)) = self.types.get(&format!("<{}>", ident)) {
+                    *i = arg.clone();
+                    return;
+                }
+            }
+        }
+        visit_expr_mut(self, i);
+    }
+
+    fn visit_lifetime_mut(&mut self, i: &mut Lifetime) {
+        match self.lifetimes.get(&i.ident.to_string()) {
+            Some(it) => *i = it.clone(),
+            None => visit_lifetime_mut(self, i),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::Item;
+
+    use crate::{test_support::compiled, Database, GlobalIdent, ResolvedType};
+
+    const SOURCE: &str = "
+        pub struct Len(pub f32);
+        pub type Meters = Len;
+        pub type Distance = Meters;
+        pub type Pair<A> = (A, A);
+        pub type Lens = Pair<Len>;
+        pub struct Holder { pub lens: Lens, pub distance: Distance }
+    ";
+
+    fn holder_fields(db: &Database) -> Vec<String> {
+        let binding = db.decls.find_value(GlobalIdent::from_qualified_name("c::Holder").to_parts()).unwrap();
+        let Some(Item::Struct(holder)) = binding.type_ast.as_ref().and_then(|it| it.ast.as_ref()) else {
+            panic!("no struct");
+        };
+        holder.fields.iter().map(|it| db.resolve_type(&it.ty).to_string()).collect()
+    }
+
+    #[test]
+    fn aliases_are_inlined() {
+        let db = compiled(SOURCE);
+        assert_eq!(holder_fields(&db), ["(c::Len, c::Len)", "c::Len"]);
+
+        let len = db.decls.find_value(GlobalIdent::from_qualified_name("c::Len").to_parts()).unwrap();
+        let aliases = len.known_aliases.iter().map(|it| it.to_string()).collect::<Vec<_>>();
+        assert_eq!(aliases, ["c::Distance", "c::Meters"]);
+    }
+
+    #[test]
+    fn alias_uses_are_kept() {
+        let db = compiled(SOURCE);
+        let uses = db
+            .alias_uses(&GlobalIdent::from_qualified_name("c::Holder"))
+            .iter()
+            .map(|it| {
+                (
+                    it.alias.to_string(),
+                    it.written.to_token_stream().to_string(),
+                    db.resolve_type(&it.inlined).to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            uses,
+            [
+                ("c::Lens".to_owned(), "Lens".to_owned(), "(c::Len, c::Len)".to_owned()),
+                ("c::Distance".to_owned(), "Distance".to_owned(), "c::Len".to_owned()),
+            ]
+        );
+        // uses inside other aliases are not the holder's
+        assert!(db.alias_uses(&GlobalIdent::from_qualified_name("c::Len")).is_empty());
+    }
+
+    #[test]
+    fn generic_aliases_are_substituted() {
+        let db = compiled(SOURCE);
+        let len = ResolvedType::Path {
+            address: GlobalIdent::from_qualified_name("c::Len"),
+            args: vec![],
+        };
+        let expanded = db.expand_alias(&GlobalIdent::from_qualified_name("c::Pair"), std::slice::from_ref(&len));
+        assert_eq!(expanded, Some(ResolvedType::Tuple(vec![len.clone(), len])));
+    }
+}
@@ -5,20 +5,57 @@ pub mod lookup_decl;
 pub mod display_utils;
 pub mod eval_cfg;
 pub mod named_tree;
+pub mod primitive;
+pub mod builtin_crates;
+pub mod crate_meta;
+pub mod macro_rules;
+pub mod derive;
+pub mod include;
+pub mod foreign;
+pub mod trait_items;
+pub mod sysroot;
 pub mod dedoc;
 pub mod bake_wildcards;
 pub mod global_ident;
 pub mod ident_part;
 pub mod resolve_idents;
+pub mod resolved_type;
+pub mod generic_bounds;
+pub mod trait_resolution;
+pub mod auto_traits;
+pub mod references;
+pub mod dep_graph;
+pub mod module_tree;
+pub mod codegen;
+pub mod lua_bindings;
+pub mod lua_stubs;
+pub mod ts_declarations;
+pub mod c_header;
+pub mod monomorphize;
+pub mod normalize;
 use std::fmt::Display;
 
 pub mod add_crate;
 pub mod model;
 pub mod stopwatch;
+#[cfg(test)]
+mod test_support;
 
 pub use global_ident::GlobalIdent;
 pub use ident_part::IdentPart;
 pub use model::*;
+pub use primitive::Primitive;
+pub use resolved_type::{ResolvedType, TraitRef};
+pub use generic_bounds::{GenericBound, ResolvedGenericParam, ResolvedGenerics, ResolvedPredicate};
+pub use trait_resolution::{ImplMatch, ImplRecord};
+pub use normalize::AbstractProjection;
+pub use auto_traits::{AutoTraitAnswer, AutoTraitLink};
+pub use dep_graph::{DepEdge, DepEdgeKind, DepGraph, DepGraphFilter};
+pub use module_tree::{ItemKind, ModuleItem, ModuleTree};
+pub use lua_bindings::LuaBindings;
+pub use lua_stubs::{LuaStubFormat, LuaStubs};
+pub use ts_declarations::TsDeclarations;
+pub use c_header::CHeader;
 
 #[extend::ext]
 pub impl &str {
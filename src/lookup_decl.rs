@@ -8,26 +8,121 @@ use std::{
 
 use proc_macro2::Span;
 use quote::{quote, ToTokens};
-use syn::{parse2, parse_str, Ident, Item, ItemStruct, Path};
+use syn::{parse2, parse_str, Ident, Item, ItemStruct, Path, TraitItem, TypeParamBound};
 
 use crate::{
     dedoc::ItemExt, ident_part::RefSliceOfIdentPartExt, named_tree::{FromPath, NamedNode}, Ast, Binding, Database, DeclAst, GlobalIdent, IdentPart, Resolution
 };
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Namespace {
+    /// structs, enums, unions, traits, type aliases, associated types and variants
+    Type,
+    /// functions, consts, statics, methods, associated consts and variants
+    Value,
+}
+
 impl Database {
-	
+    /// paths to try (in order) when `path` is mentioned inside `scope`
+    pub fn candidates(&self, scope: &GlobalIdent, path: &[String]) -> Vec<GlobalIdent> {
+        if path.first().map(|it| it.as_str()) == Some("crate") {
+            let crate_ = GlobalIdent::from_ident_path(&[scope.first_part()]);
+            return vec![GlobalIdent::from_mod_and_path(&crate_, &path[1..])];
+        }
+        let mut candidates = vec![GlobalIdent::from_mod_and_path(scope, path)];
+        if let Some((first, rest)) = path.split_first() {
+            if let Some(crate_) = self.extern_crate_of(&scope.first_part().to_string(), first) {
+                let mut renamed = vec![crate_.clone()];
+                renamed.extend_from_slice(rest);
+                candidates.push(GlobalIdent::from_path(&renamed));
+            }
+        }
+        candidates.push(GlobalIdent::from_path(path));
+        if let Some(prelude) = self.prelude_of(&scope.first_part().to_string()) {
+            candidates.push(GlobalIdent::from_mod_and_path(prelude, path));
+        }
+        candidates
+    }
+
+    /// mod whose names are visible in the declaration. it is the parent, except for
+    /// declarations nested in types, like enum variants
+    pub fn scope_of(&self, address: &GlobalIdent) -> GlobalIdent {
+        let mut scope = address.parent();
+        while let Some(binding) = self.decls.find_value(scope.to_parts()) {
+            if binding.type_ast.is_none() || scope.to_parts().len() <= 1 {
+                break;
+            }
+            scope = scope.parent();
+        }
+        scope
+    }
+
+    pub fn lookup_path(&self, scope: &GlobalIdent, path: &Path) -> Resolution {
+        let path = path.segments.iter().map(|it| it.ident.to_string()).collect::<Vec<_>>();
+        for candidate in self.candidates(scope, &path) {
+            if let Resolution::Fully(it) = self.lookup_decl(&candidate) {
+                return Resolution::Fully(it);
+            }
+        }
+        Resolution::Failed
+    }
+
+    /// finds the trait (`trait_` itself or one of its supertraits) that declares associated type `assoc`
+    pub fn find_assoc_type(&self, trait_: &GlobalIdent, assoc: &str) -> Option<GlobalIdent> {
+        self.find_assoc_type_internal(trait_, assoc, &mut Default::default())
+    }
+
+    fn find_assoc_type_internal(
+        &self,
+        trait_: &GlobalIdent,
+        assoc: &str,
+        checked: &mut HashSet<GlobalIdent>,
+    ) -> Option<GlobalIdent> {
+        if !checked.insert(trait_.clone()) {
+            return None;
+        }
+        let Resolution::Fully(DeclAst { address, ast: Ast::Real(Item::Trait(ast)) }) = self.lookup_decl(trait_) else {
+            return None;
+        };
+        let declared = ast.items.iter().any(|it| match it {
+            TraitItem::Type(it) => it.ident == assoc,
+            _ => false,
+        });
+        if declared {
+            return Some(address);
+        }
+        for bound in ast.supertraits.iter() {
+            if let TypeParamBound::Trait(bound) = bound {
+                if let Resolution::Fully(supertrait) = self.lookup_path(&address.parent(), &bound.path) {
+                    if let Some(it) = self.find_assoc_type_internal(&supertrait.address, assoc, checked) {
+                        return Some(it);
+                    }
+                }
+            }
+        }
+        None
+    }
 
     pub fn lookup_decl(&self, candidate: &GlobalIdent) -> Resolution {
         println!("      lookup_decl {}", candidate);
 
         let path = candidate.to_parts();
-        self.lookup_internal(&self.decls, &path, 0, &mut Default::default())
+        self.lookup_internal(&self.decls, &path, Namespace::Type, 0, &mut Default::default())
+    }
+
+    /// like `lookup_decl`, but finds functions, consts, statics, methods and variants
+    pub fn lookup_value_decl(&self, candidate: &GlobalIdent) -> Resolution {
+        println!("      lookup_value_decl {}", candidate);
+
+        let path = candidate.to_parts();
+        self.lookup_internal(&self.decls, &path, Namespace::Value, 0, &mut Default::default())
     }
 
     fn lookup_internal<'a, 'b, 'c>(
         &'a self,
         base: &'b NamedNode<IdentPart, Binding>,
         path: &[IdentPart],
+        ns: Namespace,
         depth: usize,
         checked: &mut HashSet<GlobalIdent>,
     ) -> Resolution
@@ -53,24 +148,20 @@ impl Database {
             let mut new_path = import.to_parts();
             new_path.extend_from_slice(path);
             if let Resolution::Fully(result) =
-                self.lookup_internal(&self.decls, &new_path, depth + 1, checked)
+                self.lookup_internal(&self.decls, &new_path, ns, depth + 1, checked)
             {
                 return Resolution::Fully(result);
             }
         }
         if path.is_empty() {
-            if let Some(ast) = &value.type_ast {
+            let ast = match ns {
+                Namespace::Type => &value.type_ast,
+                Namespace::Value => &value.non_type_ast,
+            };
+            if let Some(ast) = ast {
                 return Resolution::Fully(ast.clone());
             }
             if !value.alias_for.is_empty() {
-                for (it, _) in value.alias_for.iter() {
-                    if it.first_part() == "std" || it.first_part() == "core" {
-                        return Resolution::Fully(DeclAst {
-                            address: base.path().to_global_path(),
-                            ast: Ast::Stub,
-                        });
-                    }
-                }
                 assert!(value.alias_for.len() == 1, "cannot choose partial resolution: {}", value);
                 println!(
                     "      {}partial resolution by alias \"{}\"",
@@ -90,7 +181,7 @@ impl Database {
         if let Some(decl) = base.get_child(first) {
             let mut new_base_path = base.path().to_vec();
             new_base_path.push(first.clone());
-            return self.lookup_internal(decl, rem, depth + 1, checked);
+            return self.lookup_internal(decl, rem, ns, depth + 1, checked);
         }
         if value.wildcard_alias_for.is_empty() {
             println!("      {}no wildcard imports", indent);
@@ -107,7 +198,7 @@ impl Database {
             let mut new_path = wildcard_import.to_parts();
             new_path.extend_from_slice(path);
             if let Resolution::Fully(result) =
-                self.lookup_internal(&self.decls, &new_path, depth + 1, checked)
+                self.lookup_internal(&self.decls, &new_path, ns, depth + 1, checked)
             {
                 return Resolution::Fully(result);
             }
@@ -0,0 +1,748 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write},
+};
+
+use syn::{Fields, FnArg, GenericParam, Generics, ImplItem, Item, ItemFn, Pat};
+
+use crate::{
+    codegen::unique_names, dedoc::doc_of, monomorphize::MonoMethod, Ast, Database, GlobalIdent, Primitive,
+    ResolvedGenericParam, ResolvedType, TraitRef,
+};
+
+const CLONE: &str = "core::clone::Clone";
+const COPY: &str = "core::marker::Copy";
+const DISPLAY: &str = "core::fmt::Display";
+
+/// Rust source of `mlua` bindings, see `Database::lua_bindings`
+#[derive(Debug, Clone, Default)]
+pub struct LuaBindings {
+    pub code: String,
+    /// items and members left out because of unsupported signatures, with the reason
+    pub skipped: Vec<(String, String)>,
+}
+
+/// how a value crosses the Lua boundary: the type `mlua` converts it from (for args)
+/// and the expression converting it, with `$` standing for the value
+struct Conversion {
+    lua_ty: String,
+    expr: String,
+}
+
+impl Conversion {
+    fn new(lua_ty: impl Into<String>, expr: impl Into<String>) -> Self {
+        Conversion {
+            lua_ty: lua_ty.into(),
+            expr: expr.into(),
+        }
+    }
+
+    fn apply(&self, value: &str) -> String {
+        self.expr.replace('$', value)
+    }
+
+    /// closure body returning the converted value
+    fn body(&self, value: &str) -> String {
+        match self.lua_ty.as_str() {
+            "()" => format!("{{\n    {};\n    Ok(())\n}}", self.apply(value)),
+            _ => format!("Ok({})", self.apply(value)),
+        }
+    }
+}
+
+/// struct or enum getting a `mlua::UserData` wrapper
+struct Exposed {
+    address: GlobalIdent,
+    /// `::crate::path::Type` reachable from other crates
+    path: String,
+    wrapper: String,
+    item: Item,
+}
+
+/// bound part of the API, shared by the bindings and the annotation stubs
+#[derive(Default)]
+pub(crate) struct LuaApi {
+    pub(crate) classes: Vec<LuaClass>,
+    /// free functions put into the globals
+    pub(crate) functions: Vec<LuaMember>,
+}
+
+pub(crate) struct LuaClass {
+    pub(crate) address: GlobalIdent,
+    /// global holding the class table, also the name of the class in the stubs
+    pub(crate) name: String,
+    pub(crate) wrapper: String,
+    pub(crate) path: String,
+    pub(crate) doc: Option<String>,
+    pub(crate) fields: Vec<LuaMember>,
+    /// called on instances with `:`
+    pub(crate) methods: Vec<LuaMember>,
+    /// associated functions, variant constructors and unit variants of the class table
+    pub(crate) functions: Vec<LuaMember>,
+}
+
+pub(crate) struct LuaMember {
+    pub(crate) name: String,
+    /// `None` for fields and constants
+    pub(crate) params: Option<Vec<(String, ResolvedType)>>,
+    /// type of the field or the constant, return type of functions
+    pub(crate) ty: ResolvedType,
+    pub(crate) doc: Option<String>,
+    /// Rust statements registering the member
+    pub(crate) code: String,
+}
+
+pub(crate) struct LuaGenerator<'a> {
+    db: &'a Database,
+    types: BTreeMap<GlobalIdent, Exposed>,
+    fns: Vec<(GlobalIdent, String, ItemFn)>,
+    pub(crate) skipped: Vec<(String, String)>,
+}
+
+impl Database {
+    /// `mlua` bindings of the symbols: `UserData` wrappers of structs (public fields as getters and setters,
+    /// inherent methods) and enums (variants as constants or constructors), free functions and a `register`
+    /// function putting it all into the globals. modules stand for all their public items
+    pub fn lua_bindings(&self, symbols: &[GlobalIdent]) -> LuaBindings {
+        let mut generator = LuaGenerator::new(self, symbols);
+        let api = generator.api();
+        LuaBindings {
+            code: render_bindings(&api),
+            skipped: generator.skipped,
+        }
+    }
+}
+
+impl<'a> LuaGenerator<'a> {
+    pub(crate) fn new(db: &'a Database, symbols: &[GlobalIdent]) -> Self {
+        let mut generator = LuaGenerator {
+            db,
+            types: Default::default(),
+            fns: vec![],
+            skipped: vec![],
+        };
+        for target in db.expand_symbols(symbols, &mut generator.skipped) {
+            generator.add_target(&target);
+        }
+        generator.name_wrappers();
+        generator
+    }
+
+    fn add_target(&mut self, address: &GlobalIdent) {
+        let Some(binding) = self.db.decls.find_value(address.to_parts()) else {
+            return;
+        };
+        let item = binding
+            .type_ast
+            .iter()
+            .chain(binding.non_type_ast.iter())
+            .find_map(|it| match &it.ast {
+                Ast::Real(item @ (Item::Struct(_) | Item::Enum(_) | Item::Fn(_))) => Some(item.clone()),
+                _ => None,
+            });
+        let Some(item) = item else {
+            self.skip(address, "only structs, enums and functions are supported");
+            return;
+        };
+        let Some(path) = self.db.public_path(address) else {
+            self.skip(address, "not reachable from outside of its crate");
+            return;
+        };
+        let path = format!("::{}", path);
+        match item {
+            Item::Fn(it) => {
+                if has_params(&it.sig.generics) {
+                    self.skip(address, "generic functions are not supported");
+                } else if it.sig.asyncness.is_some() || it.sig.unsafety.is_some() {
+                    self.skip(address, "async and unsafe functions are not supported");
+                } else {
+                    self.fns.push((address.clone(), path, it));
+                }
+            }
+            Item::Struct(syn::ItemStruct { ref generics, .. }) | Item::Enum(syn::ItemEnum { ref generics, .. })
+                if !generics.params.is_empty() =>
+            {
+                self.skip(address, "generic types are not supported");
+            }
+            item => {
+                self.types.insert(
+                    address.clone(),
+                    Exposed {
+                        address: address.clone(),
+                        path,
+                        wrapper: String::new(),
+                        item,
+                    },
+                );
+            }
+        }
+    }
+
+    /// `LuaPoint`, or `LuaWorldPoint` if another `Point` is exposed too, see `unique_names`
+    fn name_wrappers(&mut self) {
+        let names = unique_names(self.types.keys());
+        for it in self.types.values_mut() {
+            it.wrapper = format!("Lua{}", names[&it.address]);
+        }
+    }
+
+    fn skip(&mut self, what: impl Display, reason: &str) {
+        println!("skip {} for lua: {}", what, reason);
+        self.skipped.push((what.to_string(), reason.to_string()));
+    }
+
+    fn implements(&self, ty: &ResolvedType, trait_: &str) -> bool {
+        let trait_ = TraitRef {
+            address: GlobalIdent::from_qualified_name(trait_),
+            args: vec![],
+        };
+        self.db.implements(ty, &trait_).is_match()
+    }
+
+    fn wrapper_of(&self, ty: &ResolvedType) -> Option<&str> {
+        match ty {
+            ResolvedType::Path { address, args } if args.is_empty() => {
+                self.types.get(address).map(|it| it.wrapper.as_str())
+            }
+            _ => None,
+        }
+    }
+
+    /// conversion of a Lua value to the type of a param
+    fn arg_conversion(&self, ty: &ResolvedType) -> Result<Conversion, String> {
+        match ty {
+            ResolvedType::Primitive(it) if is_lua_primitive(*it) => Ok(Conversion::new(it.name(), "$")),
+            ResolvedType::Reference { mutable: false, elem } if **elem == ResolvedType::Primitive(Primitive::Str) => {
+                Ok(Conversion::new("String", "&$"))
+            }
+            ResolvedType::Reference { mutable, elem } => match (self.wrapper_of(elem), mutable) {
+                (Some(wrapper), true) => Ok(Conversion::new(format!("mlua::UserDataRefMut<{}>", wrapper), "&mut $.0")),
+                (Some(wrapper), false) => Ok(Conversion::new(format!("mlua::UserDataRef<{}>", wrapper), "&$.0")),
+                (None, false) => match self.arg_conversion(elem)? {
+                    it if it.expr == "$" => Ok(Conversion::new(it.lua_ty, "&$")),
+                    _ => Err(format!("{} is not supported", ty)),
+                },
+                (None, true) => Err(format!("{} is not supported", ty)),
+            },
+            ResolvedType::Path { address, args } => match (address.to_string().as_str(), args.as_slice()) {
+                ("alloc::string::String", []) => Ok(Conversion::new("String", "$")),
+                ("core::option::Option", [inner]) => {
+                    let inner = self.owned_arg_conversion(inner)?;
+                    Ok(Conversion::new(
+                        format!("Option<{}>", inner.lua_ty),
+                        map_expr("$.map(|it| {})", &inner),
+                    ))
+                }
+                ("alloc::vec::Vec", [inner]) => {
+                    let inner = self.owned_arg_conversion(inner)?;
+                    Ok(Conversion::new(
+                        format!("Vec<{}>", inner.lua_ty),
+                        map_expr("$.into_iter().map(|it| {}).collect()", &inner),
+                    ))
+                }
+                _ => match self.wrapper_of(ty) {
+                    Some(_) if !self.implements(ty, CLONE) => Err(format!("{} is taken by value but is not Clone", ty)),
+                    Some(wrapper) => Ok(Conversion::new(
+                        format!("mlua::UserDataRef<{}>", wrapper),
+                        if self.implements(ty, COPY) { "$.0" } else { "$.0.clone()" },
+                    )),
+                    None => Err(format!("{} is not supported", ty)),
+                },
+            },
+            _ => Err(format!("{} is not supported", ty)),
+        }
+    }
+
+    fn owned_arg_conversion(&self, ty: &ResolvedType) -> Result<Conversion, String> {
+        if let ResolvedType::Reference { .. } = ty {
+            return Err(format!("borrowed {} inside Option or Vec is not supported", ty));
+        }
+        self.arg_conversion(ty)
+    }
+
+    /// conversion of a returned value to something `mlua` can pass to Lua
+    fn return_conversion(&self, ty: &ResolvedType) -> Result<Conversion, String> {
+        match ty {
+            ResolvedType::Primitive(it) if is_lua_primitive(*it) => Ok(Conversion::new(it.name(), "$")),
+            ResolvedType::Reference { elem, .. } => match &**elem {
+                ResolvedType::Primitive(Primitive::Str) => Ok(Conversion::new("String", "$.to_string()")),
+                elem if !self.implements(elem, CLONE) => Err(format!("{} is returned by reference but is not Clone", elem)),
+                elem => {
+                    let inner = self.return_conversion(elem)?;
+                    let value = if self.implements(elem, COPY) { "*$" } else { "$.clone()" };
+                    Ok(Conversion::new(inner.lua_ty.clone(), inner.apply(value)))
+                }
+            },
+            ResolvedType::Path { address, args } => match (address.to_string().as_str(), args.as_slice()) {
+                ("alloc::string::String", []) => Ok(Conversion::new("String", "$")),
+                ("core::option::Option", [inner]) => {
+                    let inner = self.return_conversion(inner)?;
+                    Ok(Conversion::new(
+                        format!("Option<{}>", inner.lua_ty),
+                        map_expr("$.map(|it| {})", &inner),
+                    ))
+                }
+                ("alloc::vec::Vec", [inner]) => {
+                    let inner = self.return_conversion(inner)?;
+                    Ok(Conversion::new(
+                        format!("Vec<{}>", inner.lua_ty),
+                        map_expr("$.into_iter().map(|it| {}).collect::<Vec<_>>()", &inner),
+                    ))
+                }
+                _ => match self.wrapper_of(ty) {
+                    Some(wrapper) => Ok(Conversion::new(wrapper, format!("{}($)", wrapper))),
+                    None => Err(format!("{} is not supported", ty)),
+                },
+            },
+            _ => Err(format!("{} is not supported", ty)),
+        }
+    }
+
+    /// like `return_conversion`, also accepting tuples (as multiple values) and turning `Err` of `Result<T, E: Display>` into a Lua error
+    fn output_conversion(&self, ty: &ResolvedType) -> Result<Conversion, String> {
+        if let ResolvedType::Path { address, args } = ty {
+            if let ("core::result::Result", [ok, err]) = (address.to_string().as_str(), args.as_slice()) {
+                if !self.implements(err, DISPLAY) {
+                    return Err(format!("error {} is not Display", err));
+                }
+                let ok = self.output_conversion(ok)?;
+                return Ok(Conversion::new(
+                    ok.lua_ty.clone(),
+                    ok.apply("$.map_err(|e| mlua::Error::RuntimeError(e.to_string()))?"),
+                ));
+            }
+        }
+        match ty {
+            ResolvedType::Tuple(items) if items.is_empty() => Ok(Conversion::new("()", "$")),
+            // returned as multiple values
+            ResolvedType::Tuple(items) => {
+                let items = items.iter().map(|it| self.return_conversion(it)).collect::<Result<Vec<_>, _>>()?;
+                let lua_ty = format!("({})", items.iter().map(|it| it.lua_ty.as_str()).collect::<Vec<_>>().join(", "));
+                if items.iter().all(|it| it.expr == "$") {
+                    return Ok(Conversion::new(lua_ty, "$"));
+                }
+                let names = (0..items.len()).map(|i| format!("it{}", i)).collect::<Vec<_>>();
+                let values = items.iter().zip(names.iter()).map(|(it, name)| it.apply(name)).collect::<Vec<_>>();
+                let tuple = |items: &[String]| match items.len() {
+                    1 => format!("({},)", items[0]),
+                    _ => format!("({})", items.join(", ")),
+                };
+                Ok(Conversion::new(
+                    lua_ty,
+                    format!("{{ let {} = $; {} }}", tuple(&names), tuple(&values)),
+                ))
+            }
+            _ => self.return_conversion(ty),
+        }
+    }
+
+    /// `(name, score): (String, u32)` closure param and the converted args of the call
+    fn lua_args(&self, inputs: &[(String, ResolvedType)]) -> Result<(String, Vec<String>), String> {
+        let mut names = vec![];
+        let mut types = vec![];
+        let mut args = vec![];
+        for (i, (name, ty)) in inputs.iter().enumerate() {
+            let conversion = self.arg_conversion(ty).map_err(|it| format!("param {}: {}", name, it))?;
+            let name = match name.as_str() {
+                "this" | "lua" | "_" => format!("arg{}", i),
+                it if syn::parse_str::<syn::Ident>(it).is_ok() => it.to_string(),
+                _ => format!("arg{}", i),
+            };
+            args.push(conversion.apply(&name));
+            names.push(name);
+            types.push(conversion.lua_ty);
+        }
+        let tuple = |items: Vec<String>| match items.len() {
+            1 => format!("({},)", items[0]),
+            _ => format!("({})", items.join(", ")),
+        };
+        Ok((format!("{}: {}", tuple(names), tuple(types)), args))
+    }
+
+    /// classes and functions which can be bound, members with unsupported signatures are skipped
+    pub(crate) fn api(&mut self) -> LuaApi {
+        let mut api = LuaApi::default();
+        let addresses = self.types.keys().cloned().collect::<Vec<_>>();
+        for address in addresses {
+            if let Some(it) = self.class(&address) {
+                api.classes.push(it);
+            }
+        }
+        for (address, path, item) in self.fns.clone() {
+            let inputs = item
+                .sig
+                .inputs
+                .iter()
+                .enumerate()
+                .filter_map(|(i, it)| match it {
+                    FnArg::Typed(it) => Some((pat_name(&it.pat, i), self.db.normalize(&self.db.resolve_type(&it.ty)))),
+                    FnArg::Receiver(_) => None,
+                })
+                .collect::<Vec<_>>();
+            let output = self.db.normalize(&self.db.resolve_return_type(&item.sig.output));
+            let name = item.sig.ident.to_string();
+            match self.function_expr(&path, &inputs, &output) {
+                Ok(it) => api.functions.push(LuaMember {
+                    code: format!("globals.set({:?}, {})?;", name, it),
+                    name,
+                    params: Some(inputs),
+                    ty: output,
+                    doc: doc_of(&item.attrs),
+                }),
+                Err(reason) => self.skip(&address, &reason),
+            }
+        }
+        api
+    }
+
+    /// `lua.create_function(...)?` calling `path`
+    fn function_expr(&self, path: &str, inputs: &[(String, ResolvedType)], output: &ResolvedType) -> Result<String, String> {
+        let (params, args) = self.lua_args(inputs)?;
+        let output = self.output_conversion(output).map_err(|it| format!("output: {}", it))?;
+        let call = format!("{}({})", path, args.join(", "));
+        Ok(format!("lua.create_function(|_, {}| {})?", params, output.body(&call)))
+    }
+
+    fn class(&mut self, address: &GlobalIdent) -> Option<LuaClass> {
+        let exposed = &self.types[address];
+        let (wrapper, path, item) = (exposed.wrapper.clone(), exposed.path.clone(), exposed.item.clone());
+        let ty = ResolvedType::Path {
+            address: address.clone(),
+            args: vec![],
+        };
+        let Some(api) = self.db.monomorphize(&ty) else {
+            self.skip(address, "cannot be monomorphized");
+            return None;
+        };
+        let (attrs, field_docs) = match &item {
+            Item::Struct(it) => (&it.attrs, it.fields.iter().map(|it| doc_of(&it.attrs)).collect()),
+            Item::Enum(it) => (&it.attrs, vec![]),
+            _ => unreachable!(),
+        };
+        let mut class = LuaClass {
+            address: address.clone(),
+            name: wrapper["Lua".len()..].to_string(),
+            wrapper: wrapper.clone(),
+            path: path.clone(),
+            doc: doc_of(attrs),
+            fields: vec![],
+            methods: vec![],
+            functions: vec![],
+        };
+        for (field, doc) in api.fields.iter().zip(field_docs.into_iter().chain(std::iter::repeat(None))) {
+            if !field.public {
+                continue;
+            }
+            let member = format!("{}.{}", address, field.name);
+            if !self.implements(&field.ty, CLONE) {
+                self.skip(&member, &format!("{} is not Clone", field.ty));
+                continue;
+            }
+            let (getter, setter) = match (self.return_conversion(&field.ty), self.arg_conversion(&field.ty)) {
+                (Ok(getter), Ok(setter)) => (getter, setter),
+                (Err(reason), _) | (_, Err(reason)) => {
+                    self.skip(&member, &reason);
+                    continue;
+                }
+            };
+            let value = format!("this.0.{}", field.name);
+            let copied = if self.implements(&field.ty, COPY) {
+                value.clone()
+            } else {
+                format!("{}.clone()", value)
+            };
+            class.fields.push(LuaMember {
+                name: field.name.clone(),
+                params: None,
+                ty: field.ty.clone(),
+                doc,
+                code: format!(
+                    "fields.add_field_method_get({:?}, |_, this| Ok({}));\n\
+                     fields.add_field_method_set({:?}, |_, this, value: {}| {{\n    {} = {};\n    Ok(())\n}});",
+                    field.name,
+                    getter.apply(&copied),
+                    field.name,
+                    setter.lua_ty,
+                    value,
+                    setter.apply("value")
+                ),
+            });
+        }
+        if let Item::Enum(it) = &item {
+            let arms = it
+                .variants
+                .iter()
+                .map(|v| format!("{}::{}{} => {:?}", path, v.ident, variant_pattern(&v.fields), v.ident.to_string()))
+                .collect::<Vec<_>>();
+            let names = it.variants.iter().map(|v| v.ident.to_string()).collect::<Vec<_>>();
+            class.fields.push(LuaMember {
+                name: "variant".to_string(),
+                params: None,
+                ty: ResolvedType::Reference {
+                    mutable: false,
+                    elem: Box::new(ResolvedType::Primitive(Primitive::Str)),
+                },
+                doc: Some(format!("name of the variant: {}", names.join(", "))),
+                code: format!(
+                    "fields.add_field_method_get(\"variant\", |_, this| Ok(match &this.0 {{ {} }}));",
+                    arms.join(", ")
+                ),
+            });
+            for v in it.variants.iter() {
+                let member = format!("{}::{}", address, v.ident);
+                let variant = format!("{}::{}", path, v.ident);
+                let name = v.ident.to_string();
+                if let Fields::Unit = v.fields {
+                    class.functions.push(LuaMember {
+                        code: format!("class.set({:?}, {}({}))?;", name, wrapper, variant),
+                        name,
+                        params: None,
+                        ty: ty.clone(),
+                        doc: doc_of(&v.attrs),
+                    });
+                    continue;
+                }
+                let inputs = v
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, f)| {
+                        let name = f.ident.as_ref().map(|it| it.to_string()).unwrap_or_else(|| format!("arg{}", i));
+                        (name, self.db.normalize(&self.db.resolve_type(&f.ty)))
+                    })
+                    .collect::<Vec<_>>();
+                let (params, args) = match self.lua_args(&inputs) {
+                    Ok(it) => it,
+                    Err(reason) => {
+                        self.skip(&member, &reason);
+                        continue;
+                    }
+                };
+                let value = match &v.fields {
+                    Fields::Named(_) => {
+                        let args = inputs.iter().zip(args.iter()).map(|((name, _), arg)| match arg == name {
+                            true => name.clone(),
+                            false => format!("{}: {}", name, arg),
+                        });
+                        format!("{} {{ {} }}", variant, args.collect::<Vec<_>>().join(", "))
+                    }
+                    _ => format!("{}({})", variant, args.join(", ")),
+                };
+                class.functions.push(LuaMember {
+                    code: format!(
+                        "class.set({:?}, lua.create_function(|_, {}| Ok({}({})))?)?;",
+                        name, params, wrapper, value
+                    ),
+                    name,
+                    params: Some(inputs),
+                    ty: ty.clone(),
+                    doc: doc_of(&v.attrs),
+                });
+            }
+        }
+        for method in api.methods.iter().filter(|it| it.trait_.is_none() && it.public) {
+            let member = format!("{}::{}", address, method.name);
+            let code = match self.method_registration(&ty, &path, method) {
+                Ok(it) => it,
+                Err(reason) => {
+                    self.skip(&member, &reason);
+                    continue;
+                }
+            };
+            let it = LuaMember {
+                name: method.name.clone(),
+                params: Some(method.inputs.clone()),
+                ty: method.output.clone(),
+                doc: self.method_doc(&method.impl_, &method.name),
+                code,
+            };
+            match method.receiver {
+                Some(_) => class.methods.push(it),
+                None => class.functions.push(it),
+            }
+        }
+        Some(class)
+    }
+
+    /// `methods.add_method(...)` for methods, `class.set(...)` for associated functions
+    fn method_registration(&self, ty: &ResolvedType, path: &str, method: &MonoMethod) -> Result<String, String> {
+        if has_resolved_params(method) {
+            return Err("generic methods are not supported".to_string());
+        }
+        let Some(receiver) = &method.receiver else {
+            let function = self.function_expr(&format!("{}::{}", path, method.name), &method.inputs, &method.output)?;
+            return Ok(format!("class.set({:?}, {})?;", method.name, function));
+        };
+        let (register, this) = match receiver {
+            ResolvedType::Reference { mutable: false, elem } if **elem == *ty => ("add_method", "this.0"),
+            ResolvedType::Reference { mutable: true, elem } if **elem == *ty => ("add_method_mut", "this.0"),
+            it if it == ty && self.implements(ty, CLONE) => ("add_method", "this.0.clone()"),
+            it if it == ty => return Err(format!("takes self by value but {} is not Clone", ty)),
+            it => return Err(format!("receiver {} is not supported", it)),
+        };
+        let (params, args) = self.lua_args(&method.inputs)?;
+        let output = self.output_conversion(&method.output).map_err(|it| format!("output: {}", it))?;
+        let call = format!("{}.{}({})", this, method.name, args.join(", "));
+        Ok(format!(
+            "methods.{}({:?}, |_, this, {}| {});",
+            register,
+            method.name,
+            params,
+            output.body(&call)
+        ))
+    }
+
+    fn method_doc(&self, impl_: &GlobalIdent, name: &str) -> Option<String> {
+        let decl = self.db.decls.find_value(impl_.to_parts())?.non_type_ast.as_ref()?;
+        let Ast::Real(Item::Impl(it)) = &decl.ast else {
+            return None;
+        };
+        it.items.iter().find_map(|it| match it {
+            ImplItem::Fn(it) if it.sig.ident == name => doc_of(&it.attrs),
+            _ => None,
+        })
+    }
+}
+
+fn render_bindings(api: &LuaApi) -> String {
+    let mut out = String::new();
+    writeln!(out, "// generated by syg from the resolved signatures").unwrap();
+    let mut registrations = vec![];
+    for class in api.classes.iter() {
+        writeln!(out).unwrap();
+        writeln!(out, "pub struct {}(pub {});", class.wrapper, class.path).unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "impl mlua::UserData for {} {{", class.wrapper).unwrap();
+        if !class.fields.is_empty() {
+            writeln!(out, "    fn add_fields<F: mlua::UserDataFields<Self>>(fields: &mut F) {{").unwrap();
+            for it in class.fields.iter() {
+                write_indented(&mut out, &it.code, 2);
+            }
+            writeln!(out, "    }}").unwrap();
+        }
+        if !class.methods.is_empty() {
+            if !class.fields.is_empty() {
+                writeln!(out).unwrap();
+            }
+            writeln!(out, "    fn add_methods<M: mlua::UserDataMethods<Self>>(methods: &mut M) {{").unwrap();
+            for it in class.methods.iter() {
+                write_indented(&mut out, &it.code, 2);
+            }
+            writeln!(out, "    }}").unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+        if !class.functions.is_empty() {
+            registrations.push("let class = lua.create_table()?;".to_string());
+            registrations.extend(class.functions.iter().map(|it| it.code.clone()));
+            registrations.push(format!("globals.set({:?}, class)?;", class.name));
+        }
+    }
+    registrations.extend(api.functions.iter().map(|it| it.code.clone()));
+    writeln!(out).unwrap();
+    writeln!(out, "pub fn register(lua: &mlua::Lua) -> mlua::Result<()> {{").unwrap();
+    writeln!(out, "    let globals = lua.globals();").unwrap();
+    for it in registrations {
+        write_indented(&mut out, &it, 1);
+    }
+    writeln!(out, "    Ok(())").unwrap();
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+fn write_indented(out: &mut String, code: &str, level: usize) {
+    for line in code.lines() {
+        writeln!(out, "{}{}", "    ".repeat(level), line).unwrap();
+    }
+}
+
+fn is_lua_primitive(it: Primitive) -> bool {
+    !matches!(
+        it,
+        Primitive::Char | Primitive::Str | Primitive::I128 | Primitive::U128 | Primitive::F16 | Primitive::F128
+    )
+}
+
+fn has_params(generics: &Generics) -> bool {
+    generics.params.iter().any(|it| !matches!(it, GenericParam::Lifetime(_)))
+}
+
+fn has_resolved_params(method: &MonoMethod) -> bool {
+    method
+        .generics
+        .params
+        .iter()
+        .any(|it| !matches!(it, ResolvedGenericParam::Lifetime { .. }))
+}
+
+/// `$.map(|it| LuaPet(it))`, or just `$` if the items need no conversion
+fn map_expr(template: &str, inner: &Conversion) -> String {
+    if inner.expr == "$" {
+        return "$".to_string();
+    }
+    template.replace("{}", &inner.apply("it"))
+}
+
+fn pat_name(pat: &Pat, i: usize) -> String {
+    match pat {
+        Pat::Ident(it) => it.ident.to_string(),
+        _ => format!("arg{}", i),
+    }
+}
+
+fn variant_pattern(fields: &Fields) -> &'static str {
+    match fields {
+        Fields::Named(_) => " { .. }",
+        Fields::Unnamed(_) => "(..)",
+        Fields::Unit => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_support::compiled, GlobalIdent};
+
+    const SOURCE: &str = "
+        pub mod shapes {
+            pub struct Point { pub x: f32, secret: u8 }
+            impl Point {
+                pub fn new(x: f32) -> Self { todo!() }
+                pub fn len(&self) -> f32 { 0.0 }
+                pub fn scale(&mut self, k: f32) {}
+                fn hidden(&self) {}
+            }
+            pub enum Kind { A, B(u8) }
+            pub fn area(p: &Point, name: &str) -> f64 { 0.0 }
+            pub struct Gen<T>(T);
+        }
+        pub mod a { pub mod geo { #[derive(Clone)] pub struct Point; } }
+        pub mod b { pub mod geo { #[derive(Clone)] pub struct Point; } }
+    ";
+
+    #[test]
+    fn bindings_of_structs_and_functions() {
+        let db = compiled(SOURCE);
+        let symbols = ["c::shapes::Point", "c::shapes::Kind::A", "c::shapes::area", "c::shapes::Gen"];
+        let bindings = db.lua_bindings(&symbols.map(GlobalIdent::from_qualified_name));
+        let code = &bindings.code;
+        assert!(code.contains("pub struct LuaPoint(pub ::c::shapes::Point);"), "{}", code);
+        assert!(code.contains(r#"fields.add_field_method_get("x""#), "{}", code);
+        assert!(!code.contains("secret"), "{}", code);
+        assert!(code.contains(r#"methods.add_method("len""#), "{}", code);
+        assert!(code.contains(r#"methods.add_method_mut("scale""#), "{}", code);
+        assert!(!code.contains("hidden"), "{}", code);
+        assert!(code.contains(r#"class.set("new""#), "{}", code);
+        assert!(code.contains("::c::shapes::area(&p.0, &name)"), "{}", code);
+        let skipped = bindings.skipped.iter().map(|(it, _)| it.as_str()).collect::<Vec<_>>();
+        assert_eq!(skipped, ["c::shapes::Gen", "c::shapes::Kind::A"]);
+    }
+
+    #[test]
+    fn wrappers_of_same_named_types_are_prefixed() {
+        let db = compiled(SOURCE);
+        let symbols = ["c::a::geo::Point", "c::b::geo::Point", "c::shapes::Kind"];
+        let code = db.lua_bindings(&symbols.map(GlobalIdent::from_qualified_name)).code;
+        assert!(code.contains("pub struct LuaAGeoPoint(pub ::c::a::geo::Point);"), "{}", code);
+        assert!(code.contains("pub struct LuaBGeoPoint(pub ::c::b::geo::Point);"), "{}", code);
+        assert!(code.contains("pub struct LuaKind(pub ::c::shapes::Kind);"), "{}", code);
+    }
+}
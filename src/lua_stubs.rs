@@ -0,0 +1,296 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use crate::{
+    lua_bindings::{LuaApi, LuaGenerator, LuaMember},
+    Database, GlobalIdent, Primitive, ResolvedType,
+};
+
+const LUA_KEYWORDS: [&str; 22] = [
+    "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "goto", "if", "in", "local", "nil",
+    "not", "or", "repeat", "return", "then", "true", "until", "while",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LuaStubFormat {
+    /// `---@class`, `---@field`, `---@param` and `---@return` annotations of LuaLS and EmmyLua
+    LuaLs,
+    /// `declare class` definitions of Luau (`.d.luau`)
+    Luau,
+}
+
+/// editor annotations of the Lua API, see `Database::lua_stubs`
+#[derive(Debug, Clone, Default)]
+pub struct LuaStubs {
+    pub code: String,
+    /// items and members left out because of unsupported signatures, with the reason
+    pub skipped: Vec<(String, String)>,
+}
+
+impl Database {
+    /// annotations of exactly what `lua_bindings` binds for the same symbols, with doc comments
+    pub fn lua_stubs(&self, symbols: &[GlobalIdent], format: LuaStubFormat) -> LuaStubs {
+        let mut generator = LuaGenerator::new(self, symbols);
+        let api = generator.api();
+        let stubs = StubWriter {
+            classes: api.classes.iter().map(|it| (it.address.clone(), it.name.clone())).collect(),
+            format,
+        };
+        LuaStubs {
+            code: match format {
+                LuaStubFormat::LuaLs => stubs.lua_ls(&api),
+                LuaStubFormat::Luau => stubs.luau(&api),
+            },
+            skipped: generator.skipped,
+        }
+    }
+}
+
+struct StubWriter {
+    /// bound types to their class names
+    classes: BTreeMap<GlobalIdent, String>,
+    format: LuaStubFormat,
+}
+
+impl StubWriter {
+    fn lua_ls(&self, api: &LuaApi) -> String {
+        let mut out = String::new();
+        writeln!(out, "---@meta").unwrap();
+        writeln!(out, "-- generated by syg from the resolved signatures").unwrap();
+        for class in api.classes.iter() {
+            writeln!(out).unwrap();
+            write_doc(&mut out, &class.doc, "");
+            writeln!(out, "---@class {}", class.name).unwrap();
+            for it in class.fields.iter() {
+                write!(out, "---@field {} {}", field_name(&it.name), self.lua_type(&it.ty)).unwrap();
+                match &it.doc {
+                    Some(doc) => writeln!(out, " {}", doc.replace('\n', " ")).unwrap(),
+                    None => writeln!(out).unwrap(),
+                }
+            }
+            writeln!(out, "{} = {{}}", class.name).unwrap();
+            for it in class.functions.iter() {
+                writeln!(out).unwrap();
+                match &it.params {
+                    Some(params) => self.lua_ls_function(&mut out, &format!("{}.{}", class.name, it.name), params, it),
+                    None => {
+                        write_doc(&mut out, &it.doc, "");
+                        writeln!(out, "---@type {}", self.lua_type(&it.ty)).unwrap();
+                        writeln!(out, "{}.{} = nil", class.name, it.name).unwrap();
+                    }
+                }
+            }
+            for it in class.methods.iter() {
+                writeln!(out).unwrap();
+                let params = it.params.as_deref().unwrap_or_default();
+                self.lua_ls_function(&mut out, &format!("{}:{}", class.name, it.name), params, it);
+            }
+        }
+        for it in api.functions.iter() {
+            writeln!(out).unwrap();
+            self.lua_ls_function(&mut out, &it.name, it.params.as_deref().unwrap_or_default(), it);
+        }
+        out
+    }
+
+    fn lua_ls_function(&self, out: &mut String, name: &str, params: &[(String, ResolvedType)], member: &LuaMember) {
+        write_doc(out, &member.doc, "");
+        for (param, ty) in params {
+            writeln!(out, "---@param {} {}", param_name(param), self.lua_type(ty)).unwrap();
+        }
+        for ty in returned(&member.ty) {
+            writeln!(out, "---@return {}", self.lua_type(&ty)).unwrap();
+        }
+        let params = params.iter().map(|(it, _)| param_name(it)).collect::<Vec<_>>();
+        writeln!(out, "function {}({}) end", name, params.join(", ")).unwrap();
+    }
+
+    fn luau(&self, api: &LuaApi) -> String {
+        let mut out = String::new();
+        writeln!(out, "-- generated by syg from the resolved signatures").unwrap();
+        for class in api.classes.iter() {
+            writeln!(out).unwrap();
+            write_doc(&mut out, &class.doc, "");
+            writeln!(out, "declare class {}", class.name).unwrap();
+            for it in class.fields.iter() {
+                write_doc(&mut out, &it.doc, "    ");
+                writeln!(out, "    {}: {}", field_name(&it.name), self.lua_type(&it.ty)).unwrap();
+            }
+            for it in class.methods.iter() {
+                write_doc(&mut out, &it.doc, "    ");
+                let mut params = vec!["self".to_string()];
+                params.extend(self.luau_params(it.params.as_deref().unwrap_or_default()));
+                writeln!(out, "    function {}({}): {}", it.name, params.join(", "), self.luau_return(&it.ty)).unwrap();
+            }
+            writeln!(out, "end").unwrap();
+            if class.functions.is_empty() {
+                continue;
+            }
+            writeln!(out).unwrap();
+            writeln!(out, "declare {}: {{", class.name).unwrap();
+            for it in class.functions.iter() {
+                write_doc(&mut out, &it.doc, "    ");
+                match &it.params {
+                    Some(params) => writeln!(
+                        out,
+                        "    {}: ({}) -> {},",
+                        it.name,
+                        self.luau_params(params).join(", "),
+                        self.luau_return(&it.ty)
+                    )
+                    .unwrap(),
+                    None => writeln!(out, "    {}: {},", it.name, self.lua_type(&it.ty)).unwrap(),
+                }
+            }
+            writeln!(out, "}}").unwrap();
+        }
+        for it in api.functions.iter() {
+            writeln!(out).unwrap();
+            write_doc(&mut out, &it.doc, "");
+            let params = self.luau_params(it.params.as_deref().unwrap_or_default());
+            writeln!(out, "declare function {}({}): {}", it.name, params.join(", "), self.luau_return(&it.ty)).unwrap();
+        }
+        out
+    }
+
+    fn luau_params(&self, params: &[(String, ResolvedType)]) -> Vec<String> {
+        params
+            .iter()
+            .map(|(name, ty)| format!("{}: {}", param_name(name), self.lua_type(ty)))
+            .collect()
+    }
+
+    /// `()`, `number` or `(number, Point)` for multiple values
+    fn luau_return(&self, ty: &ResolvedType) -> String {
+        match returned(ty).as_slice() {
+            [it] => self.lua_type(it),
+            items => format!("({})", items.iter().map(|it| self.lua_type(it)).collect::<Vec<_>>().join(", ")),
+        }
+    }
+
+    /// type annotation of a value converted by the bindings
+    fn lua_type(&self, ty: &ResolvedType) -> String {
+        let luau = self.format == LuaStubFormat::Luau;
+        match ty {
+            ResolvedType::Primitive(Primitive::Bool) => "boolean".to_string(),
+            ResolvedType::Primitive(Primitive::Str | Primitive::Char) => "string".to_string(),
+            ResolvedType::Primitive(Primitive::F16 | Primitive::F32 | Primitive::F64 | Primitive::F128) => {
+                "number".to_string()
+            }
+            ResolvedType::Primitive(_) if luau => "number".to_string(),
+            ResolvedType::Primitive(_) => "integer".to_string(),
+            ResolvedType::Reference { elem, .. } => self.lua_type(elem),
+            ResolvedType::Tuple(items) if items.is_empty() => "nil".to_string(),
+            ResolvedType::Tuple(_) if luau => "{any}".to_string(),
+            ResolvedType::Tuple(items) => {
+                format!("[{}]", items.iter().map(|it| self.lua_type(it)).collect::<Vec<_>>().join(", "))
+            }
+            ResolvedType::Slice(elem) | ResolvedType::Array { elem, .. } => self.array_type(elem),
+            ResolvedType::Path { address, args } => match (address.to_string().as_str(), args.as_slice()) {
+                ("alloc::string::String", []) => "string".to_string(),
+                ("core::option::Option", [inner]) => format!("{}?", parenthesized(self.lua_type(inner))),
+                ("alloc::vec::Vec", [inner]) => self.array_type(inner),
+                ("core::result::Result", [ok, _]) => self.lua_type(ok),
+                _ => match self.classes.get(address) {
+                    Some(it) if args.is_empty() => it.clone(),
+                    _ => "any".to_string(),
+                },
+            },
+            _ => "any".to_string(),
+        }
+    }
+
+    fn array_type(&self, elem: &ResolvedType) -> String {
+        match self.format {
+            LuaStubFormat::LuaLs => format!("{}[]", parenthesized(self.lua_type(elem))),
+            LuaStubFormat::Luau => format!("{{{}}}", self.lua_type(elem)),
+        }
+    }
+}
+
+/// values a function returns to Lua: none for `()`, several for tuples, `Ok` of results
+fn returned(ty: &ResolvedType) -> Vec<ResolvedType> {
+    match ty {
+        ResolvedType::Path { address, args } if address.to_string() == "core::result::Result" && args.len() == 2 => {
+            returned(&args[0])
+        }
+        ResolvedType::Tuple(items) => items.clone(),
+        it => vec![it.clone()],
+    }
+}
+
+fn parenthesized(ty: String) -> String {
+    if ty.contains(' ') || ty.contains('|') {
+        format!("({})", ty)
+    } else {
+        ty
+    }
+}
+
+fn write_doc(out: &mut String, doc: &Option<String>, indent: &str) {
+    for line in doc.iter().flat_map(|it| it.lines()) {
+        writeln!(out, "{}--- {}", indent, line).unwrap();
+    }
+}
+
+fn param_name(name: &str) -> String {
+    if LUA_KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// tuple struct fields (`0`) aren't identifiers in Lua
+fn field_name(name: &str) -> String {
+    if name.starts_with(|it: char| it.is_ascii_digit()) || LUA_KEYWORDS.contains(&name) {
+        format!("[{:?}]", name)
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LuaStubFormat;
+    use crate::{test_support::compiled, GlobalIdent};
+
+    const SOURCE: &str = "
+        /// a point
+        pub struct Point { pub x: f32, pub tags: Vec<String> }
+        impl Point {
+            pub fn new(x: f32) -> Self { todo!() }
+            /// length of the vector
+            pub fn len(&self, end: Option<u8>) -> f32 { 0.0 }
+        }
+        pub fn describe(n: u32) -> String { todo!() }
+    ";
+
+    #[test]
+    fn lua_ls_annotations() {
+        let stubs = compiled(SOURCE).lua_stubs(&[GlobalIdent::from_qualified_name("c")], LuaStubFormat::LuaLs);
+        let code = &stubs.code;
+        for expected in [
+            "--- a point\n---@class Point\n---@field x number\n---@field tags string[]\nPoint = {}",
+            "---@param x number\n---@return Point\nfunction Point.new(x) end",
+            "--- length of the vector\n---@param end_ integer?\n---@return number\nfunction Point:len(end_) end",
+            "---@param n integer\n---@return string\nfunction describe(n) end",
+        ] {
+            assert!(code.contains(expected), "{}\nis not in\n{}", expected, code);
+        }
+        assert!(stubs.skipped.is_empty(), "{:?}", stubs.skipped);
+    }
+
+    #[test]
+    fn luau_declarations() {
+        let stubs = compiled(SOURCE).lua_stubs(&[GlobalIdent::from_qualified_name("c")], LuaStubFormat::Luau);
+        let code = &stubs.code;
+        for expected in [
+            "declare class Point\n    x: number\n    tags: {string}\n",
+            "    function len(self, end_: number?): number\nend",
+            "declare Point: {\n    new: (x: number) -> Point,\n}",
+            "declare function describe(n: number): string",
+        ] {
+            assert!(code.contains(expected), "{}\nis not in\n{}", expected, code);
+        }
+    }
+}
@@ -0,0 +1,597 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{
+    parse::{ParseStream, Parser},
+    Block, Expr, Item, Lifetime, Lit, Meta, Pat, Path, Stmt, Type, Visibility,
+};
+
+/// `macro_rules!` definition
+#[derive(Debug)]
+pub struct MacroRules {
+    pub name: String,
+    /// crate the macro is defined in, substituted for `$crate`
+    pub crate_: String,
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug)]
+struct Rule {
+    matcher: Vec<Matcher>,
+    transcriber: Vec<Transcriber>,
+}
+
+#[derive(Debug)]
+enum Matcher {
+    Token(TokenTree),
+    Group(Delimiter, Vec<Matcher>),
+    Fragment(String, FragmentKind),
+    Repeat(Vec<Matcher>, Option<TokenTree>, RepeatOp),
+}
+
+#[derive(Debug)]
+enum Transcriber {
+    Token(TokenTree),
+    Group(Delimiter, Vec<Transcriber>),
+    Var(Ident),
+    Crate,
+    Repeat(Vec<Transcriber>, Option<TokenTree>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RepeatOp {
+    ZeroOrMore,
+    OneOrMore,
+    ZeroOrOne,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum FragmentKind {
+    Ident,
+    Lifetime,
+    Literal,
+    Tt,
+    Ty,
+    Path,
+    Expr,
+    Pat,
+    PatParam,
+    Stmt,
+    Block,
+    Item,
+    Meta,
+    Vis,
+}
+
+impl FragmentKind {
+    fn from_name(name: &str) -> Option<FragmentKind> {
+        match name {
+            "ident" => Some(FragmentKind::Ident),
+            "lifetime" => Some(FragmentKind::Lifetime),
+            "literal" => Some(FragmentKind::Literal),
+            "tt" => Some(FragmentKind::Tt),
+            "ty" => Some(FragmentKind::Ty),
+            "path" => Some(FragmentKind::Path),
+            "expr" | "expr_2021" => Some(FragmentKind::Expr),
+            "pat" => Some(FragmentKind::Pat),
+            "pat_param" => Some(FragmentKind::PatParam),
+            "stmt" => Some(FragmentKind::Stmt),
+            "block" => Some(FragmentKind::Block),
+            "item" => Some(FragmentKind::Item),
+            "meta" => Some(FragmentKind::Meta),
+            "vis" => Some(FragmentKind::Vis),
+            _ => None,
+        }
+    }
+}
+
+/// tokens captured by a fragment, or by each iteration of the enclosing repetition
+#[derive(Debug, Clone)]
+enum Captured {
+    Tokens(Vec<TokenTree>),
+    Repeated(Vec<Captured>),
+}
+
+type Captures = BTreeMap<String, Captured>;
+
+impl MacroRules {
+    /// parses the body of `macro_rules! name { ... }`
+    pub fn parse(name: &str, crate_: &str, body: TokenStream) -> Option<MacroRules> {
+        let tokens = body.into_iter().collect::<Vec<_>>();
+        let mut rules = vec![];
+        let mut pos = 0;
+        while pos < tokens.len() {
+            let (TokenTree::Group(matcher), Some(TokenTree::Punct(eq)), Some(TokenTree::Punct(gt)), Some(TokenTree::Group(transcriber))) =
+                (&tokens[pos], tokens.get(pos + 1), tokens.get(pos + 2), tokens.get(pos + 3))
+            else {
+                println!("WARN: cannot parse rules of macro {}", name);
+                return None;
+            };
+            if eq.as_char() != '=' || gt.as_char() != '>' {
+                println!("WARN: cannot parse rules of macro {}", name);
+                return None;
+            }
+            rules.push(Rule {
+                matcher: parse_matcher(&matcher.stream().into_iter().collect::<Vec<_>>()),
+                transcriber: parse_transcriber(&transcriber.stream().into_iter().collect::<Vec<_>>()),
+            });
+            pos += 4;
+            if let Some(TokenTree::Punct(it)) = tokens.get(pos) {
+                if it.as_char() == ';' {
+                    pos += 1;
+                }
+            }
+        }
+        Some(MacroRules {
+            name: name.to_string(),
+            crate_: crate_.to_string(),
+            rules,
+        })
+    }
+
+    /// expands the invocation using the first matching rule
+    pub fn expand(&self, input: TokenStream) -> Option<TokenStream> {
+        let input = input.into_iter().collect::<Vec<_>>();
+        for rule in self.rules.iter() {
+            let mut captures = Captures::default();
+            if match_seq(&rule.matcher, &input, 0, &mut captures) == Some(input.len()) {
+                let mut output = vec![];
+                self.transcribe(&rule.transcriber, &captures, &mut output);
+                return Some(output.into_iter().collect());
+            }
+        }
+        None
+    }
+
+    fn transcribe(&self, elements: &[Transcriber], captures: &Captures, output: &mut Vec<TokenTree>) {
+        for element in elements {
+            match element {
+                Transcriber::Token(it) => output.push(it.clone()),
+                Transcriber::Group(delimiter, inner) => {
+                    let mut tokens = vec![];
+                    self.transcribe(inner, captures, &mut tokens);
+                    output.push(TokenTree::Group(Group::new(*delimiter, tokens.into_iter().collect())));
+                }
+                Transcriber::Var(name) => match captures.get(&name.to_string()) {
+                    Some(Captured::Tokens(it)) => output.extend(it.iter().cloned()),
+                    Some(Captured::Repeated(_)) => {
+                        println!("WARN: variable {} is still repeating at this depth in macro {}", name, self.name);
+                    }
+                    None => {
+                        // not a macro variable, e.g. a nested macro definition
+                        output.push(TokenTree::Punct(Punct::new('$', Spacing::Alone)));
+                        output.push(TokenTree::Ident(name.clone()));
+                    }
+                },
+                Transcriber::Crate => output.push(TokenTree::Ident(Ident::new(&self.crate_, Span::call_site()))),
+                Transcriber::Repeat(inner, separator) => {
+                    let mut names = vec![];
+                    collect_vars(inner, &mut names);
+                    let count = names
+                        .iter()
+                        .filter_map(|it| match captures.get(it) {
+                            Some(Captured::Repeated(it)) => Some(it.len()),
+                            _ => None,
+                        })
+                        .min();
+                    let Some(count) = count else {
+                        println!("WARN: repetition without repeating variables in macro {}", self.name);
+                        continue;
+                    };
+                    for i in 0..count {
+                        if i > 0 {
+                            output.extend(separator.clone());
+                        }
+                        let mut iteration = captures.clone();
+                        for name in names.iter() {
+                            if let Some(Captured::Repeated(it)) = captures.get(name) {
+                                iteration.insert(name.clone(), it[i].clone());
+                            }
+                        }
+                        self.transcribe(inner, &iteration, output);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn is_punct(token: Option<&TokenTree>, ch: char) -> bool {
+    matches!(token, Some(TokenTree::Punct(it)) if it.as_char() == ch)
+}
+
+fn repeat_op(token: Option<&TokenTree>) -> Option<RepeatOp> {
+    match token {
+        Some(TokenTree::Punct(it)) => match it.as_char() {
+            '*' => Some(RepeatOp::ZeroOrMore),
+            '+' => Some(RepeatOp::OneOrMore),
+            '?' => Some(RepeatOp::ZeroOrOne),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// separator and operator after `$(...)`, with the count of the tokens they take
+fn parse_repeat_suffix(tokens: &[TokenTree], pos: usize) -> (Option<TokenTree>, RepeatOp, usize) {
+    if let Some(op) = repeat_op(tokens.get(pos)) {
+        // `$(...)?+` is not valid, so `+` after an operator is a separator only if followed by another operator
+        if !(op != RepeatOp::ZeroOrOne && repeat_op(tokens.get(pos + 1)).is_some()) {
+            return (None, op, 1);
+        }
+    }
+    match (tokens.get(pos), repeat_op(tokens.get(pos + 1))) {
+        (Some(separator), Some(op)) => (Some(separator.clone()), op, 2),
+        _ => (None, RepeatOp::ZeroOrMore, 0),
+    }
+}
+
+fn parse_matcher(tokens: &[TokenTree]) -> Vec<Matcher> {
+    let mut result = vec![];
+    let mut pos = 0;
+    while pos < tokens.len() {
+        let token = &tokens[pos];
+        pos += 1;
+        if is_punct(Some(token), '$') {
+            match tokens.get(pos) {
+                Some(TokenTree::Ident(name)) if is_punct(tokens.get(pos + 1), ':') => {
+                    if let Some(TokenTree::Ident(kind)) = tokens.get(pos + 2) {
+                        match FragmentKind::from_name(&kind.to_string()) {
+                            Some(kind) => result.push(Matcher::Fragment(name.to_string(), kind)),
+                            None => println!("WARN: unknown fragment specifier {}", kind),
+                        }
+                        pos += 3;
+                        continue;
+                    }
+                }
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    let inner = parse_matcher(&group.stream().into_iter().collect::<Vec<_>>());
+                    let (separator, op, taken) = parse_repeat_suffix(tokens, pos + 1);
+                    result.push(Matcher::Repeat(inner, separator, op));
+                    pos += 1 + taken;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        match token {
+            TokenTree::Group(group) => result.push(Matcher::Group(
+                group.delimiter(),
+                parse_matcher(&group.stream().into_iter().collect::<Vec<_>>()),
+            )),
+            it => result.push(Matcher::Token(it.clone())),
+        }
+    }
+    result
+}
+
+fn parse_transcriber(tokens: &[TokenTree]) -> Vec<Transcriber> {
+    let mut result = vec![];
+    let mut pos = 0;
+    while pos < tokens.len() {
+        let token = &tokens[pos];
+        pos += 1;
+        if is_punct(Some(token), '$') {
+            match tokens.get(pos) {
+                Some(TokenTree::Ident(name)) => {
+                    result.push(if name == "crate" {
+                        Transcriber::Crate
+                    } else {
+                        Transcriber::Var(name.clone())
+                    });
+                    pos += 1;
+                    continue;
+                }
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    let inner = parse_transcriber(&group.stream().into_iter().collect::<Vec<_>>());
+                    let (separator, _op, taken) = parse_repeat_suffix(tokens, pos + 1);
+                    result.push(Transcriber::Repeat(inner, separator));
+                    pos += 1 + taken;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        match token {
+            TokenTree::Group(group) => result.push(Transcriber::Group(
+                group.delimiter(),
+                parse_transcriber(&group.stream().into_iter().collect::<Vec<_>>()),
+            )),
+            it => result.push(Transcriber::Token(it.clone())),
+        }
+    }
+    result
+}
+
+fn collect_vars(elements: &[Transcriber], names: &mut Vec<String>) {
+    for element in elements {
+        match element {
+            Transcriber::Var(it) => names.push(it.to_string()),
+            Transcriber::Group(_, inner) => collect_vars(inner, names),
+            Transcriber::Repeat(inner, _) => collect_vars(inner, names),
+            Transcriber::Token(_) | Transcriber::Crate => {}
+        }
+    }
+}
+
+fn collect_fragment_names(matchers: &[Matcher], names: &mut Vec<String>) {
+    for matcher in matchers {
+        match matcher {
+            Matcher::Fragment(name, _) => names.push(name.clone()),
+            Matcher::Group(_, inner) => collect_fragment_names(inner, names),
+            Matcher::Repeat(inner, _, _) => collect_fragment_names(inner, names),
+            Matcher::Token(_) => {}
+        }
+    }
+}
+
+fn same_token(a: &TokenTree, b: &TokenTree) -> bool {
+    match (a, b) {
+        (TokenTree::Ident(a), TokenTree::Ident(b)) => a == b,
+        (TokenTree::Punct(a), TokenTree::Punct(b)) => a.as_char() == b.as_char(),
+        (TokenTree::Literal(a), TokenTree::Literal(b)) => a.to_string() == b.to_string(),
+        _ => false,
+    }
+}
+
+/// matches `matchers` against `input` from `pos`. returns the position after the matched tokens
+fn match_seq(matchers: &[Matcher], input: &[TokenTree], mut pos: usize, captures: &mut Captures) -> Option<usize> {
+    for matcher in matchers {
+        match matcher {
+            Matcher::Token(expected) => {
+                if !same_token(expected, input.get(pos)?) {
+                    return None;
+                }
+                pos += 1;
+            }
+            Matcher::Group(delimiter, inner) => {
+                let TokenTree::Group(group) = input.get(pos)? else {
+                    return None;
+                };
+                if group.delimiter() != *delimiter {
+                    return None;
+                }
+                let tokens = group.stream().into_iter().collect::<Vec<_>>();
+                if match_seq(inner, &tokens, 0, captures)? != tokens.len() {
+                    return None;
+                }
+                pos += 1;
+            }
+            Matcher::Fragment(name, kind) => {
+                let taken = match_fragment(*kind, &input[pos..])?;
+                let tokens = &input[pos..pos + taken];
+                let captured = match kind {
+                    FragmentKind::Tt | FragmentKind::Ident | FragmentKind::Lifetime => tokens.to_vec(),
+                    // the rest are opaque: `$a * 2` with `1 + 1` for `$a:expr` is `(1 + 1) * 2`
+                    _ => vec![TokenTree::Group(Group::new(Delimiter::None, tokens.iter().cloned().collect()))],
+                };
+                captures.insert(name.clone(), Captured::Tokens(captured));
+                pos += taken;
+            }
+            Matcher::Repeat(inner, separator, op) => {
+                let mut iterations: Vec<Captures> = vec![];
+                loop {
+                    if *op == RepeatOp::ZeroOrOne && iterations.len() == 1 {
+                        break;
+                    }
+                    let mut next = pos;
+                    if !iterations.is_empty() {
+                        if let Some(separator) = separator {
+                            match input.get(next) {
+                                Some(it) if same_token(separator, it) => next += 1,
+                                _ => break,
+                            }
+                        }
+                    }
+                    let mut iteration = Captures::default();
+                    match match_seq(inner, input, next, &mut iteration) {
+                        // an empty iteration would repeat forever
+                        Some(end) if end > pos => {
+                            pos = end;
+                            iterations.push(iteration);
+                        }
+                        _ => break,
+                    }
+                }
+                if *op == RepeatOp::OneOrMore && iterations.is_empty() {
+                    return None;
+                }
+                let mut names = vec![];
+                collect_fragment_names(inner, &mut names);
+                for name in names {
+                    let values = iterations
+                        .iter_mut()
+                        .filter_map(|it| it.remove(&name))
+                        .collect();
+                    captures.insert(name, Captured::Repeated(values));
+                }
+            }
+        }
+    }
+    Some(pos)
+}
+
+/// count of the token trees taken by the fragment
+fn match_fragment(kind: FragmentKind, input: &[TokenTree]) -> Option<usize> {
+    match kind {
+        FragmentKind::Tt => return if input.is_empty() { None } else { Some(1) },
+        FragmentKind::Ident => {
+            return match input.first()? {
+                TokenTree::Ident(it) if it != "_" => Some(1),
+                _ => None,
+            }
+        }
+        _ => {}
+    }
+    let stream = input.iter().cloned().collect::<TokenStream>();
+    let parser = |input: ParseStream| -> syn::Result<usize> {
+        match kind {
+            FragmentKind::Lifetime => {
+                input.parse::<Lifetime>()?;
+            }
+            FragmentKind::Literal => {
+                if input.peek(syn::Token![-]) {
+                    input.parse::<syn::Token![-]>()?;
+                }
+                input.parse::<Lit>()?;
+            }
+            FragmentKind::Ty => {
+                input.parse::<Type>()?;
+            }
+            FragmentKind::Path => {
+                input.parse::<Path>()?;
+            }
+            FragmentKind::Expr => {
+                input.parse::<Expr>()?;
+            }
+            FragmentKind::Pat => {
+                Pat::parse_multi_with_leading_vert(input)?;
+            }
+            FragmentKind::PatParam => {
+                Pat::parse_single(input)?;
+            }
+            FragmentKind::Stmt => {
+                input.parse::<Stmt>()?;
+            }
+            FragmentKind::Block => {
+                input.parse::<Block>()?;
+            }
+            FragmentKind::Item => {
+                input.parse::<Item>()?;
+            }
+            FragmentKind::Meta => {
+                input.parse::<Meta>()?;
+            }
+            FragmentKind::Vis => {
+                input.parse::<Visibility>()?;
+            }
+            FragmentKind::Tt | FragmentKind::Ident => unreachable!(),
+        }
+        let rest: TokenStream = input.parse()?;
+        Ok(rest.into_iter().count())
+    };
+    let rest = parser.parse2(stream).ok()?;
+    Some(input.len() - rest)
+}
+
+/// items produced by a macro expansion
+pub fn parse_items(tokens: TokenStream) -> syn::Result<Vec<Item>> {
+    let parser = |input: ParseStream| {
+        let mut items = vec![];
+        while !input.is_empty() {
+            items.push(input.parse::<Item>()?);
+        }
+        Ok(items)
+    };
+    parser.parse2(tokens)
+}
+
+impl std::fmt::Display for MacroRules {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "macro_rules! {} ({} rules, from {})", self.name, self.rules.len(), self.crate_)
+    }
+}
+
+/// `name!(...)` invocation to print in logs
+pub fn display_invocation(path: &syn::Path) -> String {
+    path.to_token_stream().to_string().replace(' ', "")
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::{BinOp, Expr, Item};
+
+    use crate::{test_support::compiled, GlobalIdent};
+
+    use super::{parse_items, MacroRules};
+
+    fn expand(rules: &str, input: &str) -> Option<Vec<Item>> {
+        let rules = MacroRules::parse("m", "c", rules.parse().unwrap()).unwrap();
+        let tokens = rules.expand(input.parse().unwrap())?;
+        Some(parse_items(tokens).unwrap())
+    }
+
+    fn names(items: &[Item]) -> Vec<String> {
+        items
+            .iter()
+            .map(|it| match it {
+                Item::Struct(it) => format!("{}{}", it.ident, it.fields.to_token_stream()).replace(' ', ""),
+                Item::Const(it) => it.ident.to_string(),
+                it => it.to_token_stream().to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn repetition_with_separator() {
+        let rules = "($($name:ident),* $(,)?) => { $(pub struct $name;)* }";
+        assert_eq!(names(&expand(rules, "A, B, C").unwrap()), ["A", "B", "C"]);
+        assert_eq!(names(&expand(rules, "A, B,").unwrap()), ["A", "B"]);
+        assert_eq!(names(&expand(rules, "").unwrap()), Vec::<String>::new());
+    }
+
+    #[test]
+    fn operator_as_separator() {
+        // `+` is the separator of `$(...)++` and the operator of `$(...)+`
+        let rules = "($($name:ident)++) => { $(const $name: u8 = 0;)* }; ($($name:ident)+) => {}";
+        assert_eq!(names(&expand(rules, "a + b + c").unwrap()), ["a", "b", "c"]);
+        assert_eq!(names(&expand(rules, "a b").unwrap()), Vec::<String>::new());
+    }
+
+    #[test]
+    fn nested_repetition() {
+        let rules = "($($name:ident { $($field:ident: $ty:ty),* });*) => { $(pub struct $name { $(pub $field: $ty),* })* }";
+        let items = expand(rules, "A { x: u8, y: Vec<u8> }; B {}").unwrap();
+        assert_eq!(names(&items), ["A{pubx:u8,puby:Vec<u8>}", "B{}"]);
+    }
+
+    #[test]
+    fn optional_and_crate() {
+        let rules = "($name:ident $(: $parent:ty)?) => { pub struct $name($($parent)?); pub type Krate = $crate::Root; }";
+        let items = expand(rules, "A: u8").unwrap();
+        assert_eq!(names(&items)[0], "A(u8)");
+        assert_eq!(items[1].to_token_stream().to_string().replace(' ', ""), "pubtypeKrate=c::Root;");
+        assert_eq!(names(&expand(rules, "B").unwrap())[0], "B()");
+    }
+
+    #[test]
+    fn failed_match() {
+        let rules = "(struct $name:ident) => { pub struct $name; }; ($($value:literal)+) => {}";
+        assert!(expand(rules, "enum A").is_none());
+        assert!(expand(rules, "1 x").is_none());
+        assert!(expand(rules, "").is_none());
+        assert!(expand(rules, "struct A B").is_none());
+        assert!(expand("($e:expr) => {}", "struct").is_none());
+    }
+
+    #[test]
+    fn fragments_keep_precedence() {
+        let rules = MacroRules::parse("m", "c", "($a:expr) => { $a * 2 }".parse().unwrap()).unwrap();
+        let expr = syn::parse2::<Expr>(rules.expand("1 + 1".parse().unwrap()).unwrap()).unwrap();
+        let Expr::Binary(it) = expr else {
+            panic!("not a binary expression: {}", expr.to_token_stream());
+        };
+        assert!(matches!(it.op, BinOp::Mul(_)));
+        assert!(matches!(*it.left, Expr::Group(_)));
+    }
+
+    #[test]
+    fn invocation_adds_items() {
+        let db = compiled(
+            "
+            macro_rules! wrappers {
+                ($($name:ident($ty:ty)),*) => { $(pub struct $name(pub $ty);)* };
+            }
+            wrappers!(Meters(f32), Names(Vec<String>));
+            ",
+        );
+        let binding = db.decls.find_value(GlobalIdent::from_qualified_name("c::Names").to_parts()).unwrap();
+        let Some(Item::Struct(names)) = binding.type_ast.as_ref().and_then(|it| it.ast.as_ref()) else {
+            panic!("no struct");
+        };
+        let field = db.resolve_type(&names.fields.iter().next().unwrap().ty);
+        assert_eq!(field.to_string(), "alloc::vec::Vec<alloc::string::String>");
+    }
+}
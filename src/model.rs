@@ -8,10 +8,10 @@ use std::{
 
 use proc_macro2::Span;
 use quote::{quote, ToTokens};
-use syn::{parse2, parse_str, Ident, Item, ItemStruct, Path};
+use syn::{parse2, parse_str, punctuated::Punctuated, Ident, Item, ItemEnum, ItemStruct, ItemTrait, Path, TraitItem, Variant};
 
 use crate::{
-    dedoc::ItemExt, ident_part::RefSliceOfIdentPartExt, named_tree::{FromPath, NamedNode}, stopwatch::start_watch, GlobalIdent, IdentPart
+    crate_meta::CrateMeta, dedoc::ItemExt, derive::default_derives, eval_cfg::CfgSet, ident_part::RefSliceOfIdentPartExt, inline_types::AliasUse, macro_rules::MacroRules, named_tree::{FromPath, NamedNode}, stopwatch::start_watch, trait_resolution::ImplRecord, GlobalIdent, IdentPart
 };
 
 #[derive(Debug)]
@@ -19,6 +19,32 @@ pub struct Database {
     pub decls: NamedNode<IdentPart, Binding>,
     pub wildcard_imports_temp: Vec<Rc<WildcardImport>>,
     pub unresolved: BTreeMap<String, UnresolvedCtx>,
+    /// type aliases inlined in the declaration, as they were written there. see `alias_uses`
+    pub alias_uses: BTreeMap<GlobalIdent, Vec<AliasUse>>,
+    /// options for `#[cfg(...)]` evaluation, host platform by default
+    pub cfg: CfgSet,
+    /// enabled features of crates, see `set_features`
+    pub features: BTreeMap<String, BTreeSet<String>>,
+    pub crates: BTreeMap<String, CrateMeta>,
+    /// `macro_rules!` exported with `#[macro_export]`, by `crate::name`
+    pub macros: BTreeMap<GlobalIdent, Rc<MacroRules>>,
+    /// derive macro by `crate::Name` to the path of the implemented trait, see `default_derives`
+    pub derives: BTreeMap<String, String>,
+    /// compile-time environment of crates for `env!` in `include!` paths, see `set_crate_env`
+    pub crate_env: BTreeMap<String, BTreeMap<String, String>>,
+    /// trait impls by the trait address, filled by `compile`
+    pub impls: BTreeMap<GlobalIdent, Vec<ImplRecord>>,
+    /// addresses of inherent and trait impls by the address of their self type, filled by `compile`.
+    /// blanket impls (like `impl<T: Display> ToString for T`) are in `blanket_impls`
+    pub impls_of_type: BTreeMap<GlobalIdent, Vec<GlobalIdent>>,
+    /// addresses of trait impls for a generic param of the impl, filled by `compile`
+    pub blanket_impls: BTreeSet<GlobalIdent>,
+    /// declaration to the declarations which signatures mention it, filled by `compile`. see `referrers`
+    pub references: BTreeMap<GlobalIdent, BTreeSet<GlobalIdent>>,
+    /// declaration to the `pub use` bindings re-exporting it, filled by `compile`. see `public_path`
+    pub reexports: BTreeMap<GlobalIdent, BTreeSet<GlobalIdent>>,
+    /// module to the modules re-exporting all its items with `pub use module::*`, filled by `compile`
+    pub reexporting_mods: BTreeMap<GlobalIdent, BTreeSet<GlobalIdent>>,
 }
 
 impl Default for Database {
@@ -27,6 +53,19 @@ impl Default for Database {
             decls: NamedNode::new(Binding::new_empty(GlobalIdent::root())),
             wildcard_imports_temp: Default::default(),
             unresolved: Default::default(),
+            alias_uses: Default::default(),
+            cfg: CfgSet::host(),
+            features: Default::default(),
+            crates: Default::default(),
+            macros: Default::default(),
+            derives: default_derives(),
+            crate_env: Default::default(),
+            impls: Default::default(),
+            impls_of_type: Default::default(),
+            blanket_impls: Default::default(),
+            references: Default::default(),
+            reexports: Default::default(),
+            reexporting_mods: Default::default(),
         }
     }
 }
@@ -45,10 +84,13 @@ pub enum Resolution {
 
 impl Database {
     pub fn compile(&mut self) {
+        self.add_builtin_crates();
         // TODO delete it
         // self.bake_wildcards();
         self.resolve_idents();
 		self.inline_types();
+        self.index_impls();
+        self.index_reexports();
     }
 
     pub fn print_to(&self, f: &mut dyn fmt::Write) -> fmt::Result {
@@ -102,7 +144,13 @@ pub struct Binding {
     pub alias_for: Vec<(GlobalIdent, ImportKind)>,
     /// means this binding imports all children from all these binding
     pub wildcard_alias_for: BTreeSet<GlobalIdent>,
+    /// type aliases which are inlined to this type
+    pub known_aliases: BTreeSet<GlobalIdent>,
     pub resolution: BindingResolution,
+    /// `Some` for modules: whether the module is `pub` (crate roots are)
+    pub mod_public: Option<bool>,
+    /// sources of `alias_for` and `wildcard_alias_for` imported by `pub use`
+    pub public_imports: BTreeSet<GlobalIdent>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -183,7 +231,10 @@ impl Binding {
             type_ast: Default::default(),
             alias_for: Default::default(),
             wildcard_alias_for: Default::default(),
+            known_aliases: Default::default(),
             resolution: BindingResolution::NotAttempted,
+            mod_public: None,
+            public_imports: Default::default(),
         }
     }
 
@@ -197,7 +248,10 @@ impl Binding {
             }),
             alias_for: Default::default(),
             wildcard_alias_for: Default::default(),
+            known_aliases: Default::default(),
             resolution: BindingResolution::NotAttempted,
+            mod_public: None,
+            public_imports: Default::default(),
         }
     }
 
@@ -211,7 +265,10 @@ impl Binding {
             type_ast: Default::default(),
             alias_for: Default::default(),
             wildcard_alias_for: Default::default(),
+            known_aliases: Default::default(),
             resolution: BindingResolution::NotAttempted,
+            mod_public: None,
+            public_imports: Default::default(),
         }
     }
 }
@@ -281,6 +338,10 @@ pub struct DeclAst {
 #[derive(Debug, Clone)]
 pub enum Ast {
 	Real(Item),
+	/// enum variant: the enum without variants and the variant
+	Variant(ItemEnum, Variant),
+	/// method, associated type or const of a trait: the trait without items and the item
+	TraitItem(ItemTrait, TraitItem),
 	Stub,
 }
 
@@ -291,21 +352,48 @@ impl Default for Ast {
 }
 
 impl Ast {
+	/// the item, `None` for stubs, variants and trait items
 	pub fn as_ref(&self) -> Option<&Item> {
 		match self {
 			Ast::Real(it) => Some(it),
-			Ast::Stub => None,
+			_ => None,
 		}
 	}
-}
 
-impl From<Ast> for Option<Item> {
-	fn from(value: Ast) -> Self {
-		match value {
-			Ast::Real(it) => Some(it),
+	/// the item, variants and trait items as their parent with this member only
+	pub fn to_item(&self) -> Option<Item> {
+		match self {
+			Ast::Real(it) => Some(it.clone()),
+			Ast::Variant(enum_, variant) => Some(Item::Enum(ItemEnum {
+				variants: Punctuated::from_iter([variant.clone()]),
+				..enum_.clone()
+			})),
+			Ast::TraitItem(trait_, item) => Some(Item::Trait(ItemTrait {
+				items: vec![item.clone()],
+				..trait_.clone()
+			})),
 			Ast::Stub => None,
 		}
 	}
+
+	/// visits the item, variants and trait items within their parent (for the generics and `Self` in scope)
+	pub fn visit_item_mut(&mut self, f: impl FnOnce(&mut Item)) {
+		let Some(mut item) = self.to_item() else {
+			return;
+		};
+		f(&mut item);
+		*self = match (&*self, item) {
+			(Ast::Variant(..), Item::Enum(mut it)) => {
+				let variant = it.variants.pop().unwrap().into_value();
+				Ast::Variant(it, variant)
+			}
+			(Ast::TraitItem(..), Item::Trait(mut it)) => {
+				let item = it.items.pop().unwrap();
+				Ast::TraitItem(it, item)
+			}
+			(_, it) => Ast::Real(it),
+		};
+	}
 }
 
 impl Display for DeclAst {
@@ -315,7 +403,7 @@ impl Display for DeclAst {
             "({}) {}",
             self.address,
             self.ast
-                .as_ref()
+                .to_item()
                 .map(|it| it.dedoc().to_token_stream().to_string())
                 .unwrap_or("<stubbed type>".to_owned())
         )
@@ -0,0 +1,405 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display},
+};
+
+use syn::{ForeignItem, Item, Visibility};
+
+use crate::{stopwatch::start_watch, Ast, Binding, BindingResolution, Database, DeclAst, GlobalIdent, IdentPart};
+
+const MAX_REEXPORT_DEPTH: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ItemKind {
+    Struct,
+    Enum,
+    Union,
+    Trait,
+    TypeAlias,
+    /// `type Foo;` of an `extern` block
+    ForeignType,
+    Fn,
+    Const,
+    Static,
+    /// `use a::b::Item;`
+    ReExport,
+    /// `use a::b::*;`
+    Glob,
+}
+
+impl ItemKind {
+    fn plural(&self) -> &'static str {
+        match self {
+            ItemKind::Struct => "structs",
+            ItemKind::Enum => "enums",
+            ItemKind::Union => "unions",
+            ItemKind::Trait => "traits",
+            ItemKind::TypeAlias => "type aliases",
+            ItemKind::ForeignType => "foreign types",
+            ItemKind::Fn => "fns",
+            ItemKind::Const => "consts",
+            ItemKind::Static => "statics",
+            ItemKind::ReExport => "re-exports",
+            ItemKind::Glob => "globs",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ModuleItem {
+    /// `*` for globs
+    pub name: String,
+    pub kind: ItemKind,
+    pub public: bool,
+    pub resolution: BindingResolution,
+    /// imported path of re-exports and globs
+    pub source: Option<GlobalIdent>,
+}
+
+/// module with its items grouped by kind, see `Database::module_tree`
+#[derive(Debug, Clone)]
+pub struct ModuleTree {
+    pub address: GlobalIdent,
+    pub public: bool,
+    pub items: BTreeMap<ItemKind, Vec<ModuleItem>>,
+    pub modules: Vec<ModuleTree>,
+}
+
+impl Database {
+    /// modules of the crate with their items. with `public_only` private items and modules are omitted
+    pub fn module_tree(&self, crate_: &str, public_only: bool) -> Option<ModuleTree> {
+        let root = GlobalIdent::from_ident_path(&[IdentPart::from_name(crate_)]);
+        self.module_tree_of(&root, public_only)
+    }
+
+    pub fn module_tree_of(&self, address: &GlobalIdent, public_only: bool) -> Option<ModuleTree> {
+        let node = self.decls.find(address.to_parts())?;
+        let binding = node.get_value();
+        let Some(public) = binding.mod_public else {
+            println!("WARN: {} is not a module", address);
+            return None;
+        };
+        let mut tree = ModuleTree {
+            address: address.clone(),
+            public,
+            items: Default::default(),
+            modules: vec![],
+        };
+        for source in binding.wildcard_alias_for.iter() {
+            tree.add(
+                ModuleItem {
+                    name: "*".to_string(),
+                    kind: ItemKind::Glob,
+                    public: binding.public_imports.contains(source),
+                    resolution: match self.decls.find(source.to_parts()) {
+                        Some(_) => BindingResolution::Fully,
+                        None => BindingResolution::Failed,
+                    },
+                    source: Some(source.clone()),
+                },
+                public_only,
+            );
+        }
+        for child in node.children() {
+            let child = child.get_value();
+            if child.mod_public.is_some() {
+                if let Some(it) = self.module_tree_of(&child.address, public_only) {
+                    if it.public || !public_only {
+                        tree.modules.push(it);
+                    }
+                }
+            }
+            for item in self.module_items(child) {
+                tree.add(item, public_only);
+            }
+        }
+        Some(tree)
+    }
+
+    /// path under which the declaration is reachable from other crates: its own address if the modules
+    /// on the way are `pub`, otherwise the shortest `pub use` re-export of it (named or glob). needs `compile`
+    pub fn public_path(&self, address: &GlobalIdent) -> Option<GlobalIdent> {
+        self.public_path_internal(address, 0)
+    }
+
+    fn public_path_internal(&self, address: &GlobalIdent, depth: usize) -> Option<GlobalIdent> {
+        if self.is_public_address(address) {
+            return Some(address.clone());
+        }
+        if depth >= MAX_REEXPORT_DEPTH {
+            return None;
+        }
+        let name = address.last_part().to_string();
+        let globs = self.reexporting_mods.get(&address.parent()).into_iter().flatten();
+        let candidates = globs
+            .map(|it| GlobalIdent::from_mod_and_name(it, &name))
+            .chain(self.reexports.get(address).into_iter().flatten().cloned());
+        candidates
+            .filter(|it| it != address)
+            .filter_map(|it| self.public_path_internal(&it, depth + 1))
+            .min_by_key(|it| it.to_parts().len())
+    }
+
+    pub(crate) fn index_reexports(&mut self) {
+        let _watch = start_watch("index_reexports");
+        let mut reexports: BTreeMap<GlobalIdent, BTreeSet<GlobalIdent>> = Default::default();
+        let mut reexporting_mods: BTreeMap<GlobalIdent, BTreeSet<GlobalIdent>> = Default::default();
+        self.decls.for_each(&mut |_, binding| {
+            if binding.public_imports.is_empty() {
+                return;
+            }
+            for source in binding.public_imports.iter().filter(|it| binding.wildcard_alias_for.contains(it)) {
+                reexporting_mods.entry(source.clone()).or_default().insert(binding.address.clone());
+            }
+            if !binding.alias_for.is_empty() {
+                for it in self.reexported(&binding.address, &binding.alias_for) {
+                    reexports.entry(it).or_default().insert(binding.address.clone());
+                }
+            }
+        });
+        self.reexports = reexports;
+        self.reexporting_mods = reexporting_mods;
+    }
+
+    /// the item (or `pub use` of it) is public and so are all the modules containing it
+    fn is_public_address(&self, address: &GlobalIdent) -> bool {
+        let parts = address.to_parts();
+        for i in 1..=parts.len() {
+            let Some(binding) = self.decls.find_value(&parts[..i]) else {
+                // item glob-imported into a public module has no binding of its own
+                return i == parts.len() && self.glob_reexports(&GlobalIdent::from_ident_path(&parts[..i - 1]), address);
+            };
+            if !is_public_binding(binding) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// `pub use source::*;` in the module brings a `pub` item named like `address`.
+    /// the source module itself may be private
+    fn glob_reexports(&self, module: &GlobalIdent, address: &GlobalIdent) -> bool {
+        let Some(binding) = self.decls.find_value(module.to_parts()) else {
+            return false;
+        };
+        binding.public_imports.iter().filter(|it| binding.wildcard_alias_for.contains(it)).any(|source| {
+            let reexported = GlobalIdent::from_mod_and_name(source, &address.last_part().to_string());
+            self.decls.find_value(reexported.to_parts()).is_some_and(is_public_binding)
+        })
+    }
+
+    /// declarations the symbols stand for: re-exports are followed and modules give all their public items
+    /// (of submodules too). unresolved symbols and globs are reported to `skipped`
+    pub(crate) fn expand_symbols(&self, symbols: &[GlobalIdent], skipped: &mut Vec<(String, String)>) -> BTreeSet<GlobalIdent> {
+        let mut targets = BTreeSet::new();
+        for symbol in symbols {
+            self.expand_symbol(symbol, &mut targets, skipped);
+        }
+        targets
+    }
+
+    fn expand_symbol(&self, symbol: &GlobalIdent, targets: &mut BTreeSet<GlobalIdent>, skipped: &mut Vec<(String, String)>) {
+        let Some(binding) = self.decls.find_value(symbol.to_parts()) else {
+            skipped.push((symbol.to_string(), "not found".to_string()));
+            return;
+        };
+        if binding.mod_public.is_some() {
+            if let Some(tree) = self.module_tree_of(symbol, true) {
+                self.expand_module(&tree, targets, skipped);
+            }
+        } else if !binding.alias_for.is_empty() {
+            let reexported = self.reexported(&binding.address, &binding.alias_for);
+            if reexported.is_empty() {
+                skipped.push((symbol.to_string(), "re-export is not resolved".to_string()));
+            }
+            targets.extend(reexported);
+        } else {
+            targets.insert(symbol.clone());
+        }
+    }
+
+    fn expand_module(&self, tree: &ModuleTree, targets: &mut BTreeSet<GlobalIdent>, skipped: &mut Vec<(String, String)>) {
+        for (kind, items) in tree.items.iter() {
+            for item in items {
+                let address = GlobalIdent::from_mod_and_name(&tree.address, &item.name);
+                match kind {
+                    ItemKind::Glob => skipped.push((address.to_string(), "glob re-exports are not expanded".to_string())),
+                    _ => self.expand_symbol(&address, targets, skipped),
+                }
+            }
+        }
+        for it in tree.modules.iter() {
+            self.expand_module(it, targets, skipped);
+        }
+    }
+
+    fn module_items(&self, binding: &Binding) -> Vec<ModuleItem> {
+        let name = binding.address.last_part().to_string();
+        let mut items = vec![];
+        for decl in binding.type_ast.iter().chain(binding.non_type_ast.iter()) {
+            if let Some((kind, public)) = kind_of(decl) {
+                items.push(ModuleItem {
+                    name: name.clone(),
+                    kind,
+                    public,
+                    resolution: binding.resolution,
+                    source: None,
+                });
+            }
+        }
+        let resolved = !binding.alias_for.is_empty() && !self.reexported(&binding.address, &binding.alias_for).is_empty();
+        for (source, _) in binding.alias_for.iter() {
+            items.push(ModuleItem {
+                name: name.clone(),
+                kind: ItemKind::ReExport,
+                public: binding.public_imports.contains(source),
+                resolution: if resolved {
+                    BindingResolution::Fully
+                } else {
+                    BindingResolution::Failed
+                },
+                source: Some(source.clone()),
+            });
+        }
+        items
+    }
+}
+
+/// the item, module or `use` is `pub`
+fn is_public_binding(binding: &Binding) -> bool {
+    binding.mod_public == Some(true)
+        || !binding.public_imports.is_empty()
+        || binding.type_ast.iter().chain(binding.non_type_ast.iter()).any(|it| kind_of(it).is_some_and(|(_, public)| public))
+}
+
+fn kind_of(decl: &DeclAst) -> Option<(ItemKind, bool)> {
+    let Ast::Real(item) = &decl.ast else {
+        return None;
+    };
+    let (kind, vis) = match item {
+        Item::Struct(it) => (ItemKind::Struct, &it.vis),
+        Item::Enum(it) => (ItemKind::Enum, &it.vis),
+        Item::Union(it) => (ItemKind::Union, &it.vis),
+        Item::Trait(it) => (ItemKind::Trait, &it.vis),
+        Item::TraitAlias(it) => (ItemKind::Trait, &it.vis),
+        Item::Type(it) => (ItemKind::TypeAlias, &it.vis),
+        Item::Fn(it) => (ItemKind::Fn, &it.vis),
+        Item::Const(it) => (ItemKind::Const, &it.vis),
+        Item::Static(it) => (ItemKind::Static, &it.vis),
+        Item::ForeignMod(it) => match it.items.first()? {
+            ForeignItem::Fn(it) => (ItemKind::Fn, &it.vis),
+            ForeignItem::Static(it) => (ItemKind::Static, &it.vis),
+            ForeignItem::Type(it) => (ItemKind::ForeignType, &it.vis),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some((kind, matches!(vis, Visibility::Public(_))))
+}
+
+impl ModuleTree {
+    fn add(&mut self, item: ModuleItem, public_only: bool) {
+        if item.public || !public_only {
+            self.items.entry(item.kind).or_default().push(item);
+        }
+    }
+
+    fn print_to(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        let pad = "  ".repeat(indent);
+        writeln!(f, "{}{}mod {}", pad, if self.public { "pub " } else { "" }, self.address)?;
+        for (kind, items) in self.items.iter() {
+            writeln!(f, "{}  {}:", pad, kind.plural())?;
+            for item in items.iter() {
+                write!(f, "{}    {}{}", pad, if item.public { "pub " } else { "" }, item.name)?;
+                if let Some(source) = &item.source {
+                    write!(f, " <- {}", source)?;
+                }
+                writeln!(f, " ({:?})", item.resolution)?;
+            }
+        }
+        for it in self.modules.iter() {
+            it.print_to(f, indent + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ModuleTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.print_to(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_support::compiled, Database, GlobalIdent};
+
+    use super::{ItemKind, ModuleTree};
+
+    const SOURCE: &str = "
+        pub mod shapes {
+            pub struct Point;
+            pub enum Kind { Round }
+            struct Cache;
+            pub fn area() {}
+        }
+        mod hidden { pub trait Draw {} }
+        pub use shapes::Point;
+        pub use crate::hidden::*;
+    ";
+
+    const REEXPORTS: &str = "
+        pub mod open { pub struct Open; }
+        mod hidden {
+            pub struct Named;
+            pub struct Globbed;
+            struct Private;
+            pub mod deep { pub struct Deep; }
+        }
+        mod inner { pub use crate::hidden::deep::Deep; }
+        pub mod api { pub use crate::hidden::*; }
+        pub use hidden::Named;
+        pub use inner::Deep;
+    ";
+
+    fn names(tree: &ModuleTree, kind: ItemKind) -> Vec<&str> {
+        tree.items.get(&kind).into_iter().flatten().map(|it| it.name.as_str()).collect()
+    }
+
+    #[test]
+    fn items_are_grouped_by_kind() {
+        let db = compiled(SOURCE);
+        let tree = db.module_tree("c", false).unwrap();
+        assert_eq!(names(&tree, ItemKind::ReExport), ["Point"]);
+        assert_eq!(tree.items[&ItemKind::Glob][0].source, Some(GlobalIdent::from_qualified_name("c::hidden")));
+        let modules = tree.modules.iter().map(|it| it.address.to_string()).collect::<Vec<_>>();
+        assert_eq!(modules, ["c::hidden", "c::shapes"]);
+        let shapes = &tree.modules[1];
+        assert_eq!(names(shapes, ItemKind::Struct), ["Cache", "Point"]);
+        assert_eq!(names(shapes, ItemKind::Enum), ["Kind"]);
+        assert_eq!(names(shapes, ItemKind::Fn), ["area"]);
+    }
+
+    #[test]
+    fn private_items_are_omitted() {
+        let db = compiled(SOURCE);
+        let tree = db.module_tree("c", true).unwrap();
+        let modules = tree.modules.iter().map(|it| it.address.to_string()).collect::<Vec<_>>();
+        assert_eq!(modules, ["c::shapes"]);
+        assert_eq!(names(&tree.modules[0], ItemKind::Struct), ["Point"]);
+    }
+
+    fn public_path(db: &Database, name: &str) -> Option<String> {
+        db.public_path(&GlobalIdent::from_qualified_name(name)).map(|it| it.to_string())
+    }
+
+    #[test]
+    fn public_paths_through_reexports() {
+        let db = compiled(REEXPORTS);
+        assert_eq!(public_path(&db, "c::open::Open").as_deref(), Some("c::open::Open"));
+        assert_eq!(public_path(&db, "c::hidden::Named").as_deref(), Some("c::Named"));
+        assert_eq!(public_path(&db, "c::hidden::Globbed").as_deref(), Some("c::api::Globbed"));
+        assert_eq!(public_path(&db, "c::hidden::deep::Deep").as_deref(), Some("c::Deep"));
+        assert_eq!(public_path(&db, "c::hidden::Private"), None);
+    }
+}
@@ -0,0 +1,364 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use quote::ToTokens;
+use syn::{FnArg, Generics, ImplItem, Item, ItemImpl, Pat, Signature, TraitItem};
+
+use crate::{
+    generic_bounds::{GenericBound, ResolvedGenericParam, ResolvedGenerics, ResolvedPredicate},
+    trait_resolution::unify,
+    Ast, Database, GlobalIdent, ResolvedType, TraitRef,
+};
+
+const MAX_ALIAS_DEPTH: usize = 16;
+
+/// API of a generic type instantiated with concrete arguments
+#[derive(Debug, Clone)]
+pub struct MonoApi {
+    /// the requested type with aliases expanded
+    pub ty: ResolvedType,
+    pub fields: Vec<MonoField>,
+    pub methods: Vec<MonoMethod>,
+    /// methods (or whole impls) which bounds can't be met for this instantiation, with the reason.
+    /// blanket impls are reported only if they apply
+    pub dropped: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MonoField {
+    /// `0`, `1`... for tuple structs
+    pub name: String,
+    pub ty: ResolvedType,
+    pub public: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct MonoMethod {
+    pub name: String,
+    /// impl providing the method
+    pub impl_: GlobalIdent,
+    /// `None` for inherent methods
+    pub trait_: Option<TraitRef>,
+    /// type of `self`, `None` for associated functions
+    pub receiver: Option<ResolvedType>,
+    pub inputs: Vec<(String, ResolvedType)>,
+    pub output: ResolvedType,
+    /// own generics of the method, with the type's params substituted in their bounds
+    pub generics: ResolvedGenerics,
+    /// provided by a blanket impl (like `impl<T: Display> ToString for T`), see `Database::blanket_impls`
+    pub blanket: bool,
+    /// `pub fn` of an inherent impl or any method of a trait impl
+    pub public: bool,
+}
+
+impl Database {
+    /// expands aliases until the type is a struct, enum, union or something not nominal
+    pub fn expand_aliases(&self, ty: &ResolvedType) -> ResolvedType {
+        let mut ty = ty.clone();
+        for _ in 0..MAX_ALIAS_DEPTH {
+            let ResolvedType::Path { address, args } = &ty else {
+                break;
+            };
+            match self.expand_alias(address, args) {
+                Some(it) => ty = it,
+                None => break,
+            }
+        }
+        ty
+    }
+
+    /// fields and methods of `ty` (like `nalgebra::Vector3<f32>`) with generic params substituted.
+    /// methods which where-clauses don't hold for the instantiation are dropped
+    pub fn monomorphize(&self, ty: &ResolvedType) -> Option<MonoApi> {
+        let ty = self.normalize(&self.expand_aliases(ty));
+        let ResolvedType::Path { address, args } = &ty else {
+            println!("WARN: cannot monomorphize {}, it's not a nominal type", ty);
+            return None;
+        };
+        let decl = self.decls.find_value(address.to_parts())?.type_ast.as_ref()?;
+        let Ast::Real(item) = &decl.ast else {
+            return None;
+        };
+        let (generics, fields) = match item {
+            Item::Struct(it) => (&it.generics, Some(&it.fields)),
+            Item::Enum(it) => (&it.generics, None),
+            Item::Union(it) => (&it.generics, None),
+            _ => {
+                println!("WARN: cannot monomorphize {}, it's not a struct, enum or union", ty);
+                return None;
+            }
+        };
+        let substitution = self.substitution_of(generics, args);
+        let mut api = MonoApi {
+            ty: ty.clone(),
+            fields: vec![],
+            methods: vec![],
+            dropped: vec![],
+        };
+        if let Some(fields) = fields {
+            for (i, field) in fields.iter().enumerate() {
+                api.fields.push(MonoField {
+                    name: field.ident.as_ref().map(|it| it.to_string()).unwrap_or_else(|| i.to_string()),
+                    ty: self.normalize(&self.resolve_type(&field.ty).substitute(&substitution)),
+                    public: matches!(field.vis, syn::Visibility::Public(_)),
+                });
+            }
+        }
+        let full_ty = self.with_default_args(&ty);
+        let blanket_impls = self.blanket_impls.iter();
+        for impl_address in self.impls_of_type.get(address).into_iter().flatten().chain(blanket_impls) {
+            if let Some(ast) = self.impl_ast(impl_address) {
+                self.monomorphize_impl(&full_ty, impl_address, ast, &mut api);
+            }
+        }
+        Some(api)
+    }
+
+    /// generic params of the declaration to the given args, omitted args are taken from defaults
+    pub(crate) fn substitution_of(&self, generics: &Generics, args: &[ResolvedType]) -> BTreeMap<String, ResolvedType> {
+        let mut substitution = BTreeMap::new();
+        let params = self.resolve_generics(generics).params.into_iter().filter_map(|it| match it {
+            ResolvedGenericParam::Type { name, default, .. } => Some((name, default)),
+            ResolvedGenericParam::Const { name, default, .. } => Some((name, default.map(ResolvedType::Const))),
+            ResolvedGenericParam::Lifetime { .. } => None,
+        });
+        for (i, (name, default)) in params.enumerate() {
+            let arg = args.get(i).cloned().or_else(|| default.map(|it| it.substitute(&substitution)));
+            if let Some(arg) = arg {
+                substitution.insert(name, arg);
+            }
+        }
+        substitution
+    }
+
+    fn monomorphize_impl(&self, ty: &ResolvedType, impl_address: &GlobalIdent, ast: &ItemImpl, api: &mut MonoApi) {
+        let generics = self.resolve_generics(&ast.generics);
+        let params = generics
+            .params
+            .iter()
+            .filter_map(|it| match it {
+                ResolvedGenericParam::Type { name, .. } | ResolvedGenericParam::Const { name, .. } => Some(name.clone()),
+                ResolvedGenericParam::Lifetime { .. } => None,
+            })
+            .collect::<BTreeSet<_>>();
+        let mut substitution = BTreeMap::new();
+        let self_ty = self.with_default_args(&self.expand_aliases(&self.resolve_type(&ast.self_ty)));
+        if !unify(&self_ty, ty, &params, &mut substitution) {
+            return;
+        }
+        let trait_ = match &ast.trait_ {
+            Some((Some(_), _, _)) => return,
+            Some((None, path, _)) => match self.resolve_trait_ref(path) {
+                Some(it) => Some(it.map(&mut |it| it.substitute(&substitution))),
+                None => {
+                    api.dropped.push((impl_address.to_string(), "trait is not resolved".to_string()));
+                    return;
+                }
+            },
+            None => None,
+        };
+        if let Err(reason) = self.check_bounds(&generics, &substitution, &Default::default()) {
+            println!("drop {} for {}: {}", impl_address, ty, reason);
+            // blanket impls (`impl<T: Display> ToString for T`) not applying to the type aren't a part of its API
+            if !self.blanket_impls.contains(impl_address) {
+                api.dropped.push((impl_address.to_string(), reason));
+            }
+            return;
+        }
+        let mut implemented = BTreeSet::new();
+        for item in ast.items.iter() {
+            let ImplItem::Fn(it) = item else {
+                continue;
+            };
+            implemented.insert(it.sig.ident.to_string());
+            let public = trait_.is_some() || matches!(it.vis, syn::Visibility::Public(_));
+            self.add_mono_method(&it.sig, public, impl_address, &trait_, &substitution, api);
+        }
+        // provided methods of the trait which the impl doesn't override
+        let Some(trait_) = &trait_ else {
+            return;
+        };
+        let trait_substitution = self.trait_substitution(ty, trait_);
+        for item in self.provided_items(&trait_.address) {
+            let TraitItem::Fn(it) = &item.item else {
+                continue;
+            };
+            if implemented.contains(&it.sig.ident.to_string()) {
+                continue;
+            }
+            self.add_mono_method(&it.sig, true, impl_address, &Some(trait_.clone()), &trait_substitution, api);
+        }
+    }
+
+    fn add_mono_method(
+        &self,
+        sig: &Signature,
+        public: bool,
+        impl_address: &GlobalIdent,
+        trait_: &Option<TraitRef>,
+        substitution: &BTreeMap<String, ResolvedType>,
+        api: &mut MonoApi,
+    ) {
+        let name = sig.ident.to_string();
+        let concrete = |ty: &ResolvedType| self.normalize(&ty.substitute(substitution));
+        let own = self.resolve_generics(&sig.generics);
+        let own_params = own
+            .params
+            .iter()
+            .filter_map(|it| match it {
+                ResolvedGenericParam::Type { name, .. } | ResolvedGenericParam::Const { name, .. } => Some(name.clone()),
+                ResolvedGenericParam::Lifetime { .. } => None,
+            })
+            .collect::<BTreeSet<_>>();
+        let mentions_own = |ty: &ResolvedType| {
+            let mut found = false;
+            ty.for_each(&mut |it| {
+                if let ResolvedType::Generic(it) = it {
+                    found |= own_params.contains(it);
+                }
+            });
+            found
+        };
+        // predicates on the type's params only (like `where T: Float`) are decided now,
+        // the ones involving the method's own params stay for the caller
+        let mut generics = ResolvedGenerics {
+            params: own.params.iter().map(|it| substitute_param(it, &concrete)).collect(),
+            where_clause: vec![],
+        };
+        let mut fixed = ResolvedGenerics::default();
+        for predicate in own.where_clause.iter() {
+            match predicate {
+                ResolvedPredicate::Type { bounded, higher_ranked, bounds } => {
+                    let predicate = ResolvedPredicate::Type {
+                        bounded: concrete(bounded),
+                        higher_ranked: higher_ranked.clone(),
+                        bounds: bounds.iter().map(|it| substitute_bound(it, &concrete)).collect(),
+                    };
+                    if mentions_own(bounded) {
+                        generics.where_clause.push(predicate);
+                    } else {
+                        fixed.where_clause.push(predicate);
+                    }
+                }
+                it => generics.where_clause.push(it.clone()),
+            }
+        }
+        if let Err(reason) = self.check_bounds(&fixed, &Default::default(), &generics) {
+            println!("drop {}::{} for {}: {}", impl_address, name, api.ty, reason);
+            api.dropped.push((format!("{}::{}", impl_address, name), reason));
+            return;
+        }
+        let mut receiver = None;
+        let mut inputs = vec![];
+        for (i, arg) in sig.inputs.iter().enumerate() {
+            match arg {
+                FnArg::Receiver(it) => receiver = Some(concrete(&self.resolve_type(&it.ty))),
+                FnArg::Typed(it) => {
+                    let arg_name = match &*it.pat {
+                        Pat::Ident(it) => it.ident.to_string(),
+                        it => format!("arg{}_{}", i, it.to_token_stream()).replace(' ', ""),
+                    };
+                    inputs.push((arg_name, concrete(&self.resolve_type(&it.ty))));
+                }
+            }
+        }
+        api.methods.push(MonoMethod {
+            name,
+            impl_: impl_address.clone(),
+            trait_: trait_.clone(),
+            receiver,
+            inputs,
+            output: concrete(&self.resolve_return_type(&sig.output)),
+            generics,
+            blanket: self.blanket_impls.contains(impl_address),
+            public,
+        });
+    }
+}
+
+fn substitute_bound(bound: &GenericBound, concrete: &dyn Fn(&ResolvedType) -> ResolvedType) -> GenericBound {
+    match bound {
+        GenericBound::Trait {
+            trait_,
+            lifetimes,
+            assoc_bindings,
+            assoc_constraints,
+            maybe,
+            higher_ranked,
+        } => GenericBound::Trait {
+            trait_: trait_.map(&mut |it| concrete(&it)),
+            lifetimes: lifetimes.clone(),
+            assoc_bindings: assoc_bindings.iter().map(|(k, v)| (k.clone(), concrete(v))).collect(),
+            assoc_constraints: assoc_constraints
+                .iter()
+                .map(|(k, v)| (k.clone(), v.iter().map(|it| substitute_bound(it, concrete)).collect()))
+                .collect(),
+            maybe: *maybe,
+            higher_ranked: higher_ranked.clone(),
+        },
+        it => it.clone(),
+    }
+}
+
+fn substitute_param(param: &ResolvedGenericParam, concrete: &dyn Fn(&ResolvedType) -> ResolvedType) -> ResolvedGenericParam {
+    match param {
+        ResolvedGenericParam::Type { name, bounds, default } => ResolvedGenericParam::Type {
+            name: name.clone(),
+            bounds: bounds.iter().map(|it| substitute_bound(it, concrete)).collect(),
+            default: default.as_ref().map(concrete),
+        },
+        it => it.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{primitive::Primitive, test_support::compiled, GlobalIdent, ResolvedType};
+
+    const SOURCE: &str = "
+        pub trait Show { fn show(&self) -> u8; }
+        pub trait Describe { fn describe(&self) -> u8 { 0 } }
+        pub trait Big {}
+        pub struct W<T> { pub value: T }
+        pub type WU8 = W<u8>;
+        impl<T> W<T> { pub fn get(&self) -> T { todo!() } }
+        impl WU8 { pub fn byte(&self) -> u8 { 0 } }
+        impl W<u16> { pub fn wide(&self) -> u16 { 0 } }
+        impl<T: Big> W<T> { pub fn big(&self) {} }
+        impl<T> Show for W<T> { fn show(&self) -> u8 { 0 } }
+        impl<T: Show> Describe for T {}
+    ";
+
+    fn w_u8() -> ResolvedType {
+        ResolvedType::Path {
+            address: GlobalIdent::from_qualified_name("c::W"),
+            args: vec![ResolvedType::Primitive(Primitive::U8)],
+        }
+    }
+
+    #[test]
+    fn methods_of_inherent_and_trait_impls() {
+        let db = compiled(SOURCE);
+        let api = db.monomorphize(&w_u8()).unwrap();
+        let mut methods = api.methods.iter().filter(|it| !it.blanket).map(|it| it.name.as_str()).collect::<Vec<_>>();
+        methods.sort();
+        assert_eq!(methods, ["byte", "get", "show"]);
+        let get = api.methods.iter().find(|it| it.name == "get").unwrap();
+        assert_eq!(get.output, ResolvedType::Primitive(Primitive::U8));
+        assert_eq!(api.fields[0].ty, ResolvedType::Primitive(Primitive::U8));
+        let dropped = api.dropped.iter().filter(|(it, _)| it.starts_with("c::")).count();
+        assert_eq!(dropped, 1, "{:?}", api.dropped);
+    }
+
+    #[test]
+    fn impls_are_indexed_by_self_type() {
+        let db = compiled(SOURCE);
+        let w = GlobalIdent::from_qualified_name("c::W");
+        assert_eq!(db.impls_of_type[&w].len(), 5);
+        let own_blanket = db.blanket_impls.iter().filter(|it| it.first_part() == "c").count();
+        assert_eq!(own_blanket, 1);
+        // `Describe` applies through `Show`, the method is flagged
+        let api = db.monomorphize(&w_u8()).unwrap();
+        let describe = api.methods.iter().find(|it| it.name == "describe").unwrap();
+        assert!(describe.blanket);
+    }
+}
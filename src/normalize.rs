@@ -0,0 +1,270 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use syn::{Item, ItemImpl, TraitItem};
+
+use crate::{
+    generic_bounds::{GenericBound, ResolvedGenericParam, ResolvedGenerics},
+    trait_resolution::{ImplMatch, Solver},
+    Ast, Database, DeclAst, GlobalIdent, ResolvedType, TraitRef,
+};
+
+const MAX_NORMALIZATION_DEPTH: usize = 16;
+
+/// projection which can't be replaced with a concrete type, with the reason
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbstractProjection {
+    pub projection: ResolvedType,
+    pub reason: String,
+}
+
+impl Database {
+    /// replaces projections (`<T as Trait>::Assoc`) with the associated types chosen by the matching impls
+    pub fn normalize(&self, ty: &ResolvedType) -> ResolvedType {
+        self.normalize_in(ty, &Default::default()).0
+    }
+
+    /// like `normalize`, but bindings of the params' bounds (`T: Iterator<Item = u8>`) are used too.
+    /// also returns the projections which stayed abstract
+    pub fn normalize_in(&self, ty: &ResolvedType, env: &ResolvedGenerics) -> (ResolvedType, Vec<AbstractProjection>) {
+        let mut solver = Solver::new(self, env);
+        let ty = solver.normalize(ty);
+        (ty, solver.abstract_projections)
+    }
+
+    /// substitution for `Self` and the params of the trait, omitted args are taken from defaults (like `Rhs = Self`)
+    pub fn trait_substitution(&self, self_ty: &ResolvedType, trait_: &TraitRef) -> BTreeMap<String, ResolvedType> {
+        let mut substitution = BTreeMap::new();
+        substitution.insert("Self".to_string(), self_ty.clone());
+        self.fill_args(&trait_.address, &trait_.args, &mut substitution);
+        substitution
+    }
+
+    /// args of the trait, omitted ones are taken from defaults (like `Rhs = Self`), see `with_default_args`
+    pub fn trait_args(&self, self_ty: &ResolvedType, trait_: &TraitRef) -> Vec<ResolvedType> {
+        let mut substitution = BTreeMap::new();
+        substitution.insert("Self".to_string(), self_ty.clone());
+        self.fill_args(&trait_.address, &trait_.args, &mut substitution)
+            .iter()
+            .map(|it| self.with_default_args(it))
+            .collect()
+    }
+
+    /// the type with omitted args of its paths taken from defaults (like `A = Global` of `Vec<T, A>`),
+    /// so a type written with and without defaulted args is the same
+    pub fn with_default_args(&self, ty: &ResolvedType) -> ResolvedType {
+        ty.map(&mut |it| match it {
+            ResolvedType::Path { address, args } => {
+                let args = self.fill_args(&address, &args, &mut BTreeMap::new());
+                ResolvedType::Path { address, args }
+            }
+            it => it,
+        })
+    }
+
+    /// args for the params of the declaration, omitted ones are taken from defaults while there are any.
+    /// the params are added to `substitution`
+    fn fill_args(
+        &self,
+        address: &GlobalIdent,
+        args: &[ResolvedType],
+        substitution: &mut BTreeMap<String, ResolvedType>,
+    ) -> Vec<ResolvedType> {
+        let Some(generics) = self.generics_of(address) else {
+            return args.to_vec();
+        };
+        let params = generics.params.into_iter().filter_map(|it| match it {
+            ResolvedGenericParam::Type { name, default, .. } => Some((name, default)),
+            ResolvedGenericParam::Const { name, default, .. } => Some((name, default.map(ResolvedType::Const))),
+            ResolvedGenericParam::Lifetime { .. } => None,
+        });
+        let mut result = vec![];
+        for (i, (name, default)) in params.enumerate() {
+            let Some(arg) = args.get(i).cloned().or_else(|| default.map(|it| it.substitute(substitution))) else {
+                break;
+            };
+            substitution.insert(name, arg.clone());
+            result.push(arg);
+        }
+        result.extend(args.iter().skip(result.len()).cloned());
+        result
+    }
+
+    /// `type Item = T;` default of the associated type in the trait declaration
+    fn default_assoc_type(&self, trait_: &GlobalIdent, assoc: &str) -> Option<ResolvedType> {
+        self.trait_items(trait_).into_iter().find_map(|it| match it.item {
+            TraitItem::Type(it) if it.ident == assoc => it.default.map(|(_, ty)| self.resolve_type(&ty)),
+            _ => None,
+        })
+    }
+
+    /// visits every impl (trait and inherent) with its address
+    pub fn for_each_impl(&self, f: &mut dyn FnMut(&GlobalIdent, &ItemImpl)) {
+        self.decls.for_each(&mut |_, binding| {
+            if let Some(DeclAst { address, ast: Ast::Real(Item::Impl(it)) }) = &binding.non_type_ast {
+                f(address, it);
+            }
+        });
+    }
+
+    pub fn impl_ast(&self, address: &GlobalIdent) -> Option<&ItemImpl> {
+        match &self.decls.find_value(address.to_parts())?.non_type_ast.as_ref()?.ast {
+            Ast::Real(Item::Impl(it)) => Some(it),
+            _ => None,
+        }
+    }
+}
+
+impl Solver<'_> {
+    pub(crate) fn normalize(&mut self, ty: &ResolvedType) -> ResolvedType {
+        self.normalize_internal(ty, 0)
+    }
+
+    fn normalize_internal(&mut self, ty: &ResolvedType, depth: usize) -> ResolvedType {
+        if depth > MAX_NORMALIZATION_DEPTH {
+            println!("WARN: normalization is too deep for {}", ty);
+            return ty.clone();
+        }
+        ty.map(&mut |it| {
+            let ResolvedType::Projection { self_ty, trait_, assoc } = &it else {
+                return it;
+            };
+            match self.project(self_ty, trait_, assoc) {
+                Ok(concrete) => {
+                    println!("normalized {} to {}", it, concrete);
+                    self.normalize_internal(&concrete, depth + 1)
+                }
+                Err(reason) => {
+                    // projections met while proving bounds are reported by the bounds themselves
+                    if self.stack.is_empty() {
+                        println!("WARN: {} stays abstract: {}", it, reason);
+                        self.abstract_projections.push(AbstractProjection {
+                            projection: it.clone(),
+                            reason,
+                        });
+                    }
+                    it
+                }
+            }
+        })
+    }
+
+    /// associated type chosen by the impl of `trait_` for `self_ty`
+    fn project(&mut self, self_ty: &ResolvedType, trait_: &TraitRef, assoc: &str) -> Result<ResolvedType, String> {
+        if let ResolvedType::Generic(param) = self_ty {
+            return self
+                .bound_assoc_binding(param, assoc)
+                .ok_or_else(|| format!("{} is a generic param, so the impl is unknown and its bounds don't bind {}", param, assoc));
+        }
+        match self.solve(self_ty, trait_) {
+            ImplMatch::Impl { address, substitution } => {
+                let Some(record) = self.db.impl_record(&trait_.address, &address) else {
+                    return Err(format!("impl {} is not indexed", address));
+                };
+                if let Some(it) = record.assoc_types.get(assoc) {
+                    let unbound = record.params().into_iter().filter(|it| !substitution.contains_key(it)).collect();
+                    if mentions_any(it, &unbound) {
+                        return Err(format!("{} of generic impl {} depends on its unbound params", assoc, address));
+                    }
+                    return Ok(it.substitute(&substitution));
+                }
+                self.db
+                    .default_assoc_type(&trait_.address, assoc)
+                    .map(|it| it.substitute(&self.db.trait_substitution(self_ty, trait_)))
+                    .ok_or_else(|| format!("impl {} doesn't bind {}", address, assoc))
+            }
+            ImplMatch::Bound => Err(format!("{} is bounded by {}, but {} is not bound", self_ty, trait_, assoc)),
+            ImplMatch::Builtin | ImplMatch::Auto => Err(format!("{}: {} is implemented by the compiler", self_ty, trait_)),
+            ImplMatch::NoMatch(reasons) => Err(reasons.join("; ")),
+        }
+    }
+
+    /// `u8` for `Item` if the param (or a supertrait of its bound) is bounded like `T: Iterator<Item = u8>`
+    fn bound_assoc_binding(&self, param: &str, assoc: &str) -> Option<ResolvedType> {
+        let mut checked = BTreeSet::new();
+        let mut queue = self.env.bounds_of(param);
+        while let Some(bound) = queue.pop() {
+            let GenericBound::Trait { trait_, assoc_bindings, .. } = bound else {
+                continue;
+            };
+            if let Some(it) = assoc_bindings.get(assoc) {
+                return Some(it.clone());
+            }
+            if checked.insert(trait_.address.clone()) {
+                queue.extend(self.db.supertraits(&trait_.address));
+            }
+        }
+        None
+    }
+}
+
+fn mentions_any(ty: &ResolvedType, params: &BTreeSet<String>) -> bool {
+    let mut found = false;
+    ty.for_each(&mut |it| {
+        if let ResolvedType::Generic(it) = it {
+            found |= params.contains(it);
+        }
+    });
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{primitive::Primitive, test_support::compiled, GlobalIdent, ResolvedType, TraitRef};
+
+    const SOURCE: &str = "
+        pub trait Tr { type Out; type Def = u32; }
+        pub struct S;
+        pub struct G<T>(T);
+        impl Tr for S { type Out = u8; }
+        impl<T> Tr for G<T> { type Out = T; type Def = (); }
+        pub fn bound<T: Tr<Out = u16>>() {}
+        pub fn unbound<T: Tr>() {}
+    ";
+
+    fn projection(self_ty: ResolvedType, assoc: &str) -> ResolvedType {
+        ResolvedType::Projection {
+            self_ty: Box::new(self_ty),
+            trait_: TraitRef {
+                address: GlobalIdent::from_qualified_name("c::Tr"),
+                args: vec![],
+            },
+            assoc: assoc.to_string(),
+        }
+    }
+
+    fn path(name: &str, args: Vec<ResolvedType>) -> ResolvedType {
+        ResolvedType::Path {
+            address: GlobalIdent::from_qualified_name(name),
+            args,
+        }
+    }
+
+    #[test]
+    fn projections_of_impls() {
+        let db = compiled(SOURCE);
+        let u8_ = ResolvedType::Primitive(Primitive::U8);
+        assert_eq!(db.normalize(&projection(path("c::S", vec![]), "Out")), u8_);
+        assert_eq!(db.normalize(&projection(path("c::S", vec![]), "Def")), ResolvedType::Primitive(Primitive::U32));
+        let g = path("c::G", vec![u8_.clone()]);
+        assert_eq!(db.normalize(&projection(g.clone(), "Out")), u8_);
+        assert_eq!(db.normalize(&projection(g, "Def")), ResolvedType::unit());
+        // nested projections are normalized too
+        let nested = path("c::G", vec![projection(path("c::S", vec![]), "Out")]);
+        assert_eq!(db.normalize(&nested), path("c::G", vec![u8_]));
+    }
+
+    #[test]
+    fn projections_of_params() {
+        let db = compiled(SOURCE);
+        let t = ResolvedType::Generic("T".to_string());
+        let env = db.generics_of(&GlobalIdent::from_qualified_name("c::bound")).unwrap();
+        let (ty, abstract_) = db.normalize_in(&projection(t.clone(), "Out"), &env);
+        assert_eq!(ty, ResolvedType::Primitive(Primitive::U16));
+        assert!(abstract_.is_empty());
+
+        let env = db.generics_of(&GlobalIdent::from_qualified_name("c::unbound")).unwrap();
+        let (ty, abstract_) = db.normalize_in(&projection(t.clone(), "Out"), &env);
+        assert_eq!(ty, projection(t, "Out"));
+        assert_eq!(abstract_.len(), 1);
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Primitive {
+    Bool,
+    Char,
+    Str,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    F16,
+    F32,
+    F64,
+    F128,
+}
+
+impl Primitive {
+    pub const ALL: [Primitive; 19] = [
+        Primitive::Bool,
+        Primitive::Char,
+        Primitive::Str,
+        Primitive::I8,
+        Primitive::I16,
+        Primitive::I32,
+        Primitive::I64,
+        Primitive::I128,
+        Primitive::Isize,
+        Primitive::U8,
+        Primitive::U16,
+        Primitive::U32,
+        Primitive::U64,
+        Primitive::U128,
+        Primitive::Usize,
+        Primitive::F16,
+        Primitive::F32,
+        Primitive::F64,
+        Primitive::F128,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Primitive> {
+        Self::ALL.into_iter().find(|it| it.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Primitive::Bool => "bool",
+            Primitive::Char => "char",
+            Primitive::Str => "str",
+            Primitive::I8 => "i8",
+            Primitive::I16 => "i16",
+            Primitive::I32 => "i32",
+            Primitive::I64 => "i64",
+            Primitive::I128 => "i128",
+            Primitive::Isize => "isize",
+            Primitive::U8 => "u8",
+            Primitive::U16 => "u16",
+            Primitive::U32 => "u32",
+            Primitive::U64 => "u64",
+            Primitive::U128 => "u128",
+            Primitive::Usize => "usize",
+            Primitive::F16 => "f16",
+            Primitive::F32 => "f32",
+            Primitive::F64 => "f64",
+            Primitive::F128 => "f128",
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        !matches!(self, Primitive::Bool | Primitive::Char | Primitive::Str) && !self.is_float()
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self, Primitive::F16 | Primitive::F32 | Primitive::F64 | Primitive::F128)
+    }
+}
+
+impl Display for Primitive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::BTreeSet;
+
+use crate::{Database, GlobalIdent};
+
+impl Database {
+    /// declarations which signatures (field types, params, return types, bounds, impl headers) mention the declaration
+    pub fn referrers(&self, address: &GlobalIdent) -> BTreeSet<GlobalIdent> {
+        self.references.get(address).cloned().unwrap_or_default()
+    }
+
+    /// declarations affected if the declaration (or an item inside it, like a variant) is hidden or changed:
+    /// its referrers, their referrers and so on
+    pub fn dependents(&self, address: &GlobalIdent) -> BTreeSet<GlobalIdent> {
+        let mut queue = vec![address.clone()];
+        if let Some(node) = self.decls.find(address.to_parts()) {
+            node.for_each(&mut |_, binding| queue.push(binding.address.clone()));
+        }
+        let mut result = BTreeSet::new();
+        while let Some(it) = queue.pop() {
+            for referrer in self.referrers(&it) {
+                if referrer != *address && result.insert(referrer.clone()) {
+                    queue.push(referrer);
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_support::compiled, GlobalIdent};
+
+    const SOURCE: &str = "
+        pub enum Unit { Meter, Foot }
+        pub struct Length { pub value: f32, pub unit: Unit }
+        pub fn total(items: &[Length]) -> f32 { 0.0 }
+        pub struct Route { pub legs: Vec<Length> }
+        pub trait Measure { fn length(&self) -> Length; }
+        pub fn unrelated(x: u8) {}
+    ";
+
+    fn names(items: impl IntoIterator<Item = GlobalIdent>) -> Vec<String> {
+        items.into_iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn referrers_of_signatures() {
+        let db = compiled(SOURCE);
+        assert_eq!(
+            names(db.referrers(&GlobalIdent::from_qualified_name("c::Length"))),
+            ["c::Measure", "c::Measure::length", "c::Route", "c::total"]
+        );
+        assert!(db.referrers(&GlobalIdent::from_qualified_name("c::unrelated")).is_empty());
+    }
+
+    #[test]
+    fn dependents_are_transitive() {
+        let db = compiled(SOURCE);
+        assert_eq!(
+            names(db.dependents(&GlobalIdent::from_qualified_name("c::Unit"))),
+            ["c::Length", "c::Measure", "c::Measure::length", "c::Route", "c::total"]
+        );
+        assert!(db.dependents(&GlobalIdent::from_qualified_name("c::Unit::Foot")).is_empty());
+    }
+}
@@ -5,18 +5,28 @@ use std::{
     rc::Rc,
 };
 
+use proc_macro2::{TokenStream, TokenTree};
 use quote::ToTokens;
-use syn::{visit::Visit, visit_mut::{visit_item_type_mut, visit_path_mut, VisitMut}, Ident, Item, Path, PathResolution};
+use syn::{
+    visit::Visit,
+    visit_mut::{
+        visit_impl_item_type_mut, visit_item_enum_mut, visit_item_struct_mut, visit_item_trait_mut, visit_item_type_mut,
+        visit_generics_mut, visit_item_union_mut, visit_path_mut, visit_signature_mut, visit_trait_item_type_mut,
+        visit_type_mut, visit_type_path_mut, VisitMut,
+    },
+    GenericParam, Generics, Ident, ImplItem, ImplItemType, Item, ItemEnum, ItemImpl, ItemStruct, ItemTrait, ItemUnion, Path,
+    PathResolution, Signature, TraitBound, TraitItemType, Type, TypeParamBound, TypePath, WherePredicate,
+};
 use to_vec::ToVec;
 
 use crate::{
-    dedoc::{ItemExt, ItemTypeExt}, display_utils::DisplaySlice, ident_part::RefSliceOfIdentPartExt, named_tree::{FromPath, NamedNode}, stopwatch::start_watch, Ast, BindingResolution, Database, Decl, DeclAst, GlobalIdent, IdentPart, Mod, UnresolvedCtx, WildcardImport
+    dedoc::{ItemExt, ItemTypeExt}, display_utils::DisplaySlice, ident_part::RefSliceOfIdentPartExt, named_tree::{FromPath, NamedNode}, primitive::Primitive, stopwatch::start_watch, Ast, BindingResolution, Database, Decl, DeclAst, GlobalIdent, IdentPart, Mod, UnresolvedCtx, WildcardImport
 };
 
 #[derive(Debug, Default)]
 struct Resolved {
-    type_ast: Option<Item>,
-    non_type_ast: Option<Item>,
+    type_ast: Option<Ast>,
+    non_type_ast: Option<Ast>,
 }
 
 impl<K> FromPath<K> for Resolved {
@@ -34,14 +44,14 @@ impl Database {
                 println!(
                     "decl type {} -> {}",
                     ident,
-                    decl.ast.as_ref().map(|it| it.dedoc().to_token_stream().to_string()).unwrap_or("<stubbed type>".to_owned())
+                    decl.ast.to_item().map(|it| it.dedoc().to_token_stream().to_string()).unwrap_or("<stubbed type>".to_owned())
                 );
             }
             if let Some(decl) = &decl.non_type_ast {
                 println!(
                     "decl expr {} -> {}",
                     ident,
-                    decl.ast.as_ref().map(|it| it.dedoc().to_token_stream().to_string()).unwrap_or("<stubbed type>".to_owned())
+                    decl.ast.to_item().map(|it| it.dedoc().to_token_stream().to_string()).unwrap_or("<stubbed type>".to_owned())
                 );
             }
             for (import, _) in decl.alias_for.iter() {
@@ -50,6 +60,7 @@ impl Database {
         });
         let mut resolved: NamedNode<IdentPart, Resolved> = Default::default();
         let mut unresolved: BTreeMap<GlobalIdent, UnresolvedCtx> = Default::default();
+        let mut references: BTreeMap<GlobalIdent, BTreeSet<GlobalIdent>> = Default::default();
         let mut resolutions: NamedNode<IdentPart, BindingResolution> = Default::default();
 
         self.decls.for_each(&mut |key, decl| {
@@ -60,37 +71,41 @@ impl Database {
 
                 let mut ast = decl.ast.clone();
 
-                if let Ast::Real(ast) = &mut ast {
+                ast.visit_item_mut(|ast| {
                     SymbolsResolve {
                         db: self,
-                        parent: key.parent(),
+                        parent: self.scope_of(&key),
                         key: key.clone(),
                         unresolved: &mut unresolved,
+                        references: &mut references,
                         resolutions: &mut resolutions,
                         generics: Default::default(),
+                        self_scope: Default::default(),
                     }
-                    .visit_item_mut(ast);
-                }
+                    .visit_item_mut(ast)
+                });
 
-                resolved.find_or_create(&key).get_value_mut().type_ast = ast.into();
+                resolved.find_or_create(&key).get_value_mut().type_ast = Some(ast);
             }
             for decl in &decl.non_type_ast {
                 println!("resolve decl {}", key);
 
                 let mut ast = decl.ast.clone();
-                if let Ast::Real(ast) = &mut ast {
+                ast.visit_item_mut(|ast| {
                     SymbolsResolve {
                         db: self,
-                        parent: key.parent(),
+                        parent: self.scope_of(&key),
                         key: key.clone(),
                         unresolved: &mut unresolved,
+                        references: &mut references,
                         resolutions: &mut resolutions,
                         generics: Default::default(),
+                        self_scope: Default::default(),
                     }
-                    .visit_item_mut(ast);
-                }
+                    .visit_item_mut(ast)
+                });
 
-                resolved.find_or_create(&key).get_value_mut().non_type_ast = ast.into();
+                resolved.find_or_create(&key).get_value_mut().non_type_ast = Some(ast);
             }
         });
 
@@ -102,7 +117,7 @@ impl Database {
                         ..
                     }) = resolved
                     {
-                        ast.ast = Ast::Real(resolved.clone());
+                        ast.ast = resolved.clone();
                     }
                 }
                 if let Some(ast) = &mut decls.type_ast {
@@ -111,7 +126,7 @@ impl Database {
                         ..
                     }) = resolved
                     {
-                        ast.ast = Ast::Real(resolved.clone());
+                        ast.ast = resolved.clone();
                     }
                 }
             });
@@ -122,6 +137,7 @@ impl Database {
                         decls.resolution.and(*resolution);
                     }
                 });
+        self.references = references;
 
         for (ident, ctx) in self.unresolved.iter() {
             println!("unresolved: {ident}");
@@ -148,8 +164,160 @@ struct SymbolsResolve<'a> {
     parent: GlobalIdent,
     key: GlobalIdent,
     unresolved: &'a mut BTreeMap<GlobalIdent, UnresolvedCtx>,
+    /// declaration to the declarations mentioning it
+    references: &'a mut BTreeMap<GlobalIdent, BTreeSet<GlobalIdent>>,
     resolutions: &'a mut NamedNode<IdentPart, BindingResolution>,
-    generics: BTreeSet<Ident>,
+    /// generic params in scope with the traits they are bound by
+    generics: BTreeMap<Ident, Vec<GlobalIdent>>,
+    self_scope: SelfScope,
+}
+
+/// what `Self` means at the current point of the visited item
+#[derive(Default)]
+enum SelfScope {
+    #[default]
+    None,
+    /// struct, enum or union referring to itself
+    Type(GlobalIdent),
+    /// implicit generic parameter of a trait. `Self::Assoc` points to the associated type of this trait or its supertraits
+    Trait(GlobalIdent),
+    /// `Self` is replaced with the self type of the impl, `Self::Assoc` - with the type bound to it by the impl
+    Impl {
+        self_ty: Type,
+        trait_: Option<GlobalIdent>,
+        assoc_types: BTreeMap<Ident, Type>,
+    },
+}
+
+impl SymbolsResolve<'_> {
+    fn mark(&mut self, resolution: BindingResolution) {
+        self.resolutions.find_or_create(&self.key).get_value_mut().and(resolution);
+    }
+
+    fn add_reference(&mut self, address: &GlobalIdent) {
+        if *address != self.key {
+            self.references.entry(address.clone()).or_default().insert(self.key.clone());
+        }
+    }
+
+    /// returns generic params that were not in scope before (and should be removed by `exit_generics`)
+    fn enter_generics(&mut self, generics: &Generics) -> Vec<Ident> {
+        let mut params = vec![];
+        for it in generics.params.iter() {
+            let ident = match it {
+                GenericParam::Lifetime(_) => continue,
+                GenericParam::Type(it) => &it.ident,
+                GenericParam::Const(it) => &it.ident,
+            };
+            if !self.generics.contains_key(ident) {
+                self.generics.insert(ident.clone(), vec![]);
+                params.push(ident.clone());
+            }
+        }
+        params
+    }
+
+    fn exit_generics(&mut self, params: Vec<Ident>) {
+        for it in params {
+            self.generics.remove(&it);
+        }
+    }
+
+    fn add_bounds<'b>(&mut self, ty: &Type, bounds: impl Iterator<Item = &'b TypeParamBound>) {
+        let Type::Path(TypePath { qself: None, path }) = ty else {
+            return;
+        };
+        let Some(ident) = path.get_ident() else {
+            return;
+        };
+        let Some(known) = self.generics.get_mut(ident) else {
+            return;
+        };
+        for bound in bounds {
+            if let TypeParamBound::Trait(TraitBound { path, .. }) = bound {
+                if let PathResolution::Resolved(it) = &path.resolution {
+                    known.push(GlobalIdent::from_qualified_name(it));
+                }
+            }
+        }
+    }
+
+    /// `T::Assoc` where `T` is a generic param points to the associated type of one of the traits `T` is bound by
+    fn resolve_generic_assoc(&self, generic: &Ident, assoc: &str) -> PathResolution {
+        for bound in self.generics.get(generic).into_iter().flatten() {
+            if let Some(trait_) = self.db.find_assoc_type(bound, assoc) {
+                return PathResolution::Resolved(trait_.to_string() + "::" + assoc);
+            }
+        }
+        PathResolution::Failed
+    }
+
+    fn with_self_scope(&mut self, scope: SelfScope, f: impl FnOnce(&mut Self)) {
+        let prev = mem::replace(&mut self.self_scope, scope);
+        f(self);
+        self.self_scope = prev;
+    }
+
+    fn address_of(&self, ident: &Ident) -> GlobalIdent {
+        GlobalIdent::from_mod_and_name(&self.parent, &ident.to_string())
+    }
+
+    /// type to put instead of `Self` or `Self::Assoc` inside an impl
+    fn self_replacement(&self, i: &Type) -> Option<Type> {
+        let SelfScope::Impl { self_ty, assoc_types, .. } = &self.self_scope else {
+            return None;
+        };
+        let Type::Path(TypePath { qself: None, path }) = i else {
+            return None;
+        };
+        if path.leading_colon.is_some() || path.segments[0].ident != "Self" || !path.segments[0].arguments.is_none() {
+            return None;
+        }
+        match path.segments.len() {
+            1 => Some(self_ty.clone()),
+            2 if path.segments[1].arguments.is_none() => assoc_types.get(&path.segments[1].ident).cloned(),
+            _ => None,
+        }
+    }
+
+    /// resolution of paths starting with `Self` that were not replaced on the type level
+    fn resolve_self_path(&self, path: &[String]) -> PathResolution {
+        match (&self.self_scope, path) {
+            (SelfScope::None, _) => PathResolution::Failed,
+            (SelfScope::Type(address), [_]) => PathResolution::Resolved(address.to_string()),
+            (SelfScope::Trait(_), [_]) => PathResolution::Resolved("<Self>".to_owned()),
+            (SelfScope::Impl { self_ty: Type::Path(it), .. }, [_]) => it.path.resolution.clone(),
+            (SelfScope::Trait(trait_), [_, assoc]) => match self.db.find_assoc_type(trait_, assoc) {
+                Some(trait_) => PathResolution::Resolved(trait_.to_string() + "::" + assoc),
+                None => PathResolution::Failed,
+            },
+            (SelfScope::Impl { trait_: Some(trait_), .. }, [_, assoc]) => match self.db.find_assoc_type(trait_, assoc) {
+                Some(trait_) => PathResolution::Resolved(trait_.to_string() + "::" + assoc),
+                None => PathResolution::Failed,
+            },
+            _ => PathResolution::Failed,
+        }
+    }
+}
+
+/// removes the where-predicates mentioning `Self` from the generics
+fn take_self_predicates(generics: &mut Generics) -> Vec<WherePredicate> {
+    let Some(where_clause) = &mut generics.where_clause else {
+        return vec![];
+    };
+    let (with_self, rest): (Vec<_>, Vec<_>) = mem::take(&mut where_clause.predicates)
+        .into_iter()
+        .partition(|it| mentions_self(it.to_token_stream()));
+    where_clause.predicates = rest.into_iter().collect();
+    with_self
+}
+
+fn mentions_self(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|it| match it {
+        TokenTree::Ident(it) => it == "Self",
+        TokenTree::Group(it) => mentions_self(it.stream()),
+        _ => false,
+    })
 }
 
 impl VisitMut for SymbolsResolve<'_> {
@@ -160,45 +328,217 @@ impl VisitMut for SymbolsResolve<'_> {
     fn visit_expr_mut(&mut self, _i: &mut syn::Expr) {}
 
     fn visit_item_type_mut(&mut self, i: &mut syn::ItemType) {
-        let mut params: BTreeSet<Ident> = Default::default();
-        for it in i.generics.params.iter() {
-            match it {
-                syn::GenericParam::Lifetime(it) => {},
-                syn::GenericParam::Type(it) => {
-                    params.insert(it.ident.clone());
-                    if !self.generics.insert(it.ident.clone()) {
-                        panic!("duplicate generic param?");
-                    }
-                },
-                syn::GenericParam::Const(it) => {
-                    params.insert(it.ident.clone());
-                    if !self.generics.insert(it.ident.clone()) {
-                        panic!("duplicate generic param?");
+        let params = self.enter_generics(&i.generics);
+        visit_item_type_mut(self, i);
+        self.exit_generics(params);
+    }
+
+    fn visit_item_struct_mut(&mut self, i: &mut ItemStruct) {
+        let params = self.enter_generics(&i.generics);
+        self.with_self_scope(SelfScope::Type(self.address_of(&i.ident)), |self_| {
+            visit_item_struct_mut(self_, i);
+        });
+        self.exit_generics(params);
+    }
+
+    fn visit_item_enum_mut(&mut self, i: &mut ItemEnum) {
+        let params = self.enter_generics(&i.generics);
+        self.with_self_scope(SelfScope::Type(self.address_of(&i.ident)), |self_| {
+            visit_item_enum_mut(self_, i);
+        });
+        self.exit_generics(params);
+    }
+
+    fn visit_item_union_mut(&mut self, i: &mut ItemUnion) {
+        let params = self.enter_generics(&i.generics);
+        self.with_self_scope(SelfScope::Type(self.address_of(&i.ident)), |self_| {
+            visit_item_union_mut(self_, i);
+        });
+        self.exit_generics(params);
+    }
+
+    fn visit_item_trait_mut(&mut self, i: &mut ItemTrait) {
+        let params = self.enter_generics(&i.generics);
+        self.with_self_scope(SelfScope::Trait(self.address_of(&i.ident)), |self_| {
+            visit_item_trait_mut(self_, i);
+        });
+        self.exit_generics(params);
+    }
+
+    fn visit_item_impl_mut(&mut self, i: &mut ItemImpl) {
+        let params = self.enter_generics(&i.generics);
+        // bounds go first, so the header can use projections of the params (`Foo<T::Item>`).
+        // predicates mentioning `Self` wait for the self type
+        let mut self_predicates = take_self_predicates(&mut i.generics);
+        self.visit_generics_mut(&mut i.generics);
+        self.visit_type_mut(&mut i.self_ty);
+        let trait_ = match &mut i.trait_ {
+            Some((_, path, _)) => {
+                self.visit_path_mut(path);
+                match &path.resolution {
+                    PathResolution::Resolved(it) => Some(GlobalIdent::from_qualified_name(it)),
+                    _ => None,
+                }
+            }
+            None => None,
+        };
+        let scope = SelfScope::Impl {
+            self_ty: (*i.self_ty).clone(),
+            trait_,
+            assoc_types: Default::default(),
+        };
+        self.with_self_scope(scope, |self_| {
+            for it in self_predicates.iter_mut() {
+                self_.visit_where_predicate_mut(it);
+                if let WherePredicate::Type(it) = it {
+                    self_.add_bounds(&it.bounded_ty, it.bounds.iter());
+                }
+            }
+            if !self_predicates.is_empty() {
+                i.generics.make_where_clause().predicates.extend(self_predicates);
+            }
+            // associated types go first, so `Self::Assoc` in the methods is replaced with the bound type
+            for it in i.items.iter_mut() {
+                if let ImplItem::Type(it) = it {
+                    self_.visit_impl_item_type_mut(it);
+                    if let SelfScope::Impl { assoc_types, .. } = &mut self_.self_scope {
+                        assoc_types.insert(it.ident.clone(), it.ty.clone());
                     }
-                },
+                }
+            }
+            for it in i.items.iter_mut() {
+                if !matches!(it, ImplItem::Type(_)) {
+                    self_.visit_impl_item_mut(it);
+                }
+            }
+        });
+        self.exit_generics(params);
+    }
+
+    fn visit_impl_item_type_mut(&mut self, i: &mut ImplItemType) {
+        let params = self.enter_generics(&i.generics);
+        visit_impl_item_type_mut(self, i);
+        self.exit_generics(params);
+    }
+
+    fn visit_trait_item_type_mut(&mut self, i: &mut TraitItemType) {
+        let params = self.enter_generics(&i.generics);
+        visit_trait_item_type_mut(self, i);
+        self.exit_generics(params);
+    }
+
+    fn visit_signature_mut(&mut self, i: &mut Signature) {
+        let params = self.enter_generics(&i.generics);
+        visit_signature_mut(self, i);
+        self.exit_generics(params);
+    }
+
+    fn visit_generics_mut(&mut self, i: &mut Generics) {
+        visit_generics_mut(self, i);
+        for param in i.params.iter() {
+            if let GenericParam::Type(param) = param {
+                let ty = Type::Path(TypePath {
+                    qself: None,
+                    path: Path::from(param.ident.clone()),
+                });
+                self.add_bounds(&ty, param.bounds.iter());
             }
         }
-        visit_item_type_mut(self, i);
-        for it in params {
-            self.generics.remove(&it);
+        for predicate in i.where_clause.iter().flat_map(|it| it.predicates.iter()) {
+            if let WherePredicate::Type(predicate) = predicate {
+                self.add_bounds(&predicate.bounded_ty, predicate.bounds.iter());
+            }
         }
     }
 
+    fn visit_type_path_mut(&mut self, i: &mut TypePath) {
+        if i.qself.is_none() {
+            visit_type_path_mut(self, i);
+            return;
+        }
+        println!("  visit qualified {}", i.to_token_stream());
+        let qself = i.qself.as_mut().unwrap();
+        self.visit_type_mut(&mut qself.ty);
+        let position = qself.position;
+        let qself_ty = (*qself.ty).clone();
+        let mut trait_path = i.path.clone();
+        trait_path.segments = i.path.segments.iter().take(position).cloned().collect();
+        for it in i.path.segments.iter_mut().skip(position) {
+            self.visit_path_arguments_mut(&mut it.arguments);
+        }
+        let assoc = i.path.segments.iter().nth(position).map(|it| it.ident.to_string());
+        let resolution = match (position, assoc) {
+            (0, Some(assoc)) => match &qself_ty {
+                // `<T>::Assoc`
+                Type::Path(TypePath { qself: None, path }) if path.get_ident().is_some_and(|it| self.generics.contains_key(it)) => {
+                    self.resolve_generic_assoc(path.get_ident().unwrap(), &assoc)
+                }
+                _ => PathResolution::Failed,
+            },
+            (_, Some(assoc)) => {
+                self.visit_path_mut(&mut trait_path);
+                match &trait_path.resolution {
+                    PathResolution::Resolved(it) => match self.db.find_assoc_type(&GlobalIdent::from_qualified_name(it), &assoc) {
+                        Some(trait_) => PathResolution::Resolved(trait_.to_string() + "::" + &assoc),
+                        None => PathResolution::Failed,
+                    },
+                    _ => PathResolution::Failed,
+                }
+            }
+            (_, None) => PathResolution::Failed,
+        };
+        for (index, it) in trait_path.segments.into_iter().enumerate() {
+            i.path.segments[index] = it;
+        }
+        println!("    qualified path resolved to {:?}", resolution);
+        match resolution {
+            PathResolution::Resolved(_) => self.mark(BindingResolution::Fully),
+            _ => self.mark(BindingResolution::Failed),
+        }
+        i.path.resolution = resolution;
+    }
+
+    fn visit_type_mut(&mut self, i: &mut Type) {
+        if let Some(replacement) = self.self_replacement(i) {
+            println!("  replace {} with {}", i.to_token_stream(), replacement.to_token_stream());
+            *i = replacement;
+            return;
+        }
+        visit_type_mut(self, i);
+    }
+
     fn visit_path_mut(&mut self, i: &mut syn::Path) {
         println!("  visit {}", i.to_token_stream());
         let path = i.segments.iter().map(|it| it.ident.to_string()).to_vec();
-        if path.len() == 1 && path[0] == "Self" {
+        if path[0] == "Self" && i.leading_colon.is_none() {
+            visit_path_mut(self, i);
+            i.resolution = self.resolve_self_path(&path);
+            println!("    Self resolved to {:?}", i.resolution);
+            match i.resolution {
+                PathResolution::Resolved(_) => self.mark(BindingResolution::Fully),
+                _ => self.mark(BindingResolution::Failed),
+            }
             return;
         }
-        if i.segments.len() == 1 && self.generics.contains(&i.segments[0].ident) {
+        if i.segments.len() == 1 && self.generics.contains_key(&i.segments[0].ident) {
             i.resolution = PathResolution::Resolved(format!("<{}>", i.segments[0].ident));
-            self.resolutions.find_or_create(&self.key).get_value_mut().and(BindingResolution::Fully);
+            self.mark(BindingResolution::Fully);
             return;
         }
-        let candidates = [
-            GlobalIdent::from_mod_and_path(&self.parent, &path),
-            GlobalIdent::from_path(&path),
-        ];
+        if i.segments.len() == 2 && i.leading_colon.is_none() && self.generics.contains_key(&i.segments[0].ident) {
+            visit_path_mut(self, i);
+            i.resolution = self.resolve_generic_assoc(&i.segments[0].ident, &path[1]);
+            println!("    generic projection resolved to {:?}", i.resolution);
+            match &i.resolution {
+                PathResolution::Resolved(it) => {
+                    self.add_reference(&GlobalIdent::from_qualified_name(it));
+                    self.mark(BindingResolution::Fully)
+                }
+                _ => self.mark(BindingResolution::Failed),
+            }
+            return;
+        }
+        let candidates = self.db.candidates(&self.parent, &path);
 
         let mut partial_resolutions: BTreeSet<GlobalIdent> = Default::default();
 
@@ -211,7 +551,7 @@ impl VisitMut for SymbolsResolve<'_> {
             if *candidate == self.key {
                 i.resolution = PathResolution::Resolved(self.key.to_string());
                 println!("      resolved to {}", self.key);
-                self.resolutions.find_or_create(&self.key).get_value_mut().and(BindingResolution::Fully);
+                self.mark(BindingResolution::Fully);
                 return;
             }
 
@@ -230,7 +570,8 @@ impl VisitMut for SymbolsResolve<'_> {
                     }
                     i.resolution = PathResolution::Resolved(address.to_string());
                     println!("      resolved to {}", address);
-                    self.resolutions.find_or_create(&self.key).get_value_mut().and(BindingResolution::Fully);
+                    self.add_reference(&address);
+                    self.mark(BindingResolution::Fully);
                     return;
                 }
                 crate::Resolution::Partially(it) => {
@@ -240,10 +581,17 @@ impl VisitMut for SymbolsResolve<'_> {
                 crate::Resolution::Failed => {},
             }
         }
+        // primitive types are shadowed by declarations with the same name, so they go last
+        if path.len() == 1 && i.leading_colon.is_none() && Primitive::from_name(&path[0]).is_some() {
+            println!("      resolved to primitive {}", path[0]);
+            i.resolution = PathResolution::Resolved(path[0].clone());
+            self.mark(BindingResolution::Fully);
+            return;
+        }
         println!("    unresolved");
         i.resolution = PathResolution::Failed;
         binding_resolution.or(BindingResolution::Failed);
-        self.resolutions.find_or_create(&self.key).get_value_mut().and(binding_resolution);
+        self.mark(binding_resolution);
 
         println!("    partial resolutions:");
         for it in partial_resolutions.iter() {
@@ -255,7 +603,7 @@ impl VisitMut for SymbolsResolve<'_> {
         }
         if partial_resolutions.is_empty() {
             println!("    no resolutions found for {}", DisplaySlice(&candidates));
-            partial_resolutions = BTreeSet::from(candidates);
+            partial_resolutions = candidates.into_iter().collect();
         }
 
         let ctx = self
@@ -295,3 +643,73 @@ impl VisitMut for SymbolsResolve<'_> {
 
    fn visit_item_trait_alias_mut(&mut self, _i: &mut syn::ItemTraitAlias) {}
 */
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::{ImplItem, Item, PathResolution, ReturnType, TraitItem, Type};
+
+    use crate::{test_support::compiled, Ast, Database, GlobalIdent};
+
+    /// return type of the method of the impl or trait at `address` with the resolution of its path
+    fn output_of(db: &Database, address: &str, method: &str) -> (String, PathResolution) {
+        let binding = db.decls.find_value(GlobalIdent::from_qualified_name(address).to_parts()).unwrap();
+        let decl = binding.type_ast.as_ref().or(binding.non_type_ast.as_ref()).unwrap();
+        let sig = match &decl.ast {
+            Ast::Real(Item::Impl(it)) => it.items.iter().find_map(|it| match it {
+                ImplItem::Fn(it) if it.sig.ident == method => Some(&it.sig),
+                _ => None,
+            }),
+            Ast::Real(Item::Trait(it)) => it.items.iter().find_map(|it| match it {
+                TraitItem::Fn(it) if it.sig.ident == method => Some(&it.sig),
+                _ => None,
+            }),
+            it => panic!("not an impl or a trait: {:?}", it),
+        };
+        let ReturnType::Type(_, ty) = &sig.unwrap().output else {
+            panic!("{}::{} returns nothing", address, method);
+        };
+        let Type::Path(it) = &**ty else {
+            panic!("{}::{} returns not a path", address, method);
+        };
+        (ty.to_token_stream().to_string(), it.path.resolution.clone())
+    }
+
+    fn resolved(it: &str) -> PathResolution {
+        PathResolution::Resolved(it.to_string())
+    }
+
+    #[test]
+    fn self_in_impls_is_the_self_type() {
+        let db = compiled(
+            "
+            pub struct Unit;
+            pub trait Tr { type Output; fn make(&self) -> Self; fn out(&self) -> Self::Output; }
+            pub struct P<T> { pub x: T }
+            impl<T> P<T> where Self: Tr { pub fn me(self) -> Self { self } }
+            impl Tr for P<Unit> { type Output = Unit; fn make(&self) -> Self { todo!() } fn out(&self) -> Self::Output { Unit } }
+            ",
+        );
+        assert_eq!(output_of(&db, "c::{impl#0}", "me"), ("P < T >".to_string(), resolved("c::P")));
+        assert_eq!(output_of(&db, "c::{impl#1}", "make"), ("P < Unit >".to_string(), resolved("c::P")));
+        assert_eq!(output_of(&db, "c::{impl#1}", "out"), ("Unit".to_string(), resolved("c::Unit")));
+        let binding = db.decls.find_value(GlobalIdent::from_qualified_name("c::{impl#0}").to_parts()).unwrap();
+        let Some(Item::Impl(impl_)) = binding.non_type_ast.as_ref().unwrap().ast.as_ref() else {
+            panic!("not an impl");
+        };
+        let where_clause = impl_.generics.where_clause.as_ref().unwrap();
+        assert_eq!(where_clause.to_token_stream().to_string(), "where P < T > : Tr");
+    }
+
+    #[test]
+    fn self_in_traits_is_a_param() {
+        let db = compiled(
+            "
+            pub trait Base { type Item; }
+            pub trait Iter: Base { fn next(&mut self) -> Self::Item; fn me(&self) -> Self; }
+            ",
+        );
+        assert_eq!(output_of(&db, "c::Iter", "me"), ("Self".to_string(), resolved("<Self>")));
+        assert_eq!(output_of(&db, "c::Iter", "next"), ("Self :: Item".to_string(), resolved("c::Base::Item")));
+    }
+}
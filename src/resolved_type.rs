@@ -0,0 +1,446 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use quote::ToTokens;
+use syn::{GenericArgument, Path, PathArguments, PathResolution, ReturnType, Type, TypeParamBound};
+
+use crate::{primitive::Primitive, Database, GlobalIdent};
+
+/// Type from a signature with all paths replaced by the addresses they were resolved to.
+/// Built from the resolved AST, so it's only meaningful after `Database::compile`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResolvedType {
+    /// struct, enum, union or stubbed type
+    Path {
+        address: GlobalIdent,
+        args: Vec<ResolvedType>,
+    },
+    Primitive(Primitive),
+    /// generic parameter in scope (including `Self` of a trait)
+    Generic(String),
+    /// `<T as Trait>::Assoc`, `T::Assoc` or `Self::Assoc`
+    Projection {
+        self_ty: Box<ResolvedType>,
+        trait_: TraitRef,
+        assoc: String,
+    },
+    Reference {
+        mutable: bool,
+        elem: Box<ResolvedType>,
+    },
+    Ptr {
+        mutable: bool,
+        elem: Box<ResolvedType>,
+    },
+    Slice(Box<ResolvedType>),
+    Array {
+        elem: Box<ResolvedType>,
+        len: String,
+    },
+    Tuple(Vec<ResolvedType>),
+    Fn {
+        inputs: Vec<ResolvedType>,
+        output: Box<ResolvedType>,
+    },
+    TraitObject(Vec<TraitRef>),
+    ImplTrait(Vec<TraitRef>),
+    /// const generic argument
+    Const(String),
+    Never,
+    Infer,
+    /// type (or some path inside it) which failed to resolve
+    Unresolved(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TraitRef {
+    pub address: GlobalIdent,
+    pub args: Vec<ResolvedType>,
+}
+
+impl ResolvedType {
+    pub fn unit() -> ResolvedType {
+        ResolvedType::Tuple(vec![])
+    }
+
+    pub fn is_fully_resolved(&self) -> bool {
+        let mut ok = true;
+        self.for_each(&mut |it| {
+            if let ResolvedType::Unresolved(_) = it {
+                ok = false;
+            }
+        });
+        ok
+    }
+
+    /// visits this type and all types nested into it
+    pub fn for_each(&self, f: &mut dyn FnMut(&ResolvedType)) {
+        f(self);
+        match self {
+            ResolvedType::Path { args, .. } => args.iter().for_each(|it| it.for_each(f)),
+            ResolvedType::Projection { self_ty, trait_, .. } => {
+                self_ty.for_each(f);
+                trait_.args.iter().for_each(|it| it.for_each(f));
+            }
+            ResolvedType::Reference { elem, .. } => elem.for_each(f),
+            ResolvedType::Ptr { elem, .. } => elem.for_each(f),
+            ResolvedType::Slice(elem) => elem.for_each(f),
+            ResolvedType::Array { elem, .. } => elem.for_each(f),
+            ResolvedType::Tuple(items) => items.iter().for_each(|it| it.for_each(f)),
+            ResolvedType::Fn { inputs, output } => {
+                inputs.iter().for_each(|it| it.for_each(f));
+                output.for_each(f);
+            }
+            ResolvedType::TraitObject(bounds) | ResolvedType::ImplTrait(bounds) => {
+                for bound in bounds {
+                    bound.args.iter().for_each(|it| it.for_each(f));
+                }
+            }
+            ResolvedType::Primitive(_)
+            | ResolvedType::Generic(_)
+            | ResolvedType::Const(_)
+            | ResolvedType::Never
+            | ResolvedType::Infer
+            | ResolvedType::Unresolved(_) => {}
+        }
+    }
+
+    /// replaces generic params with the given types (and const params with `Const` values)
+    pub fn substitute(&self, substitutions: &BTreeMap<String, ResolvedType>) -> ResolvedType {
+        self.map(&mut |it| match &it {
+            ResolvedType::Generic(name) | ResolvedType::Const(name) => substitutions.get(name).cloned().unwrap_or(it),
+            ResolvedType::Array { elem, len } => match substitutions.get(len) {
+                Some(ResolvedType::Const(value)) => ResolvedType::Array {
+                    elem: elem.clone(),
+                    len: value.clone(),
+                },
+                _ => it,
+            },
+            _ => it,
+        })
+    }
+
+    /// rebuilds the type bottom-up, giving `f` a chance to replace every nested type
+    pub fn map(&self, f: &mut dyn FnMut(ResolvedType) -> ResolvedType) -> ResolvedType {
+        let mapped = match self {
+            ResolvedType::Path { address, args } => ResolvedType::Path {
+                address: address.clone(),
+                args: args.iter().map(|it| it.map(f)).collect(),
+            },
+            ResolvedType::Projection { self_ty, trait_, assoc } => ResolvedType::Projection {
+                self_ty: Box::new(self_ty.map(f)),
+                trait_: trait_.map(f),
+                assoc: assoc.clone(),
+            },
+            ResolvedType::Reference { mutable, elem } => ResolvedType::Reference {
+                mutable: *mutable,
+                elem: Box::new(elem.map(f)),
+            },
+            ResolvedType::Ptr { mutable, elem } => ResolvedType::Ptr {
+                mutable: *mutable,
+                elem: Box::new(elem.map(f)),
+            },
+            ResolvedType::Slice(elem) => ResolvedType::Slice(Box::new(elem.map(f))),
+            ResolvedType::Array { elem, len } => ResolvedType::Array {
+                elem: Box::new(elem.map(f)),
+                len: len.clone(),
+            },
+            ResolvedType::Tuple(items) => ResolvedType::Tuple(items.iter().map(|it| it.map(f)).collect()),
+            ResolvedType::Fn { inputs, output } => ResolvedType::Fn {
+                inputs: inputs.iter().map(|it| it.map(f)).collect(),
+                output: Box::new(output.map(f)),
+            },
+            ResolvedType::TraitObject(bounds) => ResolvedType::TraitObject(bounds.iter().map(|it| it.map(f)).collect()),
+            ResolvedType::ImplTrait(bounds) => ResolvedType::ImplTrait(bounds.iter().map(|it| it.map(f)).collect()),
+            it => it.clone(),
+        };
+        f(mapped)
+    }
+}
+
+impl TraitRef {
+    pub fn map(&self, f: &mut dyn FnMut(ResolvedType) -> ResolvedType) -> TraitRef {
+        TraitRef {
+            address: self.address.clone(),
+            args: self.args.iter().map(|it| it.map(f)).collect(),
+        }
+    }
+}
+
+impl Display for ResolvedType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolvedType::Path { address, args } => {
+                write!(f, "{}", address)?;
+                write_args(f, args)
+            }
+            ResolvedType::Primitive(it) => write!(f, "{}", it),
+            ResolvedType::Generic(it) => write!(f, "{}", it),
+            ResolvedType::Projection { self_ty, trait_, assoc } => write!(f, "<{} as {}>::{}", self_ty, trait_, assoc),
+            ResolvedType::Reference { mutable: true, elem } => write!(f, "&mut {}", elem),
+            ResolvedType::Reference { mutable: false, elem } => write!(f, "&{}", elem),
+            ResolvedType::Ptr { mutable: true, elem } => write!(f, "*mut {}", elem),
+            ResolvedType::Ptr { mutable: false, elem } => write!(f, "*const {}", elem),
+            ResolvedType::Slice(elem) => write!(f, "[{}]", elem),
+            ResolvedType::Array { elem, len } => write!(f, "[{}; {}]", elem, len),
+            ResolvedType::Tuple(items) if items.len() == 1 => write!(f, "({},)", items[0]),
+            ResolvedType::Tuple(items) => {
+                write!(f, "(")?;
+                write_list(f, items)?;
+                write!(f, ")")
+            }
+            ResolvedType::Fn { inputs, output } => {
+                write!(f, "fn(")?;
+                write_list(f, inputs)?;
+                write!(f, ") -> {}", output)
+            }
+            ResolvedType::TraitObject(bounds) => {
+                write!(f, "dyn ")?;
+                write_bounds(f, bounds)
+            }
+            ResolvedType::ImplTrait(bounds) => {
+                write!(f, "impl ")?;
+                write_bounds(f, bounds)
+            }
+            ResolvedType::Const(it) => write!(f, "{}", it),
+            ResolvedType::Never => write!(f, "!"),
+            ResolvedType::Infer => write!(f, "_"),
+            ResolvedType::Unresolved(it) => write!(f, "?{}", it),
+        }
+    }
+}
+
+impl Display for TraitRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.address)?;
+        write_args(f, &self.args)
+    }
+}
+
+fn write_list(f: &mut fmt::Formatter<'_>, items: &[ResolvedType]) -> fmt::Result {
+    for (i, it) in items.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", it)?;
+    }
+    Ok(())
+}
+
+fn write_args(f: &mut fmt::Formatter<'_>, args: &[ResolvedType]) -> fmt::Result {
+    if args.is_empty() {
+        return Ok(());
+    }
+    write!(f, "<")?;
+    write_list(f, args)?;
+    write!(f, ">")
+}
+
+fn write_bounds(f: &mut fmt::Formatter<'_>, bounds: &[TraitRef]) -> fmt::Result {
+    for (i, it) in bounds.iter().enumerate() {
+        if i > 0 {
+            write!(f, " + ")?;
+        }
+        write!(f, "{}", it)?;
+    }
+    Ok(())
+}
+
+impl Database {
+    /// converts already resolved type from some decl AST to the typed model
+    pub fn resolve_type(&self, ty: &Type) -> ResolvedType {
+        match ty {
+            Type::Path(it) => match &it.qself {
+                Some(qself) => {
+                    let trait_args = match qself.position {
+                        0 => vec![],
+                        position => self.resolve_args(&it.path.segments[position - 1].arguments),
+                    };
+                    self.resolve_projection(self.resolve_type(&qself.ty), &it.path, trait_args)
+                        .unwrap_or_else(|| ResolvedType::Unresolved(ty.to_token_stream().to_string()))
+                }
+                None => self.resolve_path_type(&it.path),
+            },
+            Type::Reference(it) => ResolvedType::Reference {
+                mutable: it.mutability.is_some(),
+                elem: Box::new(self.resolve_type(&it.elem)),
+            },
+            Type::Ptr(it) => ResolvedType::Ptr {
+                mutable: it.mutability.is_some(),
+                elem: Box::new(self.resolve_type(&it.elem)),
+            },
+            Type::Slice(it) => ResolvedType::Slice(Box::new(self.resolve_type(&it.elem))),
+            Type::Array(it) => ResolvedType::Array {
+                elem: Box::new(self.resolve_type(&it.elem)),
+                len: it.len.to_token_stream().to_string(),
+            },
+            Type::Tuple(it) => ResolvedType::Tuple(it.elems.iter().map(|it| self.resolve_type(it)).collect()),
+            Type::BareFn(it) => ResolvedType::Fn {
+                inputs: it.inputs.iter().map(|it| self.resolve_type(&it.ty)).collect(),
+                output: Box::new(self.resolve_return_type(&it.output)),
+            },
+            Type::TraitObject(it) => ResolvedType::TraitObject(self.resolve_trait_bounds(it.bounds.iter())),
+            Type::ImplTrait(it) => ResolvedType::ImplTrait(self.resolve_trait_bounds(it.bounds.iter())),
+            Type::Paren(it) => self.resolve_type(&it.elem),
+            Type::Group(it) => self.resolve_type(&it.elem),
+            Type::Never(_) => ResolvedType::Never,
+            Type::Infer(_) => ResolvedType::Infer,
+            it => ResolvedType::Unresolved(it.to_token_stream().to_string()),
+        }
+    }
+
+    pub fn resolve_return_type(&self, ty: &ReturnType) -> ResolvedType {
+        match ty {
+            ReturnType::Default => ResolvedType::unit(),
+            ReturnType::Type(_, ty) => self.resolve_type(ty),
+        }
+    }
+
+    pub fn resolve_trait_ref(&self, path: &Path) -> Option<TraitRef> {
+        match &path.resolution {
+            PathResolution::Resolved(it) if !it.starts_with('<') => Some(TraitRef {
+                address: GlobalIdent::from_qualified_name(it),
+                args: self.resolve_args(&path.segments.last().unwrap().arguments),
+            }),
+            _ => None,
+        }
+    }
+
+    fn resolve_trait_bounds<'a>(&self, bounds: impl Iterator<Item = &'a TypeParamBound>) -> Vec<TraitRef> {
+        bounds
+            .filter_map(|it| match it {
+                TypeParamBound::Trait(it) => self.resolve_trait_ref(&it.path),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn resolve_path_type(&self, path: &Path) -> ResolvedType {
+        let PathResolution::Resolved(resolution) = &path.resolution else {
+            return ResolvedType::Unresolved(path.to_token_stream().to_string());
+        };
+        if let Some(generic) = resolution.strip_prefix('<').and_then(|it| it.strip_suffix('>')) {
+            return ResolvedType::Generic(generic.to_owned());
+        }
+        if path.segments.len() == 1 && path.segments[0].ident == resolution {
+            if let Some(it) = Primitive::from_name(resolution) {
+                return ResolvedType::Primitive(it);
+            }
+        }
+        if path.segments.len() == 2 {
+            // shorthand projections (`T::Assoc`, `Self::Assoc`) are resolved to the associated type of the trait
+            let self_ty = ResolvedType::Generic(path.segments[0].ident.to_string());
+            if let Some(it) = self.resolve_projection(self_ty, path, vec![]) {
+                return it;
+            }
+        }
+        ResolvedType::Path {
+            address: GlobalIdent::from_qualified_name(resolution),
+            args: self.resolve_args(&path.segments.last().unwrap().arguments),
+        }
+    }
+
+    /// `path` should be resolved to the associated type of some trait
+    fn resolve_projection(&self, self_ty: ResolvedType, path: &Path, trait_args: Vec<ResolvedType>) -> Option<ResolvedType> {
+        let PathResolution::Resolved(resolution) = &path.resolution else {
+            return None;
+        };
+        let assoc_address = GlobalIdent::from_qualified_name(resolution);
+        let trait_ = assoc_address.parent();
+        let assoc = assoc_address.last_part().to_string();
+        if self.find_assoc_type(&trait_, &assoc).as_ref() != Some(&trait_) {
+            return None;
+        }
+        Some(ResolvedType::Projection {
+            self_ty: Box::new(self_ty),
+            trait_: TraitRef {
+                address: trait_,
+                args: trait_args,
+            },
+            assoc,
+        })
+    }
+
+    fn resolve_args(&self, args: &PathArguments) -> Vec<ResolvedType> {
+        match args {
+            PathArguments::AngleBracketed(args) => args
+                .args
+                .iter()
+                .filter_map(|it| match it {
+                    GenericArgument::Type(it) => Some(self.resolve_type(it)),
+                    GenericArgument::Const(it) => Some(ResolvedType::Const(it.to_token_stream().to_string())),
+                    _ => None,
+                })
+                .collect(),
+            PathArguments::Parenthesized(args) => {
+                // `Fn(A, B) -> C` sugar
+                vec![
+                    ResolvedType::Tuple(args.inputs.iter().map(|it| self.resolve_type(it)).collect()),
+                    self.resolve_return_type(&args.output),
+                ]
+            }
+            PathArguments::None => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{ImplItem, Item, Signature, TraitItem};
+
+    use crate::{test_support::compiled, Ast, Database, GlobalIdent};
+
+    fn signature<'a>(db: &'a Database, address: &str, method: &str) -> &'a Signature {
+        let binding = db.decls.find_value(GlobalIdent::from_qualified_name(address).to_parts()).unwrap();
+        let decl = binding.type_ast.as_ref().or(binding.non_type_ast.as_ref()).unwrap();
+        let sig = match &decl.ast {
+            Ast::Real(Item::Impl(it)) => it.items.iter().find_map(|it| match it {
+                ImplItem::Fn(it) if it.sig.ident == method => Some(&it.sig),
+                _ => None,
+            }),
+            Ast::Real(Item::Trait(it)) => it.items.iter().find_map(|it| match it {
+                TraitItem::Fn(it) if it.sig.ident == method => Some(&it.sig),
+                _ => None,
+            }),
+            Ast::Real(Item::Fn(it)) => Some(&it.sig),
+            it => panic!("not an impl, a trait or a function: {:?}", it),
+        };
+        sig.unwrap()
+    }
+
+    fn output_of(db: &Database, address: &str, method: &str) -> String {
+        db.resolve_return_type(&signature(db, address, method).output).to_string()
+    }
+
+    #[test]
+    fn projections() {
+        let db = compiled(
+            "
+            pub struct Unit;
+            pub trait Tr {
+                type Out;
+                fn out(&self) -> Self::Out;
+                fn qualified<T: Tr>(t: T) -> <T as Tr>::Out;
+                fn shorthand<T: Tr>(t: T) -> T::Out;
+            }
+            pub struct P;
+            impl Tr for P { type Out = Unit; fn out(&self) -> Self::Out { Unit } }
+            pub struct W<T>(T);
+            impl<T: Tr> W<T::Out> { pub fn get(&self) -> Self { todo!() } }
+            pub fn of_p(p: P) -> <P as Tr>::Out { Unit }
+            ",
+        );
+        assert_eq!(output_of(&db, "c::Tr", "out"), "<Self as c::Tr>::Out");
+        assert_eq!(output_of(&db, "c::Tr", "qualified"), "<T as c::Tr>::Out");
+        assert_eq!(output_of(&db, "c::Tr", "shorthand"), "<T as c::Tr>::Out");
+        // the impl binds `Out` itself
+        assert_eq!(output_of(&db, "c::{impl#0}", "out"), "c::Unit");
+        // bounds of the impl are known in its header
+        assert_eq!(output_of(&db, "c::{impl#1}", "get"), "c::W<<T as c::Tr>::Out>");
+        let of_p = db.resolve_return_type(&signature(&db, "c::of_p", "of_p").output);
+        assert_eq!(of_p.to_string(), "<c::P as c::Tr>::Out");
+        assert_eq!(db.normalize(&of_p).to_string(), "c::Unit");
+    }
+}
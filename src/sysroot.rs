@@ -0,0 +1,99 @@
+use std::process::Command;
+
+use crate::Database;
+
+/// crates of the standard library in the order of their dependencies
+const LIBRARY_CRATES: [&str; 3] = ["core", "alloc", "std"];
+
+/// features the toolchain's `std` is built with, the defaults of `library/sysroot`
+const STD_FEATURES: [&str; 4] = ["backtrace", "panic_unwind", "std_detect_file_io", "std_detect_dlsym_getauxval"];
+
+impl Database {
+    /// adds `core`, `alloc` and `std` from the `rust-src` component of the active toolchain
+    /// (`rustup component add rust-src`). must be called before `compile`, so the bundled models are not used.
+    /// on error nothing is added and `compile` falls back to the bundled models
+    pub fn add_sysroot(&mut self) -> Result<(), String> {
+        let output = Command::new("rustc")
+            .args(["--print", "sysroot"])
+            .output()
+            .map_err(|err| format!("cannot run `rustc --print sysroot`: {}", err))?;
+        if !output.status.success() {
+            return Err(format!("`rustc --print sysroot` failed: {}", String::from_utf8_lossy(&output.stderr)));
+        }
+        let sysroot = String::from_utf8_lossy(&output.stdout).trim().replace('\\', "/");
+        self.add_library(&format!("{}/lib/rustlib/src/rust/library", sysroot))
+    }
+
+    /// adds `core`, `alloc` and `std` from the `library` directory of the Rust sources, see `add_sysroot`.
+    /// they are evaluated with the cfgs of the distributed build: no `test` and `debug_assertions`,
+    /// `STD_FEATURES` for `std` and no features for the rest, unless set by `set_features`
+    pub fn add_library(&mut self, library_path: &str) -> Result<(), String> {
+        for name in LIBRARY_CRATES {
+            let lib_path = format!("{}/{}/src/lib.rs", library_path, name);
+            if let Err(err) = std::fs::read_to_string(&lib_path) {
+                return Err(format!("cannot read {:?}: {}. please run `rustup component add rust-src`", lib_path, err));
+            }
+        }
+        for name in LIBRARY_CRATES {
+            if !self.features.contains_key(name) {
+                let features: &[&str] = if name == "std" { &STD_FEATURES } else { &[] };
+                self.set_features(name, features);
+            }
+        }
+        let mut cfg = self.cfg.clone();
+        cfg.flags.remove("test");
+        cfg.flags.remove("debug_assertions");
+        let host_cfg = std::mem::replace(&mut self.cfg, cfg);
+        for name in LIBRARY_CRATES {
+            self.add_crate(library_path, name);
+        }
+        self.cfg = host_cfg;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{Database, GlobalIdent};
+
+    #[test]
+    fn library_is_added_with_std_cfgs() {
+        let dir = std::env::temp_dir().join(format!("syg_library_{}", std::process::id()));
+        for name in ["core", "alloc"] {
+            fs::create_dir_all(dir.join(name).join("src")).unwrap();
+            fs::write(dir.join(name).join("src/lib.rs"), "").unwrap();
+        }
+        fs::create_dir_all(dir.join("std/src")).unwrap();
+        fs::write(
+            dir.join("std/src/lib.rs"),
+            r#"
+            #[cfg(feature = "backtrace")]
+            pub struct Backtrace;
+            #[cfg(feature = "panic_immediate_abort")]
+            pub struct Abort;
+            #[cfg(not(test))]
+            pub struct NotTest;
+            "#,
+        )
+        .unwrap();
+        let mut db = Database::default();
+        db.cfg.set_flag("test");
+        let result = db.add_library(&dir.to_string_lossy());
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result, Ok(()));
+        let has = |name: &str| db.decls.find_value(GlobalIdent::from_qualified_name(name).to_parts()).is_some();
+        assert!(has("std::Backtrace"));
+        assert!(!has("std::Abort"));
+        assert!(has("std::NotTest"));
+        assert!(db.cfg.flags.contains("test"));
+    }
+
+    #[test]
+    fn missing_library_is_an_error() {
+        let mut db = Database::default();
+        assert!(db.add_library("/nonexistent").is_err());
+        assert!(db.decls.find_value(GlobalIdent::from_qualified_name("core").to_parts()).is_none());
+    }
+}
@@ -0,0 +1,9 @@
+use crate::{crate_meta::Edition, Database};
+
+/// compiled database of a single crate `c` with the source as its `lib.rs`
+pub(crate) fn compiled(source: &str) -> Database {
+    let mut db = Database::default();
+    db.add_crate_source("c", source, Edition::E2021);
+    db.compile();
+    db
+}
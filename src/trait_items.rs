@@ -0,0 +1,106 @@
+use syn::TraitItem;
+
+use crate::{Ast, BindingResolution, Database, DeclAst, GlobalIdent};
+
+/// method, associated type or associated const of a trait
+#[derive(Debug, Clone)]
+pub struct TraitItemDecl {
+    pub address: GlobalIdent,
+    pub item: TraitItem,
+    /// has a default body, type or value, so implementors may omit it
+    pub provided: bool,
+    pub resolution: BindingResolution,
+}
+
+impl TraitItemDecl {
+    pub fn from_decl(decl: &DeclAst, resolution: BindingResolution) -> Option<TraitItemDecl> {
+        let Ast::TraitItem(_, item) = &decl.ast else {
+            return None;
+        };
+        let provided = match item {
+            TraitItem::Fn(it) => it.default.is_some(),
+            TraitItem::Type(it) => it.default.is_some(),
+            TraitItem::Const(it) => it.default.is_some(),
+            _ => return None,
+        };
+        Some(TraitItemDecl {
+            address: decl.address.clone(),
+            item: item.clone(),
+            provided,
+            resolution,
+        })
+    }
+}
+
+impl Database {
+    /// items declared by the trait itself, without supertraits
+    pub fn trait_items(&self, trait_: &GlobalIdent) -> Vec<TraitItemDecl> {
+        let Some(node) = self.decls.find(trait_.to_parts()) else {
+            return vec![];
+        };
+        let mut result = vec![];
+        for child in node.children() {
+            let binding = child.get_value();
+            for decl in binding.type_ast.iter().chain(binding.non_type_ast.iter()) {
+                result.extend(TraitItemDecl::from_decl(decl, binding.resolution));
+            }
+        }
+        result
+    }
+
+    pub fn required_items(&self, trait_: &GlobalIdent) -> Vec<TraitItemDecl> {
+        self.trait_items(trait_).into_iter().filter(|it| !it.provided).collect()
+    }
+
+    pub fn provided_items(&self, trait_: &GlobalIdent) -> Vec<TraitItemDecl> {
+        self.trait_items(trait_).into_iter().filter(|it| it.provided).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{Type, TraitItem};
+
+    use crate::{test_support::compiled, Ast, GlobalIdent};
+
+    const SOURCE: &str = "
+        pub struct Unit;
+        pub trait Shape {
+            type Out;
+            const SIDES: u8 = 0;
+            fn area<F: Fn() -> Unit>(&self, f: F) -> Unit;
+            fn scale(&self) -> Unit { Unit }
+        }
+    ";
+
+    fn names(items: Vec<super::TraitItemDecl>) -> Vec<String> {
+        items.iter().map(|it| it.address.to_string()).collect()
+    }
+
+    #[test]
+    fn required_and_provided_items() {
+        let db = compiled(SOURCE);
+        let shape = GlobalIdent::from_qualified_name("c::Shape");
+        assert_eq!(names(db.required_items(&shape)), ["c::Shape::Out", "c::Shape::area"]);
+        assert_eq!(names(db.provided_items(&shape)), ["c::Shape::SIDES", "c::Shape::scale"]);
+    }
+
+    #[test]
+    fn trait_items_keep_their_kind_after_resolution() {
+        let db = compiled(SOURCE);
+        let area = GlobalIdent::from_qualified_name("c::Shape::area");
+        let binding = db.decls.find_value(area.to_parts()).unwrap();
+        let Some(Ast::TraitItem(trait_, TraitItem::Fn(it))) = binding.non_type_ast.as_ref().map(|it| &it.ast) else {
+            panic!("not a trait item: {:?}", binding.non_type_ast);
+        };
+        assert_eq!(trait_.ident, "Shape");
+        assert!(trait_.items.is_empty());
+        let syn::ReturnType::Type(_, ty) = &it.sig.output else {
+            panic!("no output");
+        };
+        let Type::Path(ty) = ty.as_ref() else {
+            panic!("not a path: {:?}", ty);
+        };
+        assert_eq!(ty.path.resolution, syn::PathResolution::Resolved("c::Unit".to_string()));
+    }
+}
@@ -0,0 +1,572 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display},
+};
+
+use syn::ImplItem;
+
+use crate::{
+    generic_bounds::{GenericBound, ResolvedGenericParam, ResolvedGenerics, ResolvedPredicate},
+    normalize::AbstractProjection,
+    primitive::Primitive,
+    stopwatch::start_watch,
+    Database, GlobalIdent, ResolvedType, TraitRef,
+};
+
+const MAX_SOLVER_DEPTH: usize = 32;
+
+/// traits the compiler implements for tuples of types implementing them
+const TUPLE_TRAITS: [&str; 9] = [
+    "core::clone::Clone",
+    "core::marker::Copy",
+    "core::fmt::Debug",
+    "core::default::Default",
+    "core::cmp::PartialEq",
+    "core::cmp::Eq",
+    "core::cmp::PartialOrd",
+    "core::cmp::Ord",
+    "core::hash::Hash",
+];
+
+/// trait impl with its header converted to the typed model.
+/// omitted args of the header are taken from defaults, see `Database::with_default_args`
+#[derive(Debug, Clone)]
+pub struct ImplRecord {
+    pub address: GlobalIdent,
+    pub generics: ResolvedGenerics,
+    pub self_ty: ResolvedType,
+    pub trait_: TraitRef,
+    /// `impl !Send for X {}`
+    pub negative: bool,
+    /// `type Output = Self;` of `impl Add for Vector3`, in terms of the impl's params
+    pub assoc_types: BTreeMap<String, ResolvedType>,
+}
+
+impl ImplRecord {
+    /// names of the impl's type and const params, which are variables during unification
+    pub fn params(&self) -> BTreeSet<String> {
+        self.generics
+            .params
+            .iter()
+            .filter_map(|it| match it {
+                ResolvedGenericParam::Type { name, .. } => Some(name.clone()),
+                ResolvedGenericParam::Const { name, .. } => Some(name.clone()),
+                ResolvedGenericParam::Lifetime { .. } => None,
+            })
+            .collect()
+    }
+}
+
+/// result of `Database::implements`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImplMatch {
+    /// selected impl with the types its generic params are bound to
+    Impl {
+        address: GlobalIdent,
+        substitution: BTreeMap<String, ResolvedType>,
+    },
+    /// the type is a generic param which bounds (or their supertraits) include the trait
+    Bound,
+    /// implemented by the compiler itself, like `Sized` or `Clone` for tuples
+    Builtin,
+    /// auto trait (`Send`, `Sync`, `Unpin`) implemented by all the fields of the type
+    Auto,
+    /// no impl applies, with the reason for every rejected candidate
+    NoMatch(Vec<String>),
+}
+
+impl ImplMatch {
+    pub fn is_match(&self) -> bool {
+        !matches!(self, ImplMatch::NoMatch(_))
+    }
+}
+
+impl Display for ImplMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImplMatch::Impl { address, substitution } => {
+                write!(f, "{}", address)?;
+                for (i, (param, ty)) in substitution.iter().enumerate() {
+                    write!(f, "{}{} = {}", if i == 0 { " with " } else { ", " }, param, ty)?;
+                }
+                Ok(())
+            }
+            ImplMatch::Bound => write!(f, "bound of a generic param"),
+            ImplMatch::Builtin => write!(f, "builtin"),
+            ImplMatch::Auto => write!(f, "auto"),
+            ImplMatch::NoMatch(reasons) => write!(f, "no match: {}", reasons.join("; ")),
+        }
+    }
+}
+
+impl Database {
+    pub(crate) fn index_impls(&mut self) {
+        let _watch = start_watch("index_impls");
+        let mut impls: BTreeMap<GlobalIdent, Vec<ImplRecord>> = Default::default();
+        let mut impls_of_type: BTreeMap<GlobalIdent, Vec<GlobalIdent>> = Default::default();
+        let mut blanket_impls = BTreeSet::new();
+        self.for_each_impl(&mut |address, ast| {
+            match self.expand_aliases(&self.resolve_type(&ast.self_ty)) {
+                ResolvedType::Path { address: self_ty, .. } => impls_of_type.entry(self_ty).or_default().push(address.clone()),
+                ResolvedType::Generic(param) if ast.generics.type_params().any(|it| it.ident == param) => {
+                    blanket_impls.insert(address.clone());
+                }
+                _ => {}
+            }
+            let Some((_, trait_path, _)) = &ast.trait_ else {
+                return;
+            };
+            let Some(trait_) = self.resolve_trait_ref(trait_path) else {
+                println!("WARN: skip impl {} of unresolved trait", address);
+                return;
+            };
+            let assoc_types = ast
+                .items
+                .iter()
+                .filter_map(|it| match it {
+                    ImplItem::Type(it) => Some((it.ident.to_string(), self.resolve_type(&it.ty))),
+                    _ => None,
+                })
+                .collect();
+            let self_ty = self.with_default_args(&self.resolve_type(&ast.self_ty));
+            let record = ImplRecord {
+                address: address.clone(),
+                generics: self.resolve_generics(&ast.generics),
+                negative: matches!(ast.trait_, Some((Some(_), _, _))),
+                trait_: TraitRef {
+                    args: self.trait_args(&self_ty, &trait_),
+                    address: trait_.address,
+                },
+                self_ty,
+                assoc_types,
+            };
+            impls.entry(record.trait_.address.clone()).or_default().push(record);
+        });
+        self.impls = impls;
+        self.impls_of_type = impls_of_type;
+        self.blanket_impls = blanket_impls;
+    }
+
+    /// finds the impl through which `ty` implements `trait_`.
+    /// generic params in `ty` are rigid: they satisfy only their own (unknown here) bounds, see `implements_in`
+    pub fn implements(&self, ty: &ResolvedType, trait_: &TraitRef) -> ImplMatch {
+        self.implements_in(ty, trait_, &Default::default())
+    }
+
+    /// like `implements`, but generic params of `ty` satisfy the bounds declared in `env`
+    pub fn implements_in(&self, ty: &ResolvedType, trait_: &TraitRef, env: &ResolvedGenerics) -> ImplMatch {
+        Solver::new(self, env).solve(ty, trait_)
+    }
+
+    /// checks whether bounds of a generic item hold for the given types of its params,
+    /// returns the first unsatisfied bound
+    pub fn check_bounds(
+        &self,
+        generics: &ResolvedGenerics,
+        substitution: &BTreeMap<String, ResolvedType>,
+        env: &ResolvedGenerics,
+    ) -> Result<(), String> {
+        Solver::new(self, env).check_generics(generics, substitution)
+    }
+
+    pub fn impl_record(&self, trait_: &GlobalIdent, address: &GlobalIdent) -> Option<&ImplRecord> {
+        self.impls.get(trait_)?.iter().find(|it| it.address == *address)
+    }
+}
+
+pub(crate) struct Solver<'a> {
+    pub(crate) db: &'a Database,
+    pub(crate) env: &'a ResolvedGenerics,
+    /// obligations being proven, to stop on cycles
+    pub(crate) stack: Vec<(ResolvedType, TraitRef)>,
+    /// projections of the normalized type which stayed abstract
+    pub(crate) abstract_projections: Vec<AbstractProjection>,
+}
+
+impl<'a> Solver<'a> {
+    pub(crate) fn new(db: &'a Database, env: &'a ResolvedGenerics) -> Self {
+        Solver {
+            db,
+            env,
+            stack: vec![],
+            abstract_projections: vec![],
+        }
+    }
+
+    pub(crate) fn solve(&mut self, ty: &ResolvedType, trait_: &TraitRef) -> ImplMatch {
+        let ty = self.normalize(ty);
+        if self.stack.len() >= MAX_SOLVER_DEPTH {
+            return ImplMatch::NoMatch(vec![format!("recursion limit reached for {}: {}", ty, trait_)]);
+        }
+        if self.stack.iter().any(|(t, tr)| *t == ty && tr == trait_) {
+            // auto traits are coinductive: a recursive type is `Send` if nothing else breaks it
+            if self.db.is_auto_trait(&trait_.address) {
+                return ImplMatch::Auto;
+            }
+            return ImplMatch::NoMatch(vec![format!("cycle while proving {}: {}", ty, trait_)]);
+        }
+        println!("{}solve {}: {}", "  ".repeat(self.stack.len()), ty, trait_);
+        self.stack.push((ty.clone(), trait_.clone()));
+        let result = self.solve_internal(&ty, trait_);
+        self.stack.pop();
+        println!("{}solved {}: {} - {}", "  ".repeat(self.stack.len()), ty, trait_, result);
+        result
+    }
+
+    fn solve_internal(&mut self, ty: &ResolvedType, trait_: &TraitRef) -> ImplMatch {
+        if let ResolvedType::Unresolved(it) = ty {
+            return ImplMatch::NoMatch(vec![format!("type {} is not resolved", it)]);
+        }
+        if trait_.address.to_string() == "core::marker::Sized" {
+            return if is_sized(ty) {
+                ImplMatch::Builtin
+            } else {
+                ImplMatch::NoMatch(vec![format!("{} is unsized", ty)])
+            };
+        }
+        if let ResolvedType::Generic(name) = ty {
+            if self.param_bounded_by(name, trait_) {
+                return ImplMatch::Bound;
+            }
+        }
+        if let Some(it) = self.solve_builtin(ty, trait_) {
+            return it;
+        }
+        // impls which headers match, but where-clauses don't hold
+        let mut reasons = vec![];
+        let impls = self.db.impls.get(&trait_.address).map(|it| it.as_slice()).unwrap_or_default();
+        if impls.is_empty() && !self.db.is_auto_trait(&trait_.address) {
+            return ImplMatch::NoMatch(vec![format!("no impls of {}", trait_.address)]);
+        }
+        let full_ty = self.db.with_default_args(ty);
+        let args = self.db.trait_args(&full_ty, trait_);
+        for record in impls.iter() {
+            match self.match_impl(record, &full_ty, &args) {
+                Ok(Some(substitution)) => {
+                    if record.negative {
+                        return ImplMatch::NoMatch(vec![format!("negative impl {}", record.address)]);
+                    }
+                    return ImplMatch::Impl {
+                        address: record.address.clone(),
+                        substitution,
+                    };
+                }
+                Ok(None) => {}
+                Err(reason) => reasons.push(format!("{}: {}", record.address, reason)),
+            }
+        }
+        if reasons.is_empty() && self.db.is_auto_trait(&trait_.address) {
+            return self.solve_auto(ty, trait_);
+        }
+        if reasons.is_empty() {
+            reasons.push(match ty {
+                ResolvedType::Generic(name) => format!("{} has no bound {}", name, trait_),
+                _ => format!("no impl of {} for {}", trait_, ty),
+            });
+        }
+        ImplMatch::NoMatch(reasons)
+    }
+
+    fn solve_builtin(&mut self, ty: &ResolvedType, trait_: &TraitRef) -> Option<ImplMatch> {
+        let trait_name = trait_.address.to_string();
+        match ty {
+            ResolvedType::Tuple(items) if !items.is_empty() && TUPLE_TRAITS.contains(&trait_name.as_str()) => {
+                for item in items {
+                    if let ImplMatch::NoMatch(reasons) = self.solve(item, trait_) {
+                        return Some(ImplMatch::NoMatch(reasons));
+                    }
+                }
+                Some(ImplMatch::Builtin)
+            }
+            ResolvedType::Fn { .. } | ResolvedType::Never
+                if trait_name == "core::clone::Clone" || trait_name == "core::marker::Copy" =>
+            {
+                Some(ImplMatch::Builtin)
+            }
+            _ => None,
+        }
+    }
+
+    /// whether bounds of the param in the environment (or their supertraits) include the trait with the same args
+    fn param_bounded_by(&self, param: &str, trait_: &TraitRef) -> bool {
+        let param_ty = ResolvedType::Generic(param.to_string());
+        let args = self.db.trait_args(&param_ty, trait_);
+        let traits_of = |bounds: Vec<GenericBound>| {
+            bounds.into_iter().filter_map(|it| match it {
+                GenericBound::Trait { trait_, maybe: false, .. } => Some(trait_),
+                _ => None,
+            })
+        };
+        let mut checked = BTreeSet::new();
+        let mut queue = traits_of(self.env.bounds_of(param)).collect::<Vec<_>>();
+        while let Some(bound) = queue.pop() {
+            if bound.address == trait_.address && self.db.trait_args(&param_ty, &bound) == args {
+                return true;
+            }
+            if checked.insert(bound.clone()) {
+                // supertraits are declared in terms of the trait's params
+                let substitution = self.db.trait_substitution(&param_ty, &bound);
+                let supertraits = traits_of(self.db.supertraits(&bound.address));
+                queue.extend(supertraits.map(|it| it.map(&mut |it| it.substitute(&substitution))));
+            }
+        }
+        false
+    }
+
+    /// unifies the impl header with the obligation and checks its where-clauses. `ty` and the trait `args`
+    /// should have omitted args taken from defaults, like the header. `None` if the header doesn't match
+    fn match_impl(
+        &mut self,
+        record: &ImplRecord,
+        ty: &ResolvedType,
+        args: &[ResolvedType],
+    ) -> Result<Option<BTreeMap<String, ResolvedType>>, String> {
+        let params = record.params();
+        let mut substitution = BTreeMap::new();
+        if !unify(&record.self_ty, ty, &params, &mut substitution) {
+            return Ok(None);
+        }
+        if record.trait_.args.len() != args.len() {
+            return Ok(None);
+        }
+        for (pattern, arg) in record.trait_.args.iter().zip(args.iter()) {
+            if !unify(pattern, arg, &params, &mut substitution) {
+                return Ok(None);
+            }
+        }
+        if !record.negative {
+            self.check_generics(&record.generics, &substitution)?;
+        }
+        Ok(Some(substitution))
+    }
+
+    pub(crate) fn check_generics(
+        &mut self,
+        generics: &ResolvedGenerics,
+        substitution: &BTreeMap<String, ResolvedType>,
+    ) -> Result<(), String> {
+        let mut obligations = vec![];
+        for param in generics.params.iter() {
+            if let ResolvedGenericParam::Type { name, bounds, .. } = param {
+                let ty = substitution
+                    .get(name)
+                    .cloned()
+                    .unwrap_or_else(|| ResolvedType::Generic(name.clone()));
+                if !bounds.iter().any(|it| matches!(it, GenericBound::Trait { maybe: true, .. })) && !is_sized(&ty) {
+                    return Err(format!("{} = {} is unsized", name, ty));
+                }
+                obligations.extend(bounds.iter().map(|it| (ty.clone(), it)));
+            }
+        }
+        let predicates = generics.where_clause.iter().filter_map(|it| match it {
+            ResolvedPredicate::Type { bounded, bounds, .. } => Some((bounded.substitute(substitution), bounds)),
+            ResolvedPredicate::Lifetime { .. } => None,
+        });
+        for (bounded, bounds) in predicates {
+            obligations.extend(bounds.iter().map(|it| (bounded.clone(), it)));
+        }
+        for (ty, bound) in obligations {
+            self.check_bound(&ty, bound, substitution)?;
+        }
+        Ok(())
+    }
+
+    fn check_bound(
+        &mut self,
+        ty: &ResolvedType,
+        bound: &GenericBound,
+        substitution: &BTreeMap<String, ResolvedType>,
+    ) -> Result<(), String> {
+        let GenericBound::Trait {
+            trait_,
+            assoc_bindings,
+            maybe,
+            ..
+        } = bound
+        else {
+            if let GenericBound::Unresolved(it) = bound {
+                return Err(format!("bound {} is not resolved", it));
+            }
+            // lifetimes are not tracked
+            return Ok(());
+        };
+        if *maybe {
+            return Ok(());
+        }
+        let trait_ = trait_.map(&mut |it| it.substitute(substitution));
+        if let ImplMatch::NoMatch(reasons) = self.solve(ty, &trait_) {
+            return Err(format!("{}: {} is not satisfied ({})", ty, trait_, reasons.join("; ")));
+        }
+        for (assoc, expected) in assoc_bindings.iter() {
+            let expected = self.normalize(&expected.substitute(substitution));
+            let actual = self.normalize(&ResolvedType::Projection {
+                self_ty: Box::new(ty.clone()),
+                trait_: trait_.clone(),
+                assoc: assoc.clone(),
+            });
+            if actual != expected {
+                return Err(format!("{}: {}<{} = {}> is not satisfied, it is {}", ty, trait_, assoc, expected, actual));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_sized(ty: &ResolvedType) -> bool {
+    !matches!(
+        ty,
+        ResolvedType::Slice(_) | ResolvedType::TraitObject(_) | ResolvedType::Primitive(Primitive::Str)
+    )
+}
+
+/// matches `pattern` (from an impl header, where `params` are variables) with `ty`, extending `substitution`
+pub fn unify(
+    pattern: &ResolvedType,
+    ty: &ResolvedType,
+    params: &BTreeSet<String>,
+    substitution: &mut BTreeMap<String, ResolvedType>,
+) -> bool {
+    let bind = |name: &String, ty: &ResolvedType, substitution: &mut BTreeMap<String, ResolvedType>| {
+        match substitution.get(name) {
+            Some(bound) => bound == ty,
+            None => {
+                substitution.insert(name.clone(), ty.clone());
+                true
+            }
+        }
+    };
+    match (pattern, ty) {
+        (ResolvedType::Generic(name), _) if params.contains(name) => bind(name, ty, substitution),
+        (ResolvedType::Const(name), _) if params.contains(name) => bind(name, ty, substitution),
+        (ResolvedType::Path { address: a, args: a_args }, ResolvedType::Path { address: b, args: b_args }) => {
+            // defaulted args are expected to be filled on both sides, see `Database::with_default_args`
+            a == b
+                && a_args.len() == b_args.len()
+                && a_args.iter().zip(b_args.iter()).all(|(a, b)| unify(a, b, params, substitution))
+        }
+        (
+            ResolvedType::Reference { mutable: a_mut, elem: a },
+            ResolvedType::Reference { mutable: b_mut, elem: b },
+        )
+        | (ResolvedType::Ptr { mutable: a_mut, elem: a }, ResolvedType::Ptr { mutable: b_mut, elem: b }) => {
+            a_mut == b_mut && unify(a, b, params, substitution)
+        }
+        (ResolvedType::Slice(a), ResolvedType::Slice(b)) => unify(a, b, params, substitution),
+        (ResolvedType::Array { elem: a, len: a_len }, ResolvedType::Array { elem: b, len: b_len }) => {
+            let len_matches = if params.contains(a_len) {
+                bind(a_len, &ResolvedType::Const(b_len.clone()), substitution)
+            } else {
+                a_len == b_len
+            };
+            len_matches && unify(a, b, params, substitution)
+        }
+        (ResolvedType::Tuple(a), ResolvedType::Tuple(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| unify(a, b, params, substitution))
+        }
+        (ResolvedType::Fn { inputs: a, output: a_out }, ResolvedType::Fn { inputs: b, output: b_out }) => {
+            a.len() == b.len()
+                && a.iter().zip(b.iter()).all(|(a, b)| unify(a, b, params, substitution))
+                && unify(a_out, b_out, params, substitution)
+        }
+        (
+            ResolvedType::Projection { self_ty: a, trait_: a_trait, assoc: a_assoc },
+            ResolvedType::Projection { self_ty: b, trait_: b_trait, assoc: b_assoc },
+        ) => {
+            a_assoc == b_assoc
+                && a_trait.address == b_trait.address
+                && unify(a, b, params, substitution)
+                && a_trait.args.len() == b_trait.args.len()
+                && a_trait.args.iter().zip(b_trait.args.iter()).all(|(a, b)| unify(a, b, params, substitution))
+        }
+        (ResolvedType::TraitObject(a), ResolvedType::TraitObject(b))
+        | (ResolvedType::ImplTrait(a), ResolvedType::ImplTrait(b)) => {
+            a.len() == b.len()
+                && a.iter().zip(b.iter()).all(|(a, b)| {
+                    a.address == b.address
+                        && a.args.len() == b.args.len()
+                        && a.args.iter().zip(b.args.iter()).all(|(a, b)| unify(a, b, params, substitution))
+                })
+        }
+        (a, b) => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::{unify, ImplMatch};
+    use crate::{primitive::Primitive, test_support::compiled, GlobalIdent, ResolvedType, TraitRef};
+
+    const SOURCE: &str = "
+        pub trait Eq2<Rhs = Self> {}
+        pub trait Sub: Eq2<u8> {}
+        pub trait Marker {}
+        pub struct S;
+        pub struct Str;
+        pub struct Alloc;
+        pub struct V<T, A = Alloc>(T, A);
+        impl Eq2<Str> for S {}
+        impl<T> Marker for V<T> {}
+        pub fn bounded<T: Eq2<Str>>() {}
+        pub fn sub<T: Sub>() {}
+    ";
+
+    fn path(name: &str, args: Vec<ResolvedType>) -> ResolvedType {
+        ResolvedType::Path {
+            address: GlobalIdent::from_qualified_name(name),
+            args,
+        }
+    }
+
+    fn trait_(name: &str, args: Vec<ResolvedType>) -> TraitRef {
+        TraitRef {
+            address: GlobalIdent::from_qualified_name(name),
+            args,
+        }
+    }
+
+    #[test]
+    fn defaulted_trait_args_are_compared() {
+        let db = compiled(SOURCE);
+        let s = path("c::S", vec![]);
+        let str_ = path("c::Str", vec![]);
+        assert!(db.implements(&s, &trait_("c::Eq2", vec![str_])).is_match());
+        // `Eq2` is `Eq2<S>` here
+        assert!(!db.implements(&s, &trait_("c::Eq2", vec![])).is_match());
+    }
+
+    #[test]
+    fn defaulted_type_args_are_compared() {
+        let db = compiled(SOURCE);
+        let marker = trait_("c::Marker", vec![]);
+        let u8_ = ResolvedType::Primitive(Primitive::U8);
+        let alloc = path("c::Alloc", vec![]);
+        assert!(db.implements(&path("c::V", vec![u8_.clone()]), &marker).is_match());
+        assert!(db.implements(&path("c::V", vec![u8_.clone(), alloc]), &marker).is_match());
+        assert!(!db.implements(&path("c::V", vec![u8_.clone(), u8_]), &marker).is_match());
+    }
+
+    #[test]
+    fn bounds_of_params_are_compared_with_args() {
+        let db = compiled(SOURCE);
+        let t = ResolvedType::Generic("T".to_string());
+        let env = db.generics_of(&GlobalIdent::from_qualified_name("c::bounded")).unwrap();
+        let str_ = path("c::Str", vec![]);
+        assert_eq!(db.implements_in(&t, &trait_("c::Eq2", vec![str_]), &env), ImplMatch::Bound);
+        assert!(!db.implements_in(&t, &trait_("c::Eq2", vec![]), &env).is_match());
+
+        let env = db.generics_of(&GlobalIdent::from_qualified_name("c::sub")).unwrap();
+        let u8_ = ResolvedType::Primitive(Primitive::U8);
+        assert_eq!(db.implements_in(&t, &trait_("c::Eq2", vec![u8_]), &env), ImplMatch::Bound);
+        assert!(!db.implements_in(&t, &trait_("c::Eq2", vec![]), &env).is_match());
+    }
+
+    #[test]
+    fn paths_with_different_arg_counts_do_not_unify() {
+        let params = BTreeSet::from(["T".to_string()]);
+        let pattern = path("c::V", vec![ResolvedType::Generic("T".to_string())]);
+        let ty = path("c::V", vec![path("c::S", vec![]), path("c::S", vec![])]);
+        assert!(!unify(&pattern, &ty, &params, &mut Default::default()));
+    }
+}
@@ -0,0 +1,607 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+};
+
+use syn::{parenthesized, Attribute, Fields, FnArg, GenericParam, Generics, Item, LitStr, Token, Variant};
+
+use crate::{
+    codegen::{camel_case, is_std, unique_names}, dedoc::doc_of, Ast, Database, GlobalIdent, Primitive, ResolvedType,
+};
+
+/// TypeScript declarations (`.d.ts`), see `Database::ts_declarations`
+#[derive(Debug, Clone, Default)]
+pub struct TsDeclarations {
+    pub code: String,
+    /// items left out, with the reason
+    pub skipped: Vec<(String, String)>,
+    /// types written as `unknown` because TypeScript has no counterpart, by the item mentioning them
+    pub unknown: Vec<(String, String)>,
+}
+
+/// `#[serde(...)]` options affecting the JSON shape
+#[derive(Debug, Clone, Default)]
+struct SerdeAttrs {
+    rename: Option<String>,
+    rename_all: Option<String>,
+    tag: Option<String>,
+    content: Option<String>,
+    untagged: bool,
+    transparent: bool,
+    flatten: bool,
+    skip: bool,
+    /// `default` or `skip_serializing_if`, the field may be missing
+    optional: bool,
+}
+
+struct TsGenerator<'a> {
+    db: &'a Database,
+    /// declarations to write with their TypeScript names
+    names: BTreeMap<GlobalIdent, String>,
+    items: BTreeMap<GlobalIdent, Item>,
+    skipped: Vec<(String, String)>,
+    unknown: Vec<(String, String)>,
+}
+
+impl Database {
+    /// `.d.ts` mirroring the JSON shapes (as serde writes them) of the structs, enums and type aliases of
+    /// the symbols, along with signatures of their functions, consts and statics. types the declarations
+    /// mention are declared too, so the file is self-contained. modules stand for all their public items
+    pub fn ts_declarations(&self, symbols: &[GlobalIdent]) -> TsDeclarations {
+        let mut generator = TsGenerator {
+            db: self,
+            names: Default::default(),
+            items: Default::default(),
+            skipped: vec![],
+            unknown: vec![],
+        };
+        let targets = self.expand_symbols(symbols, &mut generator.skipped);
+        generator.collect(targets);
+        // `Point`, or `WorldPoint` if another `Point` is declared too
+        generator.names = unique_names(generator.items.keys());
+        let code = generator.generate();
+        TsDeclarations {
+            code,
+            skipped: generator.skipped,
+            unknown: generator.unknown,
+        }
+    }
+}
+
+impl TsGenerator<'_> {
+    /// the targets and the types they mention, transitively
+    fn collect(&mut self, targets: BTreeSet<GlobalIdent>) {
+        let mut queue = targets.into_iter().map(|it| (it, true)).collect::<Vec<_>>();
+        while let Some((address, requested)) = queue.pop() {
+            if self.items.contains_key(&address) {
+                continue;
+            }
+            let Some(item) = self.item_of(&address) else {
+                if requested {
+                    self.skipped.push((address.to_string(), "has no TypeScript counterpart".to_string()));
+                }
+                continue;
+            };
+            for ty in self.mentioned_types(&item) {
+                ty.for_each(&mut |it| {
+                    if let ResolvedType::Path { address, .. } = it {
+                        if !is_std(address) {
+                            queue.push((address.clone(), false));
+                        }
+                    }
+                });
+            }
+            self.items.insert(address, item);
+        }
+    }
+
+    fn item_of(&self, address: &GlobalIdent) -> Option<Item> {
+        let binding = self.db.decls.find_value(address.to_parts())?;
+        binding.type_ast.iter().chain(binding.non_type_ast.iter()).find_map(|it| match &it.ast {
+            Ast::Real(
+                item @ (Item::Struct(_)
+                | Item::Enum(_)
+                | Item::Type(_)
+                | Item::Fn(_)
+                | Item::Const(_)
+                | Item::Static(_)),
+            ) => Some(item.clone()),
+            _ => None,
+        })
+    }
+
+    fn mentioned_types(&self, item: &Item) -> Vec<ResolvedType> {
+        let mut types = vec![];
+        match item {
+            Item::Struct(it) => types.extend(it.fields.iter().map(|it| &it.ty).cloned()),
+            Item::Enum(it) => types.extend(it.variants.iter().flat_map(|it| it.fields.iter()).map(|it| it.ty.clone())),
+            Item::Type(it) => types.push((*it.ty).clone()),
+            Item::Const(it) => types.push((*it.ty).clone()),
+            Item::Static(it) => types.push((*it.ty).clone()),
+            Item::Fn(it) => {
+                types.extend(it.sig.inputs.iter().filter_map(|it| match it {
+                    FnArg::Typed(it) => Some((*it.ty).clone()),
+                    FnArg::Receiver(_) => None,
+                }));
+                return types
+                    .iter()
+                    .map(|it| self.resolve(it))
+                    .chain([self.db.normalize(&self.db.resolve_return_type(&it.sig.output))])
+                    .collect();
+            }
+            _ => {}
+        }
+        types.iter().map(|it| self.resolve(it)).collect()
+    }
+
+    fn resolve(&self, ty: &syn::Type) -> ResolvedType {
+        self.db.normalize(&self.db.resolve_type(ty))
+    }
+
+    fn generate(&mut self) -> String {
+        let mut out = String::new();
+        writeln!(out, "// generated by syg from the resolved signatures").unwrap();
+        for (address, item) in self.items.clone() {
+            let name = self.names[&address].clone();
+            let mut unknown = vec![];
+            let declaration = match &item {
+                Item::Struct(it) => {
+                    let attrs = serde_attrs(&it.attrs);
+                    let body = self.struct_type(&it.fields, &attrs, "", &mut unknown);
+                    let flattened = it.fields.iter().any(|it| serde_attrs(&it.attrs).flatten);
+                    match (&it.fields, attrs.transparent) {
+                        (Fields::Named(_), false) if !flattened => {
+                            format!("export interface {}{} {}", name, type_params(&it.generics), body)
+                        }
+                        _ => format!("export type {}{} = {};", name, type_params(&it.generics), body),
+                    }
+                }
+                Item::Enum(it) => {
+                    let attrs = serde_attrs(&it.attrs);
+                    let variants = it
+                        .variants
+                        .iter()
+                        .filter_map(|v| self.variant_type(&address, v, &attrs, &mut unknown))
+                        .collect::<Vec<_>>();
+                    let body = match variants.as_slice() {
+                        [] => " never".to_string(),
+                        _ => variants
+                            .iter()
+                            .map(|it| format!("\n    | {}", it.replace('\n', "\n    ")))
+                            .collect::<String>(),
+                    };
+                    format!("export type {}{} ={};", name, type_params(&it.generics), body)
+                }
+                Item::Type(it) => format!(
+                    "export type {}{} = {};",
+                    name,
+                    type_params(&it.generics),
+                    self.ts_type(&self.resolve(&it.ty), &mut unknown)
+                ),
+                Item::Const(it) => format!(
+                    "export const {}: {};",
+                    it.ident,
+                    self.ts_type(&self.resolve(&it.ty), &mut unknown)
+                ),
+                Item::Static(it) => format!(
+                    "export {} {}: {};",
+                    if matches!(it.mutability, syn::StaticMutability::Mut(_)) { "let" } else { "const" },
+                    it.ident,
+                    self.ts_type(&self.resolve(&it.ty), &mut unknown)
+                ),
+                Item::Fn(it) => {
+                    let params = it
+                        .sig
+                        .inputs
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(i, arg)| match arg {
+                            FnArg::Typed(arg) => {
+                                let name = match &*arg.pat {
+                                    syn::Pat::Ident(it) => it.ident.to_string(),
+                                    _ => format!("arg{}", i),
+                                };
+                                Some(format!("{}: {}", name, self.ts_type(&self.resolve(&arg.ty), &mut unknown)))
+                            }
+                            FnArg::Receiver(_) => None,
+                        })
+                        .collect::<Vec<_>>();
+                    let output = self.db.normalize(&self.db.resolve_return_type(&it.sig.output));
+                    let output = match output == ResolvedType::unit() {
+                        true => "void".to_string(),
+                        false => self.ts_type(&output, &mut unknown),
+                    };
+                    format!(
+                        "export function {}{}({}): {};",
+                        it.sig.ident,
+                        type_params(&it.sig.generics),
+                        params.join(", "),
+                        output
+                    )
+                }
+                _ => continue,
+            };
+            for ty in unknown {
+                println!("WARN: {} of {} has no TypeScript counterpart", ty, address);
+                self.unknown.push((address.to_string(), ty));
+            }
+            writeln!(out).unwrap();
+            write_doc(&mut out, &doc_of(item_attrs(&item)), "");
+            writeln!(out, "{}", declaration).unwrap();
+        }
+        out
+    }
+
+    /// `{ name: string; ... }` of named fields, `[A, B]` of tuple fields, `A` of newtypes and `null` of units.
+    /// flattened fields are joined with `&`
+    fn struct_type(&self, fields: &Fields, attrs: &SerdeAttrs, extra: &str, unknown: &mut Vec<String>) -> String {
+        let visible = fields
+            .iter()
+            .enumerate()
+            .map(|(i, it)| (i, it, serde_attrs(&it.attrs)))
+            .filter(|(_, _, attrs)| !attrs.skip)
+            .collect::<Vec<_>>();
+        match fields {
+            Fields::Named(_) if !attrs.transparent => {
+                let mut members = extra.to_string();
+                let mut flattened = vec![];
+                for (_, field, field_attrs) in visible.iter() {
+                    let ty = self.ts_type(&self.resolve(&field.ty), unknown);
+                    if field_attrs.flatten {
+                        flattened.push(ty);
+                        continue;
+                    }
+                    let ident = field.ident.as_ref().unwrap().to_string();
+                    let name = match (&field_attrs.rename, &attrs.rename_all) {
+                        (Some(it), _) => it.clone(),
+                        (None, Some(rule)) => rename_case(&ident, rule, false),
+                        (None, None) => ident,
+                    };
+                    write_doc(&mut members, &doc_of(&field.attrs), "    ");
+                    let optional = if field_attrs.optional { "?" } else { "" };
+                    writeln!(members, "    {}{}: {};", property_name(&name), optional, ty).unwrap();
+                }
+                let object = match members.is_empty() {
+                    true => "{}".to_string(),
+                    false => format!("{{\n{}}}", members),
+                };
+                std::iter::once(object).chain(flattened.into_iter().map(parenthesized)).collect::<Vec<_>>().join(" & ")
+            }
+            Fields::Unit => "null".to_string(),
+            _ => {
+                let types = visible
+                    .iter()
+                    .map(|(_, it, _)| self.ts_type(&self.resolve(&it.ty), unknown))
+                    .collect::<Vec<_>>();
+                match (types.as_slice(), fields.len()) {
+                    ([it], 1) => it.clone(),
+                    ([it], _) if attrs.transparent => it.clone(),
+                    _ => format!("[{}]", types.join(", ")),
+                }
+            }
+        }
+    }
+
+    /// one member of the union: externally tagged `{ Name: ... }` by default, or according to
+    /// `tag`, `tag` + `content` and `untagged`
+    fn variant_type(&mut self, address: &GlobalIdent, variant: &Variant, attrs: &SerdeAttrs, unknown: &mut Vec<String>) -> Option<String> {
+        let variant_attrs = serde_attrs(&variant.attrs);
+        if variant_attrs.skip {
+            return None;
+        }
+        let ident = variant.ident.to_string();
+        let name = match (&variant_attrs.rename, &attrs.rename_all) {
+            (Some(it), _) => it.clone(),
+            (None, Some(rule)) => rename_case(&ident, rule, true),
+            (None, None) => ident,
+        };
+        let literal = format!("{:?}", name);
+        let fields = SerdeAttrs {
+            rename_all: variant_attrs.rename_all.clone(),
+            ..Default::default()
+        };
+        Some(match (&attrs.tag, &attrs.content, attrs.untagged) {
+            (_, _, true) => self.struct_type(&variant.fields, &fields, "", unknown),
+            (Some(tag), Some(content), _) => match &variant.fields {
+                Fields::Unit => format!("{{ {}: {} }}", property_name(tag), literal),
+                it => format!(
+                    "{{ {}: {}; {}: {} }}",
+                    property_name(tag),
+                    literal,
+                    property_name(content),
+                    self.struct_type(it, &fields, "", unknown)
+                ),
+            },
+            (Some(tag), None, _) => match &variant.fields {
+                Fields::Unit => format!("{{ {}: {} }}", property_name(tag), literal),
+                Fields::Named(_) => {
+                    self.struct_type(&variant.fields, &fields, &format!("    {}: {};\n", property_name(tag), literal), unknown)
+                }
+                Fields::Unnamed(it) if it.unnamed.len() == 1 => format!(
+                    "{{ {}: {} }} & {}",
+                    property_name(tag),
+                    literal,
+                    parenthesized(self.struct_type(&variant.fields, &fields, "", unknown))
+                ),
+                Fields::Unnamed(_) => {
+                    let reason = "tuple variants can't be internally tagged";
+                    self.skipped.push((format!("{}::{}", address, variant.ident), reason.to_string()));
+                    return None;
+                }
+            },
+            (None, _, _) => match &variant.fields {
+                Fields::Unit => literal,
+                it => format!("{{ {}: {} }}", property_name(&name), self.struct_type(it, &fields, "", unknown)),
+            },
+        })
+    }
+
+    /// TypeScript type of the JSON serde writes for the type, unsupported ones become `unknown`
+    fn ts_type(&self, ty: &ResolvedType, unknown: &mut Vec<String>) -> String {
+        let mut unsupported = || {
+            unknown.push(ty.to_string());
+            "unknown".to_string()
+        };
+        match ty {
+            ResolvedType::Primitive(Primitive::Bool) => "boolean".to_string(),
+            ResolvedType::Primitive(Primitive::Str | Primitive::Char) => "string".to_string(),
+            ResolvedType::Primitive(_) => "number".to_string(),
+            ResolvedType::Generic(it) => it.clone(),
+            ResolvedType::Reference { elem, .. } => self.ts_type(elem, unknown),
+            ResolvedType::Tuple(items) if items.is_empty() => "null".to_string(),
+            ResolvedType::Tuple(items) => format!(
+                "[{}]",
+                items.iter().map(|it| self.ts_type(it, unknown)).collect::<Vec<_>>().join(", ")
+            ),
+            ResolvedType::Slice(elem) | ResolvedType::Array { elem, .. } => {
+                format!("{}[]", parenthesized(self.ts_type(elem, unknown)))
+            }
+            ResolvedType::Never => "never".to_string(),
+            ResolvedType::Path { address, args } => {
+                let path = address.to_string();
+                if let Some(name) = self.names.get(address) {
+                    return match args.is_empty() {
+                        true => name.clone(),
+                        false => format!(
+                            "{}<{}>",
+                            name,
+                            args.iter().map(|it| self.ts_type(it, unknown)).collect::<Vec<_>>().join(", ")
+                        ),
+                    };
+                }
+                let arg = |i: usize, unknown: &mut Vec<String>| match args.get(i) {
+                    Some(it) => self.ts_type(it, unknown),
+                    None => "unknown".to_string(),
+                };
+                match path.rsplit("::").next().unwrap_or_default() {
+                    _ if !is_std(address) => unsupported(),
+                    "String" => "string".to_string(),
+                    "Option" => format!("{} | null", arg(0, unknown)),
+                    "Vec" | "VecDeque" | "BTreeSet" | "HashSet" | "LinkedList" | "BinaryHeap" => {
+                        format!("{}[]", parenthesized(arg(0, unknown)))
+                    }
+                    "BTreeMap" | "HashMap" => {
+                        let key = match arg(0, unknown).as_str() {
+                            key @ ("string" | "number") => key.to_string(),
+                            _ => "string".to_string(),
+                        };
+                        format!("Record<{}, {}>", key, arg(1, unknown))
+                    }
+                    "Box" | "Rc" | "Arc" | "Cell" | "RefCell" | "Mutex" | "RwLock" | "Reverse" | "Wrapping" => arg(0, unknown),
+                    "Cow" => arg(args.len().saturating_sub(1), unknown),
+                    "Result" => format!("{{ Ok: {} }} | {{ Err: {} }}", arg(0, unknown), arg(1, unknown)),
+                    "PhantomData" => "null".to_string(),
+                    _ => unsupported(),
+                }
+            }
+            _ => unsupported(),
+        }
+    }
+}
+
+fn serde_attrs(attrs: &[Attribute]) -> SerdeAttrs {
+    let mut result = SerdeAttrs::default();
+    for attr in attrs.iter().filter(|it| it.path().is_ident("serde")) {
+        let parsed = attr.parse_nested_meta(|meta| {
+            let name = meta.path.get_ident().map(|it| it.to_string()).unwrap_or_default();
+            let value = if meta.input.peek(Token![=]) {
+                let value = meta.value()?;
+                match name.as_str() {
+                    "rename" | "rename_all" | "tag" | "content" => Some(value.parse::<LitStr>()?.value()),
+                    _ => {
+                        value.parse::<syn::Expr>()?;
+                        None
+                    }
+                }
+            } else if meta.input.peek(syn::token::Paren) {
+                // `rename(serialize = "...", deserialize = "...")`, the serialized name is used
+                let mut serialize = None;
+                let content;
+                parenthesized!(content in meta.input);
+                while !content.is_empty() {
+                    let key = content.parse::<syn::Ident>()?;
+                    content.parse::<Token![=]>()?;
+                    let value = content.parse::<syn::Expr>()?;
+                    if let (true, syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(it), .. })) = (key == "serialize", value) {
+                        serialize = Some(it.value());
+                    }
+                    if !content.is_empty() {
+                        content.parse::<Token![,]>()?;
+                    }
+                }
+                serialize
+            } else {
+                None
+            };
+            match name.as_str() {
+                "rename" => result.rename = value,
+                "rename_all" => result.rename_all = value,
+                "tag" => result.tag = value,
+                "content" => result.content = value,
+                "untagged" => result.untagged = true,
+                "transparent" => result.transparent = true,
+                "flatten" => result.flatten = true,
+                "skip" | "skip_serializing" => result.skip = true,
+                "default" | "skip_serializing_if" => result.optional = true,
+                _ => {}
+            }
+            Ok(())
+        });
+        if let Err(err) = parsed {
+            println!("WARN: cannot parse {}: {}", quote::ToTokens::to_token_stream(attr), err);
+        }
+    }
+    result
+}
+
+/// serde's `rename_all` rules, variants are PascalCase and fields are snake_case
+fn rename_case(name: &str, rule: &str, variant: bool) -> String {
+    let words = if variant {
+        let mut words = vec![];
+        for (i, ch) in name.char_indices() {
+            if i == 0 || ch.is_uppercase() {
+                words.push(String::new());
+            }
+            words.last_mut().unwrap().push(ch);
+        }
+        words
+    } else {
+        name.split('_').map(|it| it.to_string()).collect()
+    };
+    let lower = words.iter().map(|it| it.to_lowercase()).collect::<Vec<_>>();
+    match rule {
+        "lowercase" if variant => name.to_lowercase(),
+        "UPPERCASE" => name.to_uppercase(),
+        "lowercase" | "snake_case" => lower.join("_"),
+        "PascalCase" => lower.iter().map(|it| camel_case(it)).collect(),
+        "camelCase" => {
+            let pascal = lower.iter().map(|it| camel_case(it)).collect::<String>();
+            let mut chars = pascal.chars();
+            chars.next().map(|it| it.to_lowercase().chain(chars).collect()).unwrap_or_default()
+        }
+        "SCREAMING_SNAKE_CASE" => lower.join("_").to_uppercase(),
+        "kebab-case" => lower.join("-"),
+        "SCREAMING-KEBAB-CASE" => lower.join("-").to_uppercase(),
+        _ => {
+            println!("WARN: unknown rename_all rule {}", rule);
+            name.to_string()
+        }
+    }
+}
+
+fn item_attrs(item: &Item) -> &[Attribute] {
+    match item {
+        Item::Struct(it) => &it.attrs,
+        Item::Enum(it) => &it.attrs,
+        Item::Type(it) => &it.attrs,
+        Item::Fn(it) => &it.attrs,
+        Item::Const(it) => &it.attrs,
+        Item::Static(it) => &it.attrs,
+        _ => &[],
+    }
+}
+
+/// `<T, U>` of type params, lifetimes and const params don't exist in TypeScript
+fn type_params(generics: &Generics) -> String {
+    let params = generics
+        .params
+        .iter()
+        .filter_map(|it| match it {
+            GenericParam::Type(it) => Some(it.ident.to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    match params.is_empty() {
+        true => String::new(),
+        false => format!("<{}>", params.join(", ")),
+    }
+}
+
+fn parenthesized(ty: String) -> String {
+    if ty.contains(" | ") || ty.contains(" & ") {
+        format!("({})", ty)
+    } else {
+        ty
+    }
+}
+
+fn property_name(name: &str) -> String {
+    let identifier = name.starts_with(|it: char| it.is_alphabetic() || it == '_' || it == '$')
+        && name.chars().all(|it| it.is_alphanumeric() || it == '_' || it == '$');
+    if identifier {
+        name.to_string()
+    } else {
+        format!("{:?}", name)
+    }
+}
+
+fn write_doc(out: &mut String, doc: &Option<String>, indent: &str) {
+    let Some(doc) = doc else {
+        return;
+    };
+    let lines = doc.lines().collect::<Vec<_>>();
+    if let [line] = lines.as_slice() {
+        writeln!(out, "{}/** {} */", indent, line).unwrap();
+        return;
+    }
+    writeln!(out, "{}/**", indent).unwrap();
+    for line in lines {
+        writeln!(out, "{} * {}", indent, line).unwrap();
+    }
+    writeln!(out, "{} */", indent).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_support::compiled, GlobalIdent};
+
+    const SOURCE: &str = r#"
+        use std::collections::HashMap;
+        #[serde(rename_all = "camelCase")]
+        pub struct User {
+            pub user_name: String,
+            #[serde(skip)]
+            pub cache: u8,
+            #[serde(default)]
+            pub age: Option<u32>,
+            pub tags: Vec<Tag>,
+            pub extra: HashMap<String, i64>,
+        }
+        pub enum Tag { Plain, Named(String), Pos { x: f64 } }
+        #[serde(tag = "type")]
+        pub enum Msg { Ping, Data { value: u8 } }
+        #[serde(untagged)]
+        pub enum Either { L(u8), R(String) }
+        pub fn find(id: u64) -> Option<User> { None }
+        pub mod a { pub mod geo { pub struct Point { pub x: f64 } } }
+        pub mod b { pub mod geo { pub struct Point { pub x: f64 } } }
+    "#;
+
+    #[test]
+    fn declarations_follow_serde_attributes() {
+        let db = compiled(SOURCE);
+        let declarations = db.ts_declarations(&[GlobalIdent::from_qualified_name("c")]);
+        let code = &declarations.code;
+        for expected in [
+            "export interface User {\n    userName: string;\n    age?: number | null;\n    tags: Tag[];\n    extra: Record<string, number>;\n}",
+            "export type Tag =\n    | \"Plain\"\n    | { Named: string }\n    | { Pos: {\n        x: number;\n    } };",
+            "export type Msg =\n    | { type: \"Ping\" }\n    | {\n        type: \"Data\";\n        value: number;\n    };",
+            "export type Either =\n    | number\n    | string;",
+            "export function find(id: number): User | null;",
+        ] {
+            assert!(code.contains(expected), "{}\nis not in\n{}", expected, code);
+        }
+        assert!(declarations.skipped.is_empty() && declarations.unknown.is_empty());
+
+        let variant = db.ts_declarations(&[GlobalIdent::from_qualified_name("c::Tag::Plain")]);
+        assert_eq!(variant.skipped.len(), 1, "{:?}", variant.skipped);
+    }
+
+    #[test]
+    fn same_named_types_are_prefixed() {
+        let symbols = ["c::a::geo::Point", "c::b::geo::Point"].map(GlobalIdent::from_qualified_name);
+        let code = compiled(SOURCE).ts_declarations(&symbols).code;
+        assert!(code.contains("export interface AGeoPoint {"), "{}", code);
+        assert!(code.contains("export interface BGeoPoint {"), "{}", code);
+    }
+}